@@ -0,0 +1,214 @@
+use crate::{
+    source::AudioPlusSource,
+    voice::{AudioPlusVoice, AudioPlusVoiceHandle, AudioPlusVoiceState},
+};
+use bevy::{prelude::*, utils::HashMap};
+use std::any::TypeId;
+
+/// How much louder a voice must be than a voice of the same priority to take its channel.
+/// Keeps two equally loud voices from stealing back and forth.
+const STEAL_VOLUME_MARGIN: f32 = 0.1;
+
+#[derive(Clone, Copy, PartialEq)]
+struct VoiceScore {
+    priority: i32,
+    volume: f32,
+}
+
+impl VoiceScore {
+    fn of(source: &AudioPlusSource, index: usize) -> Option<Self> {
+        let voice = source.voices.get(index)?;
+        if !voice.should_assign {
+            return None;
+        }
+        // volume_multiplier already includes distance falloff and mixer volume. gain is left out
+        // so muted music layers keep their channels
+        Some(Self {
+            priority: source.sound_effect.priority,
+            volume: voice.volume * voice.volume_multiplier,
+        })
+    }
+
+    fn beats(&self, other: &VoiceScore) -> bool {
+        self.priority > other.priority
+            || (self.priority == other.priority && self.volume > other.volume + STEAL_VOLUME_MARGIN)
+    }
+
+    fn weaker_than(&self, other: &VoiceScore) -> bool {
+        self.priority < other.priority
+            || (self.priority == other.priority && self.volume < other.volume)
+    }
+}
+
+#[derive(Default)]
+struct AllocatorChannel {
+    voice: Option<AudioPlusVoiceHandle>,
+    score: Option<VoiceScore>,
+}
+
+/// Snapshot of a kira channel, for debugging.
+#[derive(Debug, Clone, Copy)]
+pub struct AudioPlusChannelUsage {
+    pub entity: Option<Entity>,
+    pub priority: i32,
+    pub volume: f32,
+}
+
+/// Hands the fixed pool of kira channels out to voices. When every channel is in use, a voice
+/// takes the channel of the lowest priority voice, or the quietest one if priorities are equal.
+#[derive(Resource, Default)]
+pub struct AudioPlusVoiceAllocator {
+    channels: Vec<AllocatorChannel>,
+    channel_indices: HashMap<TypeId, usize>,
+    pending_voices: usize,
+    stolen_voices: usize,
+}
+
+impl AudioPlusVoiceAllocator {
+    pub(crate) fn add_channel<T: Resource>(&mut self) {
+        self.channel_indices
+            .insert(TypeId::of::<T>(), self.channels.len());
+        self.channels.push(AllocatorChannel::default());
+    }
+
+    pub(crate) fn channel_voice<T: Resource>(&self) -> Option<AudioPlusVoiceHandle> {
+        self.channel_indices
+            .get(&TypeId::of::<T>())
+            .and_then(|index| self.channels[*index].voice)
+    }
+
+    pub(crate) fn channel_voice_at(&self, index: usize) -> Option<AudioPlusVoiceHandle> {
+        self.channels.get(index).and_then(|channel| channel.voice)
+    }
+
+    pub fn channel_count(&self) -> usize {
+        self.channels.len()
+    }
+
+    pub fn used_channels(&self) -> usize {
+        self.channels
+            .iter()
+            .filter(|channel| channel.voice.is_some())
+            .count()
+    }
+
+    /// Voices that want to play this frame but have no channel.
+    pub fn pending_voices(&self) -> usize {
+        self.pending_voices
+    }
+
+    /// Voices that lost their channel to a more important voice since startup.
+    pub fn stolen_voices(&self) -> usize {
+        self.stolen_voices
+    }
+
+    pub fn channels(&self) -> impl Iterator<Item = AudioPlusChannelUsage> + '_ {
+        self.channels.iter().map(|channel| AudioPlusChannelUsage {
+            entity: channel.voice.map(|voice| voice.entity),
+            priority: channel.score.map(|score| score.priority).unwrap_or(0),
+            volume: channel.score.map(|score| score.volume).unwrap_or(0.),
+        })
+    }
+}
+
+/// One shots are dropped, loops start over once they get a channel again.
+fn release_voice(voice: &mut AudioPlusVoice) {
+    if voice.state == AudioPlusVoiceState::Looping {
+        voice.assigned = false;
+        voice.status = default();
+    } else {
+        voice.reset();
+    }
+    voice.state_dirty = true;
+}
+
+pub(crate) fn allocate_voices(
+    mut allocator: ResMut<AudioPlusVoiceAllocator>,
+    mut query: Query<(Entity, &mut AudioPlusSource)>,
+) {
+    let allocator = allocator.as_mut();
+
+    // release channels whose voices are done, and refresh the scores of the rest
+    for channel in allocator.channels.iter_mut() {
+        let Some(handle) = channel.voice else { continue };
+        let mut score = None;
+        if let Ok((_, mut source)) = query.get_mut(handle.entity) {
+            score = VoiceScore::of(&source, handle.index);
+            if let Some(voice) = source.voices.get_mut(handle.index) {
+                if score.is_some() {
+                    voice.assigned = true;
+                } else {
+                    release_voice(voice);
+                }
+            }
+        }
+        if score.is_none() {
+            channel.voice = None;
+        }
+        channel.score = score;
+    }
+
+    let mut candidates = vec![];
+    for (entity, source) in query.iter() {
+        for (index, voice) in source.voices.iter().enumerate() {
+            if voice.should_assign && !voice.assigned {
+                if let Some(score) = VoiceScore::of(&source, index) {
+                    candidates.push((AudioPlusVoiceHandle { entity, index }, score));
+                }
+            }
+        }
+    }
+    candidates.sort_by(|(_, a), (_, b)| {
+        b.priority
+            .cmp(&a.priority)
+            .then(b.volume.total_cmp(&a.volume))
+    });
+
+    allocator.pending_voices = 0;
+    for (handle, score) in candidates {
+        let target = if let Some(free) = allocator
+            .channels
+            .iter()
+            .position(|channel| channel.voice.is_none())
+        {
+            Some(free)
+        } else {
+            let weakest = allocator
+                .channels
+                .iter()
+                .enumerate()
+                .filter_map(|(index, channel)| Some((index, channel.score?)))
+                .reduce(|weakest, other| {
+                    if other.1.weaker_than(&weakest.1) {
+                        other
+                    } else {
+                        weakest
+                    }
+                });
+            match weakest {
+                Some((index, weakest_score)) if score.beats(&weakest_score) => {
+                    let victim = allocator.channels[index].voice.unwrap();
+                    if let Ok((_, mut source)) = query.get_mut(victim.entity) {
+                        if let Some(voice) = source.voices.get_mut(victim.index) {
+                            release_voice(voice);
+                        }
+                    }
+                    allocator.stolen_voices += 1;
+                    Some(index)
+                }
+                _ => None,
+            }
+        };
+        if let Some(index) = target {
+            allocator.channels[index] = AllocatorChannel {
+                voice: Some(handle),
+                score: Some(score),
+            };
+            if let Ok((_, mut source)) = query.get_mut(handle.entity) {
+                source.voices[handle.index].assigned = true;
+            }
+        } else {
+            allocator.pending_voices += 1;
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/// How a positional sound gets quieter between its min and max distance. Sounds are always at
+/// full volume inside the min distance and silent past the max distance. The inverse and
+/// exponential curves are scaled so they fade out to silence at the max distance.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum AudioPlusAttenuation {
+    #[default]
+    Linear,
+    /// Falls off with `min / (min + rolloff * (distance - min))`.
+    Inverse { rolloff: f32 },
+    /// Falls off with `(distance / min) ^ -rolloff`.
+    Exponential { rolloff: f32 },
+    /// Maps 0 (at min distance) to 1 (at max distance) onto a volume.
+    Custom(fn(f32) -> f32),
+}
+
+impl AudioPlusAttenuation {
+    pub fn volume(&self, distance: f32, min_distance: f32, max_distance: f32) -> f32 {
+        if distance <= min_distance {
+            return 1.;
+        }
+        if distance >= max_distance {
+            return 0.;
+        }
+        let reference = min_distance.max(1.);
+        let inverse = |distance: f32, rolloff: f32| {
+            reference / (reference + rolloff * (distance - min_distance))
+        };
+        let exponential = |distance: f32, rolloff: f32| (distance / reference).powf(-rolloff);
+        let volume = match *self {
+            Self::Linear => 1. - (distance - min_distance) / (max_distance - min_distance),
+            Self::Inverse { rolloff } => {
+                normalize(inverse(distance, rolloff), inverse(max_distance, rolloff))
+            }
+            Self::Exponential { rolloff } => normalize(
+                exponential(distance, rolloff),
+                exponential(max_distance, rolloff),
+            ),
+            Self::Custom(curve) => curve((distance - min_distance) / (max_distance - min_distance)),
+        };
+        volume.clamp(0., 1.)
+    }
+}
+
+/// Rescales a curve that starts at 1 so it falls to 0 at `end`, the curve's value at the max
+/// distance.
+fn normalize(volume: f32, end: f32) -> f32 {
+    if end < 1. {
+        (volume - end) / (1. - end)
+    } else {
+        1.
+    }
+}
@@ -1,11 +1,13 @@
 use crate::{
+    allocator::AudioPlusVoiceAllocator,
+    headless::{update_headless_channels, AudioPlusRecorder},
     source::AudioPlusSource,
     voice::{AudioPlusVoiceHandle, AudioPlusVoiceState},
     AudioPlusSystem,
 };
 use bevy::ecs::system::Resource;
 use bevy::prelude::*;
-use bevy_kira_audio::{AudioApp, AudioChannel, AudioControl, AudioInstance};
+use bevy_kira_audio::{AudioApp, AudioChannel, AudioControl, AudioInstance, AudioSource};
 
 macro_rules! channels {
     ( $( $x:ident ),* ) => {
@@ -14,10 +16,22 @@ macro_rules! channels {
             pub struct $x;
         )*
         pub fn add_audio_channels(app: &mut App) {
+            let mut allocator = AudioPlusVoiceAllocator::default();
             $(
+                allocator.add_channel::<$x>();
                 app.add_audio_channel::<$x>();
-                app.add_systems(Update, update_kira_channel::<$x>.after(AudioPlusSystem::UpdateAudioSources).before(AudioPlusSystem::Debug));
+                app.add_systems(Update, update_kira_channel::<$x>.after(AudioPlusSystem::AllocateVoices).before(AudioPlusSystem::Debug));
             )*
+            app.insert_resource(allocator);
+        }
+        pub fn add_headless_audio_channels(app: &mut App) {
+            let mut allocator = AudioPlusVoiceAllocator::default();
+            $(
+                allocator.add_channel::<$x>();
+            )*
+            app.insert_resource(allocator)
+                .init_resource::<AudioPlusRecorder>()
+                .add_systems(Update, update_headless_channels.after(AudioPlusSystem::AllocateVoices).before(AudioPlusSystem::Debug));
         }
     };
 }
@@ -26,108 +40,154 @@ fn f32_sufficient_difference(to: f32, from: f32) -> bool {
     return (from - to).abs() > 0.02 || (to == 0. && from != 0.);
 }
 
+/// What a channel needs from the audio backend.
+pub(crate) trait ChannelBackend {
+    fn play(&mut self, audio_source: Handle<AudioSource>, looped: bool);
+    fn stop(&mut self);
+    fn set_volume(&mut self, volume: f32);
+    fn set_panning(&mut self, panning: f32);
+    fn set_playback_rate(&mut self, playback_rate: f32);
+    /// Whether the last played sound is still going, `None` if nothing was played since the
+    /// last stop.
+    fn playing(&self) -> Option<bool>;
+}
+
 #[derive(Default)]
-struct ChannelData {
+pub(crate) struct ChannelData {
     initialized: bool,
     voice_handle: Option<AudioPlusVoiceHandle>,
-    instance_handle: Option<Handle<AudioInstance>>,
     last_volume: f32,
     last_panning: f32,
     last_playback_rate: f32,
 }
 
-fn update_kira_channel<T: Resource>(
-    mut data: Local<ChannelData>,
-    channel: Res<AudioChannel<T>>,
-    mut query: Query<(Entity, &mut AudioPlusSource)>,
+pub(crate) fn update_channel(
+    data: &mut ChannelData,
+    backend: &mut impl ChannelBackend,
+    voice_handle: Option<AudioPlusVoiceHandle>,
+    query: &mut Query<&mut AudioPlusSource>,
 ) {
     if !data.initialized {
-        channel.set_volume(0.);
+        backend.set_volume(0.);
         data.initialized = true;
     }
-    if let Some(voice_handle) = data.voice_handle {
-        let mut unassign = true;
-        if let Ok((_, mut source)) = query.get_mut(voice_handle.entity) {
-            if let Some(voice) = source.voices.get_mut(voice_handle.index) {
-                if voice.should_assign {
-                    unassign = false;
-                    if voice.state_dirty {
-                        match voice.state {
-                            AudioPlusVoiceState::Stopped => {
-                                data.instance_handle = None;
-                                channel.stop();
-                            }
-                            AudioPlusVoiceState::Playing => {
-                                data.instance_handle = None;
-                                channel.stop();
-                                if let Some(audio_source) = &voice.audio_source {
-                                    data.instance_handle =
-                                        Some(channel.play(audio_source.clone()).handle());
-                                }
-                            }
-                            AudioPlusVoiceState::Looping => {
-                                data.instance_handle = None;
-                                channel.stop();
-                                if let Some(audio_source) = &voice.audio_source {
-                                    data.instance_handle =
-                                        Some(channel.play(audio_source.clone()).looped().handle());
-                                }
-                            }
-                        }
-                        voice.state_dirty = false;
-                    }
-                    let new_volume = voice.volume * voice.volume_multiplier * voice.volume_fade;
-                    if f32_sufficient_difference(new_volume, data.last_volume) {
-                        channel.set_volume(
-                            (voice.volume * voice.volume_multiplier * voice.volume_fade) as f64,
-                        );
-                        data.last_volume = new_volume;
-                    }
-                    if f32_sufficient_difference(voice.panning, data.last_panning) {
-                        channel.set_panning(voice.panning as f64);
-                        data.last_panning = voice.panning;
-                    }
-                    if f32_sufficient_difference(voice.playback_rate, data.last_playback_rate) {
-                        channel.set_playback_rate(voice.playback_rate as f64);
-                        data.last_playback_rate = voice.playback_rate;
-                    }
-                    if let Some(instance_handle) = &data.instance_handle {
-                        let has_position = channel.state(&instance_handle).position().is_some();
-                        if voice.status.initialized {
-                            voice.status.playing = has_position;
-                        } else {
-                            voice.status.initialized = has_position;
-                            voice.status.playing = true;
-                        }
-                    }
-                }
-            }
-        }
-        if unassign {
-            channel.stop();
+    if data.voice_handle != voice_handle {
+        if data.voice_handle.is_some() {
+            backend.stop();
             if f32_sufficient_difference(0., data.last_volume) {
-                channel.set_volume(0.);
+                backend.set_volume(0.);
                 data.last_volume = 0.;
             }
-            data.voice_handle = None;
-            data.instance_handle = None;
         }
-    } else {
-        let mut found = false;
-        for (entity, mut source) in query.iter_mut() {
-            for (index, voice) in source.voices.iter_mut().enumerate() {
-                if voice.should_assign && !voice.assigned {
-                    data.voice_handle = Some(AudioPlusVoiceHandle { entity, index });
-                    voice.assigned = true;
-                    found = true;
-                    break;
+        data.voice_handle = voice_handle;
+    }
+    let Some(voice_handle) = data.voice_handle else { return };
+    let Ok(mut source) = query.get_mut(voice_handle.entity) else { return };
+    let Some(voice) = source.voices.get_mut(voice_handle.index) else { return };
+    if voice.state_dirty {
+        backend.stop();
+        match voice.state {
+            AudioPlusVoiceState::Stopped => {}
+            AudioPlusVoiceState::Playing => {
+                if let Some(audio_source) = &voice.audio_source {
+                    backend.play(audio_source.clone(), false);
                 }
             }
-            if found {
-                break;
+            AudioPlusVoiceState::Looping => {
+                if let Some(audio_source) = &voice.audio_source {
+                    backend.play(audio_source.clone(), true);
+                }
             }
         }
+        voice.state_dirty = false;
+    }
+    let new_volume = voice.volume * voice.volume_multiplier * voice.gain * voice.volume_fade;
+    if f32_sufficient_difference(new_volume, data.last_volume) {
+        backend.set_volume(new_volume);
+        data.last_volume = new_volume;
+    }
+    if f32_sufficient_difference(voice.panning, data.last_panning) {
+        backend.set_panning(voice.panning);
+        data.last_panning = voice.panning;
+    }
+    let new_playback_rate = voice.playback_rate * voice.playback_rate_multiplier;
+    if f32_sufficient_difference(new_playback_rate, data.last_playback_rate) {
+        backend.set_playback_rate(new_playback_rate);
+        data.last_playback_rate = new_playback_rate;
     }
+    if let Some(has_position) = backend.playing() {
+        if voice.status.initialized {
+            voice.status.playing = has_position;
+        } else {
+            voice.status.initialized = has_position;
+            voice.status.playing = true;
+        }
+    }
+}
+
+struct KiraChannel<'a, T: Resource> {
+    channel: &'a AudioChannel<T>,
+    instance_handle: &'a mut Option<Handle<AudioInstance>>,
+}
+
+impl<'a, T: Resource> ChannelBackend for KiraChannel<'a, T> {
+    fn play(&mut self, audio_source: Handle<AudioSource>, looped: bool) {
+        *self.instance_handle = Some(if looped {
+            self.channel.play(audio_source).looped().handle()
+        } else {
+            self.channel.play(audio_source).handle()
+        });
+    }
+
+    fn stop(&mut self) {
+        *self.instance_handle = None;
+        self.channel.stop();
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.channel.set_volume(volume as f64);
+    }
+
+    fn set_panning(&mut self, panning: f32) {
+        self.channel.set_panning(panning as f64);
+    }
+
+    fn set_playback_rate(&mut self, playback_rate: f32) {
+        self.channel.set_playback_rate(playback_rate as f64);
+    }
+
+    fn playing(&self) -> Option<bool> {
+        self.instance_handle
+            .as_ref()
+            .map(|instance_handle| self.channel.state(instance_handle).position().is_some())
+    }
+}
+
+#[derive(Default)]
+struct KiraChannelData {
+    channel: ChannelData,
+    instance_handle: Option<Handle<AudioInstance>>,
+}
+
+fn update_kira_channel<T: Resource>(
+    mut data: Local<KiraChannelData>,
+    channel: Res<AudioChannel<T>>,
+    allocator: Res<AudioPlusVoiceAllocator>,
+    mut query: Query<&mut AudioPlusSource>,
+) {
+    let KiraChannelData {
+        channel: channel_data,
+        instance_handle,
+    } = &mut *data;
+    update_channel(
+        channel_data,
+        &mut KiraChannel {
+            channel: &channel,
+            instance_handle,
+        },
+        allocator.channel_voice::<T>(),
+        &mut query,
+    );
 }
 
 channels!(
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::mixer::AudioPlusMixerChannel;
+use crate::{attenuation::AudioPlusAttenuation, mixer::AudioPlusMixerChannel};
 
 #[derive(Clone)]
 pub struct AudioPlusSoundEffect {
@@ -12,10 +12,15 @@ pub struct AudioPlusSoundEffect {
     pub pitch: f32,
     pub pitch_variation: f32,
     pub distance: f32,
+    pub min_distance: f32,
+    pub attenuation: AudioPlusAttenuation,
+    pub doppler: f32,
+    pub spread: f32,
     pub chance: f32,
     pub fade_in: f32,
     pub fade_out: f32,
     pub channel: AudioPlusMixerChannel,
+    pub priority: i32,
 }
 
 impl Default for AudioPlusSoundEffect {
@@ -29,10 +34,15 @@ impl Default for AudioPlusSoundEffect {
             pitch: 1.,
             pitch_variation: 0.,
             distance: 1000.,
+            min_distance: 0.,
+            attenuation: AudioPlusAttenuation::Linear,
+            doppler: 0.,
+            spread: 0.3,
             chance: 1.,
             fade_in: 0.,
             fade_out: 0.,
             channel: AudioPlusMixerChannel::None,
+            priority: 0,
         }
     }
 }
@@ -137,6 +147,62 @@ impl AudioPlusSoundEffect {
         self.distance
     }
 
+    pub fn with_min_distance(self, min_distance: f32) -> Self {
+        Self {
+            min_distance,
+            ..self
+        }
+    }
+
+    pub fn set_min_distance(&mut self, min_distance: f32) {
+        self.min_distance = min_distance;
+    }
+
+    pub fn min_distance(&self) -> f32 {
+        self.min_distance
+    }
+
+    pub fn with_attenuation(self, attenuation: AudioPlusAttenuation) -> Self {
+        Self {
+            attenuation,
+            ..self
+        }
+    }
+
+    pub fn set_attenuation(&mut self, attenuation: AudioPlusAttenuation) {
+        self.attenuation = attenuation;
+    }
+
+    pub fn attenuation(&self) -> AudioPlusAttenuation {
+        self.attenuation
+    }
+
+    /// Scales the doppler pitch shift from the source and listener velocities. 0 disables it.
+    pub fn with_doppler(self, doppler: f32) -> Self {
+        Self { doppler, ..self }
+    }
+
+    pub fn set_doppler(&mut self, doppler: f32) {
+        self.doppler = doppler;
+    }
+
+    pub fn doppler(&self) -> f32 {
+        self.doppler
+    }
+
+    /// How far from center a positional sound can pan, from 0 (mono) to 0.5 (hard left/right).
+    pub fn with_spread(self, spread: f32) -> Self {
+        Self { spread, ..self }
+    }
+
+    pub fn set_spread(&mut self, spread: f32) {
+        self.spread = spread;
+    }
+
+    pub fn spread(&self) -> f32 {
+        self.spread
+    }
+
     pub fn with_chance(self, chance: f32) -> Self {
         Self { chance, ..self }
     }
@@ -184,6 +250,20 @@ impl AudioPlusSoundEffect {
     pub fn channel(&self) -> AudioPlusMixerChannel {
         self.channel
     }
+
+    /// Voices with a higher priority take channels from lower priority voices when every channel
+    /// is in use.
+    pub fn with_priority(self, priority: i32) -> Self {
+        Self { priority, ..self }
+    }
+
+    pub fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
 }
 
 impl From<Handle<bevy_kira_audio::AudioSource>> for AudioPlusSoundEffect {
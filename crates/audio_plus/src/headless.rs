@@ -0,0 +1,165 @@
+use crate::{
+    allocator::AudioPlusVoiceAllocator,
+    channels::{update_channel, ChannelBackend, ChannelData},
+    source::AudioPlusSource,
+};
+use bevy::prelude::*;
+use bevy_kira_audio::AudioSource;
+
+/// A command a channel sent to the audio backend.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioPlusCommand {
+    Play {
+        audio_source: Handle<AudioSource>,
+        looped: bool,
+    },
+    Stop,
+    SetVolume(f32),
+    SetPanning(f32),
+    SetPlaybackRate(f32),
+}
+
+#[derive(Default)]
+struct HeadlessInstance {
+    looped: bool,
+    finished: bool,
+}
+
+#[derive(Default)]
+struct HeadlessChannel {
+    data: ChannelData,
+    instance: Option<HeadlessInstance>,
+}
+
+/// Stands in for the audio device when using
+/// [`AudioPlusHeadlessPlugin`](crate::AudioPlusHeadlessPlugin), and records what every channel
+/// was told to do. Sounds play until [`AudioPlusRecorder::finish`] is called.
+#[derive(Resource, Default)]
+pub struct AudioPlusRecorder {
+    commands: Vec<(usize, AudioPlusCommand)>,
+    channels: Vec<HeadlessChannel>,
+}
+
+impl AudioPlusRecorder {
+    /// Every command so far, with the index of the channel it was sent to.
+    pub fn commands(&self) -> &[(usize, AudioPlusCommand)] {
+        &self.commands
+    }
+
+    pub fn channel_commands(&self, channel: usize) -> impl Iterator<Item = &AudioPlusCommand> {
+        self.commands
+            .iter()
+            .filter(move |(index, _)| *index == channel)
+            .map(|(_, command)| command)
+    }
+
+    pub fn take_commands(&mut self) -> Vec<(usize, AudioPlusCommand)> {
+        std::mem::take(&mut self.commands)
+    }
+
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    pub fn playing(&self, channel: usize) -> bool {
+        self.channels
+            .get(channel)
+            .and_then(|channel| channel.instance.as_ref())
+            .map_or(false, |instance| !instance.finished)
+    }
+
+    pub fn playing_channels(&self) -> usize {
+        (0..self.channels.len())
+            .filter(|channel| self.playing(*channel))
+            .count()
+    }
+
+    /// Ends the sound on `channel` as if it reached its end. Looped sounds never end on their own,
+    /// so they are left playing.
+    pub fn finish(&mut self, channel: usize) {
+        if let Some(instance) = self
+            .channels
+            .get_mut(channel)
+            .and_then(|channel| channel.instance.as_mut())
+        {
+            if !instance.looped {
+                instance.finished = true;
+            }
+        }
+    }
+
+    pub fn finish_all(&mut self) {
+        for channel in 0..self.channels.len() {
+            self.finish(channel);
+        }
+    }
+}
+
+struct HeadlessBackend<'a> {
+    channel: usize,
+    instance: &'a mut Option<HeadlessInstance>,
+    commands: &'a mut Vec<(usize, AudioPlusCommand)>,
+}
+
+impl<'a> ChannelBackend for HeadlessBackend<'a> {
+    fn play(&mut self, audio_source: Handle<AudioSource>, looped: bool) {
+        *self.instance = Some(HeadlessInstance {
+            looped,
+            finished: false,
+        });
+        self.commands.push((
+            self.channel,
+            AudioPlusCommand::Play {
+                audio_source,
+                looped,
+            },
+        ));
+    }
+
+    fn stop(&mut self) {
+        *self.instance = None;
+        self.commands.push((self.channel, AudioPlusCommand::Stop));
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.commands
+            .push((self.channel, AudioPlusCommand::SetVolume(volume)));
+    }
+
+    fn set_panning(&mut self, panning: f32) {
+        self.commands
+            .push((self.channel, AudioPlusCommand::SetPanning(panning)));
+    }
+
+    fn set_playback_rate(&mut self, playback_rate: f32) {
+        self.commands.push((
+            self.channel,
+            AudioPlusCommand::SetPlaybackRate(playback_rate),
+        ));
+    }
+
+    fn playing(&self) -> Option<bool> {
+        self.instance.as_ref().map(|instance| !instance.finished)
+    }
+}
+
+pub(crate) fn update_headless_channels(
+    mut recorder: ResMut<AudioPlusRecorder>,
+    allocator: Res<AudioPlusVoiceAllocator>,
+    mut query: Query<&mut AudioPlusSource>,
+) {
+    let AudioPlusRecorder { commands, channels } = recorder.as_mut();
+    channels.resize_with(allocator.channel_count(), HeadlessChannel::default);
+    for (index, channel) in channels.iter_mut().enumerate() {
+        update_channel(
+            &mut channel.data,
+            &mut HeadlessBackend {
+                channel: index,
+                instance: &mut channel.instance,
+                commands,
+            },
+            allocator.channel_voice_at(index),
+            &mut query,
+        );
+    }
+}
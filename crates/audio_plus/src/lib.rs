@@ -1,12 +1,16 @@
 use bevy::prelude::*;
 use bevy_kira_audio::AudioPlugin;
-use channels::add_audio_channels;
+use channels::{add_audio_channels, add_headless_audio_channels};
 use mixer::AudioPlusMixer;
+use music::AudioPlusMusicDirector;
 use source::AudioPlusSource;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
 pub enum AudioPlusSystem {
+    UpdateMusic,
+    UpdateMixer,
     UpdateAudioSources,
+    AllocateVoices,
     Debug,
 }
 
@@ -14,19 +18,44 @@ pub struct AudioPlusPlugin;
 
 impl Plugin for AudioPlusPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(AudioPlugin)
-            .init_resource::<AudioPlusMixer>()
-            .add_systems(
-                Update,
-                (
-                    source::update_audio_sources.in_set(AudioPlusSystem::UpdateAudioSources),
-                    debug.in_set(AudioPlusSystem::Debug),
-                ),
-            );
+        app.add_plugins(AudioPlugin);
+        add_audio_systems(app);
         add_audio_channels(app);
     }
 }
 
+/// Runs everything [`AudioPlusPlugin`] does without an audio device. Channel commands are kept
+/// in the [`AudioPlusRecorder`](headless::AudioPlusRecorder) resource instead of being played.
+pub struct AudioPlusHeadlessPlugin;
+
+impl Plugin for AudioPlusHeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        add_audio_systems(app);
+        add_headless_audio_channels(app);
+    }
+}
+
+fn add_audio_systems(app: &mut App) {
+    app.init_resource::<AudioPlusMixer>()
+        .init_resource::<AudioPlusMusicDirector>()
+        .add_systems(
+            Update,
+            (
+                music::update_music_director
+                    .in_set(AudioPlusSystem::UpdateMusic)
+                    .before(AudioPlusSystem::UpdateAudioSources),
+                mixer::update_mixer
+                    .in_set(AudioPlusSystem::UpdateMixer)
+                    .before(AudioPlusSystem::UpdateAudioSources),
+                source::update_audio_sources.in_set(AudioPlusSystem::UpdateAudioSources),
+                allocator::allocate_voices
+                    .in_set(AudioPlusSystem::AllocateVoices)
+                    .after(AudioPlusSystem::UpdateAudioSources),
+                debug.in_set(AudioPlusSystem::Debug),
+            ),
+        );
+}
+
 fn debug(mut _query: Query<&mut AudioPlusSource>) {
     /*let mut unassigned_count = 0;
     let mut total = 0;
@@ -48,10 +77,14 @@ fn debug(mut _query: Query<&mut AudioPlusSource>) {
     }*/
 }
 
+pub mod allocator;
+pub mod attenuation;
 pub mod channels;
 pub mod effect;
+pub mod headless;
 pub mod listener;
 pub mod mixer;
+pub mod music;
 pub mod prelude;
 pub mod source;
 pub mod voice;
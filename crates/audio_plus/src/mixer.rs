@@ -1,25 +1,77 @@
-use bevy::prelude::*;
+use crate::{source::AudioPlusSource, voice::AudioPlusVoiceState};
+use bevy::{prelude::*, utils::HashSet};
 
-const CHANNEL_COUNT: usize = 2;
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum AudioPlusMixerChannel {
-    None = 0,
-    Music = 1,
-    Sfx = 2,
+    None,
+    Music,
+    Sfx,
+    /// A bus registered at runtime with [`AudioPlusMixer::add_bus`].
+    Bus(&'static str),
+}
+
+impl AudioPlusMixerChannel {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Self::None => "None",
+            Self::Music => "Music",
+            Self::Sfx => "Sfx",
+            Self::Bus(name) => name,
+        }
+    }
+}
+
+/// Lowers a bus while another bus is playing, such as music under dialogue.
+#[derive(Copy, Clone, Debug)]
+pub struct AudioPlusDucking {
+    /// Volume multiplier applied while the trigger bus is playing.
+    pub volume: f32,
+    /// Seconds to duck down.
+    pub attack: f32,
+    /// Seconds to come back up.
+    pub release: f32,
+}
+
+impl Default for AudioPlusDucking {
+    fn default() -> Self {
+        Self {
+            volume: 0.5,
+            attack: 0.2,
+            release: 1.,
+        }
+    }
+}
+
+struct MixerBus {
+    channel: AudioPlusMixerChannel,
+    parent: AudioPlusMixerChannel,
+    volume: f32,
+    ducking: f32,
+}
+
+struct MixerDucking {
+    target: AudioPlusMixerChannel,
+    trigger: AudioPlusMixerChannel,
+    ducking: AudioPlusDucking,
 }
 
 #[derive(Resource)]
 pub struct AudioPlusMixer {
     master_volume: f32,
-    volumes: [f32; CHANNEL_COUNT],
+    buses: Vec<MixerBus>,
+    duckings: Vec<MixerDucking>,
 }
 
 impl Default for AudioPlusMixer {
     fn default() -> Self {
-        Self {
+        let mut mixer = Self {
             master_volume: 1.,
-            volumes: [1.; CHANNEL_COUNT],
-        }
+            buses: vec![],
+            duckings: vec![],
+        };
+        mixer.add_bus(AudioPlusMixerChannel::Music, AudioPlusMixerChannel::None);
+        mixer.add_bus(AudioPlusMixerChannel::Sfx, AudioPlusMixerChannel::None);
+        mixer
     }
 }
 
@@ -31,17 +83,189 @@ impl AudioPlusMixer {
         self.master_volume
     }
     pub fn set_volume(&mut self, channel: AudioPlusMixerChannel, volume: f32) {
-        let channel_id = channel as usize;
-        if channel_id != 0 && channel_id <= CHANNEL_COUNT {
-            self.volumes[channel_id - 1] = volume;
+        if let Some(bus) = self.bus_mut(channel) {
+            bus.volume = volume;
         }
     }
     pub fn get_volume(&self, channel: AudioPlusMixerChannel) -> f32 {
-        let channel_id = channel as usize;
-        if channel_id != 0 && channel_id <= CHANNEL_COUNT {
-            self.volumes[channel_id - 1]
+        self.bus(channel).map(|bus| bus.volume).unwrap_or(1.)
+    }
+
+    /// Registers a bus under `parent`, or directly under master when `parent` is
+    /// [`AudioPlusMixerChannel::None`]. The parent must already be registered. Registering a bus
+    /// twice keeps the first registration.
+    pub fn add_bus(&mut self, channel: AudioPlusMixerChannel, parent: AudioPlusMixerChannel) {
+        if channel == AudioPlusMixerChannel::None || self.bus(channel).is_some() {
+            return;
+        }
+        let parent = if self.bus(parent).is_some() {
+            parent
         } else {
-            1.
+            if parent != AudioPlusMixerChannel::None {
+                warn!(
+                    "Mixer bus {} added under unknown parent {}",
+                    channel.name(),
+                    parent.name()
+                );
+            }
+            AudioPlusMixerChannel::None
+        };
+        self.buses.push(MixerBus {
+            channel,
+            parent,
+            volume: 1.,
+            ducking: 1.,
+        });
+    }
+
+    /// Ducks `target` whenever a voice is playing on `trigger` or any bus nested under it.
+    pub fn add_ducking(
+        &mut self,
+        target: AudioPlusMixerChannel,
+        trigger: AudioPlusMixerChannel,
+        ducking: AudioPlusDucking,
+    ) {
+        self.duckings.push(MixerDucking {
+            target,
+            trigger,
+            ducking,
+        });
+    }
+
+    /// Registered buses, parents before their children.
+    pub fn buses(&self) -> impl Iterator<Item = AudioPlusMixerChannel> + '_ {
+        self.buses.iter().map(|bus| bus.channel)
+    }
+
+    pub fn get_parent(&self, channel: AudioPlusMixerChannel) -> AudioPlusMixerChannel {
+        self.bus(channel)
+            .map(|bus| bus.parent)
+            .unwrap_or(AudioPlusMixerChannel::None)
+    }
+
+    /// How many buses sit between `channel` and master.
+    pub fn get_depth(&self, channel: AudioPlusMixerChannel) -> usize {
+        self.ancestors(channel).count().saturating_sub(1)
+    }
+
+    /// Current ducking multiplier of a bus, 1 when it isn't ducked.
+    pub fn get_ducking(&self, channel: AudioPlusMixerChannel) -> f32 {
+        self.bus(channel).map(|bus| bus.ducking).unwrap_or(1.)
+    }
+
+    /// Volume a voice on `channel` plays at: the bus, its parents and master, including ducking.
+    pub fn get_effective_volume(&self, channel: AudioPlusMixerChannel) -> f32 {
+        self.ancestors(channel)
+            .filter_map(|channel| self.bus(channel))
+            .fold(self.master_volume, |volume, bus| {
+                volume * bus.volume * bus.ducking
+            })
+    }
+
+    fn bus(&self, channel: AudioPlusMixerChannel) -> Option<&MixerBus> {
+        self.buses.iter().find(|bus| bus.channel == channel)
+    }
+
+    fn bus_mut(&mut self, channel: AudioPlusMixerChannel) -> Option<&mut MixerBus> {
+        self.buses.iter_mut().find(|bus| bus.channel == channel)
+    }
+
+    /// `channel` followed by each of its parents.
+    fn ancestors(
+        &self,
+        channel: AudioPlusMixerChannel,
+    ) -> impl Iterator<Item = AudioPlusMixerChannel> + '_ {
+        std::iter::successors(Some(channel), |channel| {
+            self.bus(*channel).map(|bus| bus.parent)
+        })
+        .take_while(|channel| *channel != AudioPlusMixerChannel::None)
+        .take(self.buses.len())
+    }
+}
+
+pub trait AddAppAudioPlusMixer {
+    fn add_audio_bus(
+        &mut self,
+        channel: AudioPlusMixerChannel,
+        parent: AudioPlusMixerChannel,
+    ) -> &mut Self;
+    fn add_audio_ducking(
+        &mut self,
+        target: AudioPlusMixerChannel,
+        trigger: AudioPlusMixerChannel,
+        ducking: AudioPlusDucking,
+    ) -> &mut Self;
+}
+
+impl AddAppAudioPlusMixer for App {
+    fn add_audio_bus(
+        &mut self,
+        channel: AudioPlusMixerChannel,
+        parent: AudioPlusMixerChannel,
+    ) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(AudioPlusMixer::default)
+            .add_bus(channel, parent);
+        self
+    }
+
+    fn add_audio_ducking(
+        &mut self,
+        target: AudioPlusMixerChannel,
+        trigger: AudioPlusMixerChannel,
+        ducking: AudioPlusDucking,
+    ) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(AudioPlusMixer::default)
+            .add_ducking(target, trigger, ducking);
+        self
+    }
+}
+
+pub(crate) fn update_mixer(
+    mut mixer: ResMut<AudioPlusMixer>,
+    query: Query<&AudioPlusSource>,
+    time: Res<Time>,
+) {
+    let mut playing = HashSet::new();
+    for source in query.iter() {
+        if source
+            .voices
+            .iter()
+            .any(|voice| voice.should_assign && voice.state != AudioPlusVoiceState::Stopped)
+        {
+            playing.extend(mixer.ancestors(source.sound_effect.channel));
+        }
+    }
+    let AudioPlusMixer {
+        buses, duckings, ..
+    } = mixer.as_mut();
+    for bus in buses.iter_mut() {
+        let mut target = 1_f32;
+        let mut attack = 0_f32;
+        let mut release = 0_f32;
+        for ducking in duckings
+            .iter()
+            .filter(|ducking| ducking.target == bus.channel)
+        {
+            if playing.contains(&ducking.trigger) {
+                target = target.min(ducking.ducking.volume);
+                attack = attack.max(ducking.ducking.attack);
+            }
+            release = release.max(ducking.ducking.release);
+        }
+        if bus.ducking > target {
+            bus.ducking = if attack > 0. {
+                (bus.ducking - time.delta_seconds() / attack).max(target)
+            } else {
+                target
+            };
+        } else if bus.ducking < target {
+            bus.ducking = if release > 0. {
+                (bus.ducking + time.delta_seconds() / release).min(target)
+            } else {
+                target
+            };
         }
     }
 }
@@ -0,0 +1,372 @@
+use crate::{effect::AudioPlusSoundEffect, source::AudioPlusSource};
+use bevy::prelude::*;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum AudioPlusQuantize {
+    Immediate,
+    Beat,
+    #[default]
+    Bar,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct AudioPlusTempoSection {
+    /// Seconds from the start of the music.
+    pub start: f32,
+    pub bpm: f32,
+    pub beats_per_bar: u32,
+}
+
+/// Where the beats and bars fall in a piece of music.
+#[derive(Clone, Debug)]
+pub struct AudioPlusTempoMap {
+    sections: Vec<AudioPlusTempoSection>,
+    length: Option<f32>,
+}
+
+impl Default for AudioPlusTempoMap {
+    fn default() -> Self {
+        Self::new(120., 4)
+    }
+}
+
+impl AudioPlusTempoMap {
+    pub fn new(bpm: f32, beats_per_bar: u32) -> Self {
+        Self {
+            sections: vec![AudioPlusTempoSection {
+                start: 0.,
+                bpm,
+                beats_per_bar,
+            }],
+            length: None,
+        }
+    }
+
+    /// Changes tempo from `start` seconds on.
+    pub fn with_section(mut self, start: f32, bpm: f32, beats_per_bar: u32) -> Self {
+        self.sections.retain(|section| section.start != start);
+        self.sections.push(AudioPlusTempoSection {
+            start,
+            bpm,
+            beats_per_bar,
+        });
+        self.sections.sort_by(|a, b| a.start.total_cmp(&b.start));
+        self
+    }
+
+    /// Loop length in seconds. The loop point always counts as a boundary.
+    pub fn with_length(self, length: f32) -> Self {
+        Self {
+            length: Some(length),
+            ..self
+        }
+    }
+
+    pub fn sections(&self) -> &[AudioPlusTempoSection] {
+        &self.sections
+    }
+
+    pub fn length(&self) -> Option<f32> {
+        self.length
+    }
+
+    /// Position within the loop, in seconds.
+    pub fn position(&self, time: f32) -> f32 {
+        match self.length {
+            Some(length) if length > 0. => time.rem_euclid(length),
+            _ => time,
+        }
+    }
+
+    /// Zero-based bar and beat at `time`.
+    pub fn bar_and_beat(&self, time: f32) -> (u32, u32) {
+        let position = self.position(time);
+        let mut bar = 0;
+        for (index, section) in self.sections.iter().enumerate() {
+            if section.start > position {
+                break;
+            }
+            let end = self
+                .sections
+                .get(index + 1)
+                .map(|next| next.start.min(position))
+                .unwrap_or(position);
+            let beats = ((end - section.start) / section.beat_length()).floor() as u32;
+            let beats_per_bar = section.beats_per_bar.max(1);
+            if end == position {
+                return (bar + beats / beats_per_bar, beats % beats_per_bar);
+            }
+            bar += (beats + beats_per_bar - 1) / beats_per_bar;
+        }
+        (bar, 0)
+    }
+
+    /// First beat or bar boundary at or after `time`.
+    pub fn next_boundary(&self, time: f32, quantize: AudioPlusQuantize) -> f32 {
+        if quantize == AudioPlusQuantize::Immediate {
+            return time;
+        }
+        let position = self.position(time);
+        let loop_start = time - position;
+        let index = self
+            .sections
+            .iter()
+            .rposition(|section| section.start <= position)
+            .unwrap_or(0);
+        let section = self.sections[index];
+        let step = match quantize {
+            AudioPlusQuantize::Beat => section.beat_length(),
+            _ => section.beat_length() * section.beats_per_bar.max(1) as f32,
+        };
+        let mut boundary = section.start + ((position - section.start) / step).ceil() * step;
+        if let Some(next) = self.sections.get(index + 1) {
+            boundary = boundary.min(next.start);
+        }
+        if let Some(length) = self.length {
+            boundary = boundary.min(length);
+        }
+        loop_start + boundary
+    }
+}
+
+impl AudioPlusTempoSection {
+    fn beat_length(&self) -> f32 {
+        60. / self.bpm.max(1.)
+    }
+}
+
+/// Marks the entities the music director plays its layers on.
+#[derive(Component)]
+pub struct AudioPlusMusicLayer;
+
+struct MusicLayer {
+    name: &'static str,
+    sound_effect: AudioPlusSoundEffect,
+    entity: Option<Entity>,
+    gain: f32,
+}
+
+struct MusicState {
+    name: &'static str,
+    layers: Vec<&'static str>,
+}
+
+/// Plays music as a set of layers that loop in sync. Each state unmutes some of the layers, and
+/// switching states crossfades the layers on the next beat or bar, using the fade in and fade out
+/// of each layer's sound effect. Ducking is left to the mixer, see
+/// [`AudioPlusMixer::add_ducking`](crate::mixer::AudioPlusMixer::add_ducking).
+#[derive(Resource)]
+pub struct AudioPlusMusicDirector {
+    tempo: AudioPlusTempoMap,
+    quantize: AudioPlusQuantize,
+    layers: Vec<MusicLayer>,
+    states: Vec<MusicState>,
+    state: Option<&'static str>,
+    next_state: Option<&'static str>,
+    transition_at: Option<f32>,
+    playing: bool,
+    time: f32,
+}
+
+impl Default for AudioPlusMusicDirector {
+    fn default() -> Self {
+        Self {
+            tempo: AudioPlusTempoMap::default(),
+            quantize: AudioPlusQuantize::default(),
+            layers: vec![],
+            states: vec![],
+            state: None,
+            next_state: None,
+            transition_at: None,
+            playing: false,
+            time: 0.,
+        }
+    }
+}
+
+impl AudioPlusMusicDirector {
+    pub fn set_tempo(&mut self, tempo: AudioPlusTempoMap) {
+        self.tempo = tempo;
+    }
+
+    pub fn tempo(&self) -> &AudioPlusTempoMap {
+        &self.tempo
+    }
+
+    pub fn set_quantize(&mut self, quantize: AudioPlusQuantize) {
+        self.quantize = quantize;
+    }
+
+    pub fn quantize(&self) -> AudioPlusQuantize {
+        self.quantize
+    }
+
+    pub fn add_layer(&mut self, name: &'static str, sound_effect: AudioPlusSoundEffect) {
+        self.layers.push(MusicLayer {
+            name,
+            sound_effect,
+            entity: None,
+            gain: 0.,
+        });
+    }
+
+    pub fn add_state(&mut self, name: &'static str, layers: &[&'static str]) {
+        self.states.push(MusicState {
+            name,
+            layers: layers.to_vec(),
+        });
+    }
+
+    /// Removes every layer and state. Layers that are playing are despawned by the next update.
+    pub fn clear(&mut self) {
+        self.layers.clear();
+        self.states.clear();
+        self.state = None;
+        self.next_state = None;
+        self.transition_at = None;
+    }
+
+    /// Switches to `state` on the next boundary, or fades out on `None`.
+    pub fn set_state(&mut self, state: Option<&'static str>) {
+        if self.next_state != state {
+            self.next_state = state;
+            self.transition_at = None;
+        }
+    }
+
+    pub fn state(&self) -> Option<&'static str> {
+        self.state
+    }
+
+    pub fn next_state(&self) -> Option<&'static str> {
+        self.next_state
+    }
+
+    /// Music time the pending state change happens at.
+    pub fn transition_at(&self) -> Option<f32> {
+        self.transition_at
+    }
+
+    pub fn playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Seconds since the layers started playing.
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    pub fn layer_gain(&self, name: &str) -> f32 {
+        self.layers
+            .iter()
+            .find(|layer| layer.name == name)
+            .map(|layer| layer.gain)
+            .unwrap_or(0.)
+    }
+
+    fn layer_active(&self, name: &str) -> bool {
+        self.states
+            .iter()
+            .find(|state| Some(state.name) == self.state)
+            .map_or(false, |state| state.layers.contains(&name))
+    }
+}
+
+pub(crate) fn update_music_director(
+    mut commands: Commands,
+    mut director: ResMut<AudioPlusMusicDirector>,
+    mut query: Query<(Entity, &mut AudioPlusSource), With<AudioPlusMusicLayer>>,
+    time: Res<Time>,
+) {
+    let director = director.as_mut();
+    let delta = time.delta_seconds();
+
+    if director.next_state != director.state {
+        if !director.playing {
+            if director.next_state.is_some() {
+                // start every layer together so they stay in sync, muted until their state is on
+                for layer in director.layers.iter_mut() {
+                    layer.gain = 0.;
+                    layer.entity = None;
+                }
+                director.playing = true;
+                director.time = 0.;
+                director.state = director.next_state;
+            }
+        } else {
+            let transition_at = match director.transition_at {
+                Some(transition_at) => transition_at,
+                None => director
+                    .tempo
+                    .next_boundary(director.time, director.quantize),
+            };
+            director.transition_at = Some(transition_at);
+            if director.time >= transition_at {
+                director.state = director.next_state;
+                director.transition_at = None;
+            }
+        }
+    }
+
+    if director.playing {
+        director.time += delta;
+        // layers despawned from outside start over
+        for layer in director.layers.iter_mut() {
+            if layer
+                .entity
+                .map_or(false, |entity| query.get(entity).is_err())
+            {
+                layer.entity = None;
+            }
+        }
+        for index in 0..director.layers.len() {
+            let target = if director.layer_active(director.layers[index].name) {
+                1.
+            } else {
+                0.
+            };
+            let layer = &mut director.layers[index];
+            if layer.entity.is_none() {
+                let mut source = AudioPlusSource::new(layer.sound_effect.clone()).as_looping();
+                source.set_gain(0.);
+                layer.entity = Some(commands.spawn((source, AudioPlusMusicLayer)).id());
+            }
+            let fade = if target > layer.gain {
+                layer.sound_effect.fade_in
+            } else {
+                layer.sound_effect.fade_out
+            };
+            layer.gain = approach(layer.gain, target, fade, delta);
+        }
+        if director.state.is_none() && director.layers.iter().all(|layer| layer.gain == 0.) {
+            director.playing = false;
+        }
+    }
+
+    for (entity, mut source) in query.iter_mut() {
+        let layer = director
+            .layers
+            .iter()
+            .find(|layer| layer.entity == Some(entity));
+        match layer {
+            Some(layer) if director.playing => source.set_gain(layer.gain),
+            _ => commands.entity(entity).despawn_recursive(),
+        }
+    }
+    if !director.playing {
+        for layer in director.layers.iter_mut() {
+            layer.entity = None;
+        }
+    }
+}
+
+/// Moves `value` towards `target`, covering the full 0 to 1 range in `seconds`.
+fn approach(value: f32, target: f32, seconds: f32, delta: f32) -> f32 {
+    if seconds <= 0. {
+        target
+    } else if value < target {
+        (value + delta / seconds).min(target)
+    } else {
+        (value - delta / seconds).max(target)
+    }
+}
@@ -1,7 +1,14 @@
 pub use super::{
+    allocator::{AudioPlusChannelUsage, AudioPlusVoiceAllocator},
+    attenuation::AudioPlusAttenuation,
     effect::AudioPlusSoundEffect,
+    headless::{AudioPlusCommand, AudioPlusRecorder},
     listener::AudioPlusListener,
-    mixer::{AudioPlusMixer, AudioPlusMixerChannel},
+    mixer::{AddAppAudioPlusMixer, AudioPlusDucking, AudioPlusMixer, AudioPlusMixerChannel},
+    music::{
+        AudioPlusMusicDirector, AudioPlusMusicLayer, AudioPlusQuantize, AudioPlusTempoMap,
+        AudioPlusTempoSection,
+    },
     source::AudioPlusSource,
-    AudioPlusPlugin,
+    AudioPlusHeadlessPlugin, AudioPlusPlugin,
 };
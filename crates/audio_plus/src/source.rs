@@ -1,16 +1,23 @@
 use crate::{
     effect::AudioPlusSoundEffect,
     listener::AudioPlusListener,
-    mixer::{AudioPlusMixer, AudioPlusMixerChannel},
+    mixer::AudioPlusMixer,
     voice::{AudioPlusVoice, AudioPlusVoiceState},
 };
 use bevy::prelude::*;
 
+/// In world units per second. Only affects doppler.
+const SPEED_OF_SOUND: f32 = 3000.;
+const DOPPLER_MIN: f32 = 0.5;
+const DOPPLER_MAX: f32 = 2.;
+
 #[derive(Component)]
 pub struct AudioPlusSource {
     pub(crate) sound_effect: AudioPlusSoundEffect,
     pub(crate) voices: Vec<AudioPlusVoice>,
     pub(crate) next_voice: usize,
+    pub(crate) last_position: Option<Vec2>,
+    pub(crate) gain: f32,
 }
 
 impl AudioPlusSource {
@@ -19,6 +26,8 @@ impl AudioPlusSource {
             sound_effect,
             voices: vec![],
             next_voice: 0,
+            last_position: None,
+            gain: 1.,
         }
     }
 
@@ -92,6 +101,16 @@ impl AudioPlusSource {
         }
     }
 
+    /// Volume multiplier for the whole source. Unlike volume, voices keep playing and hold their
+    /// channel while the gain is 0, so they stay in sync with other sources.
+    pub fn set_gain(&mut self, gain: f32) {
+        self.gain = gain.clamp(0., 1.);
+    }
+
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
     pub fn effect(&self) -> &AudioPlusSoundEffect {
         &self.sound_effect
     }
@@ -106,36 +125,56 @@ pub(crate) fn update_audio_sources(
         Query<(&mut AudioPlusSource, Option<&GlobalTransform>)>,
         Query<&GlobalTransform, With<AudioPlusListener>>,
     )>,
+    mut listener_last_position: Local<Option<Vec2>>,
     time: Res<Time>,
     mixer: Res<AudioPlusMixer>,
 ) {
-    let listener_transform = if let Ok(transform) = queries.p1().get_single() {
-        Some(*transform)
+    let listener_position = if let Ok(transform) = queries.p1().get_single() {
+        Some(transform.translation().truncate())
     } else {
         None
     };
+    let listener_velocity = velocity(*listener_last_position, listener_position, &time);
+    *listener_last_position = listener_position;
     for (mut source, transform) in queries.p0().iter_mut() {
         source.create_voices();
+        let position = transform.map(|transform| transform.translation().truncate());
+        let source_velocity = velocity(source.last_position, position, &time);
+        source.last_position = position;
         let mut volume = 1.;
         let mut panning = 0.5;
-        if source.sound_effect.positional && transform.is_some() && listener_transform.is_some() {
-            let relative_position = transform.unwrap().translation().truncate()
-                - listener_transform.unwrap().translation().truncate();
+        let mut playback_rate = 1.;
+        if let (true, Some(position), Some(listener_position)) =
+            (source.sound_effect.positional, position, listener_position)
+        {
+            let sound_effect = &source.sound_effect;
+            let relative_position = position - listener_position;
             let distance = relative_position.length();
-            volume *= ((source.sound_effect.distance - distance) / source.sound_effect.distance)
-                .clamp(0., 1.);
+            volume *= sound_effect.attenuation.volume(
+                distance,
+                sound_effect.min_distance,
+                sound_effect.distance,
+            );
+            let spread = sound_effect.spread.clamp(0., 0.5);
             panning =
-                (0.5 + (relative_position.x / source.sound_effect.distance) * 1.2).clamp(0.2, 0.8);
-        }
-        if source.sound_effect.channel != AudioPlusMixerChannel::None {
-            volume *= mixer.get_volume(source.sound_effect.channel);
+                0.5 + (relative_position.x / sound_effect.distance * 1.2).clamp(-spread, spread);
+            if sound_effect.doppler > 0. {
+                let direction = relative_position.normalize_or_zero();
+                let listener_speed = listener_velocity.dot(direction) * sound_effect.doppler;
+                let source_speed = source_velocity.dot(direction) * sound_effect.doppler;
+                playback_rate = ((SPEED_OF_SOUND + listener_speed)
+                    / (SPEED_OF_SOUND + source_speed).max(1.))
+                .clamp(DOPPLER_MIN, DOPPLER_MAX);
+            }
         }
-        volume *= mixer.get_master_volume();
+        volume *= mixer.get_effective_volume(source.sound_effect.channel);
         let AudioPlusSource {
             voices,
             sound_effect,
+            gain,
             ..
         } = source.as_mut();
+        let gain = *gain;
         for voice in voices.iter_mut() {
             if voice.status.initialized && !voice.status.playing {
                 voice.reset();
@@ -161,9 +200,26 @@ pub(crate) fn update_audio_sources(
                     voice.should_assign =
                         voice.state != AudioPlusVoiceState::Stopped && volume > 0.;
                     voice.volume_multiplier = volume;
+                    voice.gain = gain;
                     voice.panning = panning;
+                    voice.playback_rate_multiplier = playback_rate;
                 }
             }
         }
     }
 }
+
+fn velocity(last_position: Option<Vec2>, position: Option<Vec2>, time: &Time) -> Vec2 {
+    match (last_position, position) {
+        (Some(last_position), Some(position)) if time.delta_seconds() > 0. => {
+            let velocity = (position - last_position) / time.delta_seconds();
+            // anything faster than sound was a teleport, or a transform that wasn't propagated yet
+            if velocity.length() < SPEED_OF_SOUND {
+                velocity
+            } else {
+                Vec2::ZERO
+            }
+        }
+        _ => Vec2::ZERO,
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) struct AudioPlusVoiceHandle {
     pub(crate) entity: Entity,
     pub(crate) index: usize,
@@ -12,9 +12,12 @@ pub(crate) struct AudioPlusVoice {
     pub(crate) audio_source: Option<Handle<bevy_kira_audio::AudioSource>>,
     pub(crate) volume: f32,
     pub(crate) volume_multiplier: f32,
+    /// Source gain, kept out of `volume_multiplier` so it doesn't count towards voice stealing.
+    pub(crate) gain: f32,
     pub(crate) volume_fade: f32,
     pub(crate) panning: f32,
     pub(crate) playback_rate: f32,
+    pub(crate) playback_rate_multiplier: f32,
     pub(crate) state: AudioPlusVoiceState,
     pub(crate) state_dirty: bool,
     pub(crate) stopping: bool,
@@ -35,9 +38,11 @@ impl AudioPlusVoice {
             audio_source: None,
             volume: 0.,
             volume_multiplier: 1.,
+            gain: 1.,
             volume_fade: 0.,
             panning: 0.5,
             playback_rate: 1.,
+            playback_rate_multiplier: 1.,
             state: AudioPlusVoiceState::Stopped,
             state_dirty: false,
             stopping: false,
@@ -51,9 +56,11 @@ impl AudioPlusVoice {
         self.audio_source = None;
         self.volume = 0.;
         self.volume_multiplier = 1.;
+        self.gain = 1.;
         self.volume_fade = 0.;
         self.panning = 0.5;
         self.playback_rate = 1.;
+        self.playback_rate_multiplier = 1.;
         self.state = AudioPlusVoiceState::Stopped;
         self.stopping = false;
         self.status = AudioPlusVoiceStatus::default();
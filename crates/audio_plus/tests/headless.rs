@@ -0,0 +1,194 @@
+use audio_plus::prelude::*;
+use bevy::{asset::HandleId, prelude::*, time::TimeUpdateStrategy};
+use bevy_kira_audio::AudioSource;
+use std::time::Duration;
+
+const FRAME: f32 = 0.1;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(AudioPlusHeadlessPlugin)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            FRAME,
+        )));
+    // the first frame has no delta
+    app.update();
+    app
+}
+
+fn sound() -> Handle<AudioSource> {
+    Handle::weak(HandleId::random::<AudioSource>())
+}
+
+fn volumes(recorder: &AudioPlusRecorder) -> Vec<f32> {
+    recorder
+        .commands()
+        .iter()
+        .filter_map(|(_, command)| match command {
+            AudioPlusCommand::SetVolume(volume) if *volume > 0. => Some(*volume),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn assignment() {
+    let mut app = app();
+    let effect = AudioPlusSoundEffect::single(sound()).with_voices(2);
+    let entity = app.world.spawn(AudioPlusSource::new(effect)).id();
+    for _ in 0..3 {
+        app.world.get_mut::<AudioPlusSource>(entity).unwrap().play();
+        app.update();
+    }
+    let recorder = app.world.resource::<AudioPlusRecorder>();
+    let plays = recorder
+        .commands()
+        .iter()
+        .filter(|(_, command)| matches!(command, AudioPlusCommand::Play { looped: false, .. }))
+        .count();
+    assert_eq!(plays, 3, "every play reaches a channel");
+    assert_eq!(
+        recorder.playing_channels(),
+        2,
+        "the third play reuses the first voice"
+    );
+
+    app.world.resource_mut::<AudioPlusRecorder>().finish_all();
+    app.update();
+    app.update();
+    let allocator = app.world.resource::<AudioPlusVoiceAllocator>();
+    assert_eq!(
+        allocator.used_channels(),
+        0,
+        "finished voices free their channels"
+    );
+}
+
+#[test]
+fn fade() {
+    let mut app = app();
+    let effect = AudioPlusSoundEffect::single(sound()).with_fade(0.5, 0.5);
+    let entity = app
+        .world
+        .spawn(AudioPlusSource::new(effect).as_looping())
+        .id();
+    for _ in 0..6 {
+        app.update();
+    }
+    let fade_in = volumes(app.world.resource::<AudioPlusRecorder>());
+    assert!(fade_in.len() > 2, "fade in takes several frames");
+    assert!(fade_in.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(fade_in.last().map_or(false, |volume| *volume > 0.98));
+
+    app.world.resource_mut::<AudioPlusRecorder>().clear();
+    app.world.get_mut::<AudioPlusSource>(entity).unwrap().stop();
+    for _ in 0..8 {
+        app.update();
+    }
+    let recorder = app.world.resource::<AudioPlusRecorder>();
+    let fade_out = volumes(recorder);
+    assert!(fade_out.len() > 2, "fade out takes several frames");
+    assert!(fade_out.windows(2).all(|pair| pair[0] > pair[1]));
+    assert!(recorder
+        .commands()
+        .iter()
+        .any(|(_, command)| *command == AudioPlusCommand::Stop));
+    assert_eq!(recorder.playing_channels(), 0);
+}
+
+#[test]
+fn chance() {
+    let mut app = app();
+    let effect = AudioPlusSoundEffect::single(sound()).with_chance(0.);
+    let entity = app.world.spawn(AudioPlusSource::new(effect)).id();
+    for _ in 0..20 {
+        app.world.get_mut::<AudioPlusSource>(entity).unwrap().play();
+        app.update();
+    }
+    let recorder = app.world.resource::<AudioPlusRecorder>();
+    assert!(
+        !recorder
+            .commands()
+            .iter()
+            .any(|(_, command)| matches!(command, AudioPlusCommand::Play { .. })),
+        "a sound with no chance never plays"
+    );
+}
+
+#[test]
+fn chance_fraction() {
+    let mut app = app();
+    let effect = AudioPlusSoundEffect::single(sound()).with_chance(0.5);
+    let entity = app.world.spawn(AudioPlusSource::new(effect)).id();
+    for _ in 0..200 {
+        app.world.get_mut::<AudioPlusSource>(entity).unwrap().play();
+        app.update();
+    }
+    let recorder = app.world.resource::<AudioPlusRecorder>();
+    let plays = recorder
+        .commands()
+        .iter()
+        .filter(|(_, command)| matches!(command, AudioPlusCommand::Play { .. }))
+        .count();
+    assert!(
+        (60..=140).contains(&plays),
+        "about half of the plays go through: {}",
+        plays
+    );
+}
+
+#[test]
+fn volume_variation() {
+    let mut app = app();
+    let effect = AudioPlusSoundEffect::single(sound()).with_volume(0.5, 0.4);
+    let entity = app.world.spawn(AudioPlusSource::new(effect)).id();
+    for _ in 0..20 {
+        app.world.get_mut::<AudioPlusSource>(entity).unwrap().play();
+        app.update();
+    }
+    let volumes = volumes(app.world.resource::<AudioPlusRecorder>());
+    assert!(!volumes.is_empty());
+    assert!(
+        volumes.iter().all(|volume| (0.3..=0.7).contains(volume)),
+        "volumes stay within the variation: {:?}",
+        volumes
+    );
+}
+
+#[test]
+fn panning() {
+    let mut app = app();
+    app.world
+        .spawn((AudioPlusListener, GlobalTransform::default()));
+    let effect = AudioPlusSoundEffect::single(sound())
+        .with_positional(true)
+        .with_distance(1000.);
+    app.world.spawn((
+        AudioPlusSource::new(effect.clone()).as_looping(),
+        GlobalTransform::from_translation(Vec3::new(-500., 0., 0.)),
+    ));
+    app.world.spawn((
+        AudioPlusSource::new(effect).as_looping(),
+        GlobalTransform::from_translation(Vec3::new(500., 0., 0.)),
+    ));
+    app.update();
+    let recorder = app.world.resource::<AudioPlusRecorder>();
+    let mut pannings = recorder
+        .commands()
+        .iter()
+        .filter_map(|(_, command)| match command {
+            AudioPlusCommand::SetPanning(panning) => Some(*panning),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    pannings.sort_by(f32::total_cmp);
+    assert_eq!(pannings.len(), 2);
+    assert!(pannings[0] < 0.5, "left source pans left");
+    assert!(pannings[1] > 0.5, "right source pans right");
+    let volumes = volumes(recorder);
+    assert!(
+        volumes.iter().all(|volume| *volume < 1.),
+        "distance attenuates"
+    );
+}
@@ -0,0 +1,59 @@
+//! cargo bench --bench combine
+
+use grid_combiner::{CombineMethod, GridCombiner, GridPoint};
+use std::time::Instant;
+
+const SIZE: i64 = 120;
+const DENSITY: u64 = 85;
+const ITERATIONS: u32 = 20;
+const EDITS: i64 = 100;
+
+fn points() -> Vec<GridPoint> {
+    let mut state: u64 = 0x5eed;
+    let mut points = vec![];
+    for x in 0..SIZE {
+        for y in 0..SIZE {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state % 100 < DENSITY {
+                points.push(GridPoint::new(x, y));
+            }
+        }
+    }
+    points
+}
+
+fn bench(name: &str, mut run: impl FnMut()) {
+    run();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        run();
+    }
+    let average = start.elapsed() / ITERATIONS;
+    println!("{:<32} {:>12?}", name, average);
+}
+
+fn main() {
+    let points = points();
+    for method in [CombineMethod::Greedy, CombineMethod::Optimal] {
+        let mut grid_combiner = GridCombiner::new().with_method(method);
+        for point in points.iter() {
+            grid_combiner.add_point(*point);
+        }
+        bench(&format!("{:?} combine", method), || {
+            std::hint::black_box(grid_combiner.combine());
+        });
+
+        grid_combiner.recombine();
+        bench(&format!("{:?} {} point edits", method, EDITS), || {
+            for i in 0..EDITS {
+                let point = GridPoint::new(i, i);
+                if !grid_combiner.remove_point(point) {
+                    grid_combiner.add_point(point);
+                }
+                grid_combiner.recombine();
+            }
+        });
+    }
+}
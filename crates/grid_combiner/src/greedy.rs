@@ -0,0 +1,101 @@
+use crate::{GridPoint, GridRect};
+use std::collections::{HashMap, HashSet};
+
+pub(crate) fn combine(mut points: HashSet<GridPoint>) -> Vec<GridRect> {
+    let mut candidates: HashMap<GridPoint, [GridRect; 2]> = points
+        .iter()
+        .map(|point| (*point, largest_rects(&points, *point)))
+        .collect();
+    let mut rects = vec![];
+    while !points.is_empty() {
+        let mut unique: Vec<GridRect> = candidates
+            .values()
+            .flatten()
+            .copied()
+            .collect::<HashSet<GridRect>>()
+            .into_iter()
+            .collect();
+        unique.sort_by_key(|rect| (-rect.size(), rect.from.y, rect.from.x, rect.to.y, rect.to.x));
+        let mut chosen: Vec<GridRect> = vec![];
+        'outer: for candidate in unique {
+            for other in chosen.iter() {
+                if other.overlaps(candidate) {
+                    continue 'outer;
+                }
+            }
+            chosen.push(candidate);
+        }
+        for rect in chosen.iter() {
+            for point in rect.points() {
+                points.remove(&point);
+                candidates.remove(&point);
+            }
+        }
+        // A candidate that doesn't overlap a removed rect would expand exactly the same way, so
+        // only those that do need to be searched again.
+        let stale: Vec<GridPoint> = candidates
+            .iter()
+            .filter(|(_, point_rects)| {
+                point_rects
+                    .iter()
+                    .any(|point_rect| chosen.iter().any(|rect| rect.overlaps(*point_rect)))
+            })
+            .map(|(point, _)| *point)
+            .collect();
+        for point in stale {
+            candidates.insert(point, largest_rects(&points, point));
+        }
+        rects.extend(chosen);
+    }
+    rects
+}
+
+fn contains_rect(points: &HashSet<GridPoint>, rect: GridRect) -> bool {
+    rect.points().all(|point| points.contains(&point))
+}
+
+/// The rect found by growing `point` horizontally first, and the one found by growing it
+/// vertically first.
+fn largest_rects(points: &HashSet<GridPoint>, point: GridPoint) -> [GridRect; 2] {
+    let mut width_first_rect = GridRect::from_point(point);
+    while points.contains(&GridPoint::new(
+        width_first_rect.from.x - 1,
+        width_first_rect.from.y,
+    )) {
+        width_first_rect.from.x -= 1;
+    }
+    while points.contains(&GridPoint::new(
+        width_first_rect.to.x + 1,
+        width_first_rect.to.y,
+    )) {
+        width_first_rect.to.x += 1;
+    }
+    while contains_rect(points, width_first_rect.row_above()) {
+        width_first_rect.from.y -= 1;
+    }
+    while contains_rect(points, width_first_rect.row_below()) {
+        width_first_rect.to.y += 1;
+    }
+
+    let mut height_first_rect = GridRect::from_point(point);
+    while points.contains(&GridPoint::new(
+        height_first_rect.from.x,
+        height_first_rect.from.y - 1,
+    )) {
+        height_first_rect.from.y -= 1;
+    }
+    while points.contains(&GridPoint::new(
+        height_first_rect.to.x,
+        height_first_rect.to.y + 1,
+    )) {
+        height_first_rect.to.y += 1;
+    }
+    while contains_rect(points, height_first_rect.col_left()) {
+        height_first_rect.from.x -= 1;
+    }
+    while contains_rect(points, height_first_rect.col_right()) {
+        height_first_rect.to.x += 1;
+    }
+
+    [width_first_rect, height_first_rect]
+}
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use std::collections::HashSet;
 
+mod greedy;
+mod optimal;
+
 #[derive(Clone, Copy, Hash, Eq, PartialEq, Default, Debug)]
 pub struct GridPoint {
     pub x: i64,
@@ -13,13 +16,21 @@ impl GridPoint {
     }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Default, Debug)]
+/// Inclusive range of grid points.
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Default, Debug)]
 pub struct GridRect {
     from: GridPoint,
     to: GridPoint,
 }
 
 impl GridRect {
+    pub fn new(from: GridPoint, to: GridPoint) -> Self {
+        Self {
+            from: GridPoint::new(from.x.min(to.x), from.y.min(to.y)),
+            to: GridPoint::new(from.x.max(to.x), from.y.max(to.y)),
+        }
+    }
+
     pub fn to_position_size(&self) -> (Vec2, Vec2) {
         let middle_x = (self.from.x as f32 + self.to.x as f32) * 0.5;
         let middle_y = (self.from.y as f32 + self.to.y as f32) * 0.5;
@@ -29,6 +40,45 @@ impl GridRect {
         )
     }
 
+    pub fn from(&self) -> GridPoint {
+        self.from
+    }
+
+    pub fn to(&self) -> GridPoint {
+        self.to
+    }
+
+    pub fn width(&self) -> i64 {
+        self.to.x - self.from.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.to.y - self.from.y + 1
+    }
+
+    pub fn size(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: GridPoint) -> bool {
+        self.from.x <= point.x
+            && self.to.x >= point.x
+            && self.from.y <= point.y
+            && self.to.y >= point.y
+    }
+
+    pub fn overlaps(&self, other: GridRect) -> bool {
+        self.from.x <= other.to.x
+            && self.to.x >= other.from.x
+            && self.from.y <= other.to.y
+            && self.to.y >= other.from.y
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = GridPoint> {
+        let GridRect { from, to } = *self;
+        (from.y..=to.y).flat_map(move |y| (from.x..=to.x).map(move |x| GridPoint::new(x, y)))
+    }
+
     fn from_point(point: GridPoint) -> Self {
         Self {
             from: point,
@@ -42,18 +92,21 @@ impl GridRect {
             to: GridPoint::new(self.to.x, self.from.y - 1),
         }
     }
+
     fn row_below(&self) -> Self {
         Self {
             from: GridPoint::new(self.from.x, self.to.y + 1),
             to: GridPoint::new(self.to.x, self.to.y + 1),
         }
     }
+
     fn col_left(&self) -> Self {
         Self {
             from: GridPoint::new(self.from.x - 1, self.from.y),
             to: GridPoint::new(self.from.x - 1, self.to.y),
         }
     }
+
     fn col_right(&self) -> Self {
         Self {
             from: GridPoint::new(self.to.x + 1, self.from.y),
@@ -61,29 +114,33 @@ impl GridRect {
         }
     }
 
-    fn overlaps(&self, other: GridRect) -> bool {
-        self.from.x <= other.to.x
-            && self.to.x >= other.from.x
-            && self.from.y <= other.to.y
-            && self.to.y >= other.from.y
-    }
-
-    fn width(&self) -> i64 {
-        self.to.x - self.from.x + 1
-    }
-
-    fn height(&self) -> i64 {
-        self.to.y - self.from.y + 1
+    fn touches(&self, point: GridPoint) -> bool {
+        GridRect::new(
+            GridPoint::new(self.from.x - 1, self.from.y - 1),
+            GridPoint::new(self.to.x + 1, self.to.y + 1),
+        )
+        .contains(point)
     }
+}
 
-    fn size(&self) -> i64 {
-        self.width() * self.height()
-    }
+#[derive(Clone, Copy, Eq, PartialEq, Default, Debug)]
+pub enum CombineMethod {
+    /// Repeatedly takes the largest rectangles that fit. Fast, but may produce more rects than
+    /// necessary.
+    #[default]
+    Greedy,
+    /// Minimum number of rectangles, found by cutting along a maximum set of non-crossing chords
+    /// between concave corners, including the corners of holes.
+    Optimal,
 }
 
 #[derive(Clone, Default)]
 pub struct GridCombiner {
     points: HashSet<GridPoint>,
+    method: CombineMethod,
+    rects: Vec<GridRect>,
+    added: HashSet<GridPoint>,
+    removed: HashSet<GridPoint>,
 }
 
 impl GridCombiner {
@@ -91,116 +148,87 @@ impl GridCombiner {
         Self::default()
     }
 
-    pub fn add_point(&mut self, point: GridPoint) {
-        self.points.insert(point);
+    pub fn with_method(mut self, method: CombineMethod) -> Self {
+        self.set_method(method);
+        self
     }
 
-    pub fn points(&self) -> &HashSet<GridPoint> {
-        &self.points
+    pub fn method(&self) -> CombineMethod {
+        self.method
     }
 
-    fn contains_rect(&self, rect: GridRect) -> bool {
-        for x in rect.from.x..=rect.to.x {
-            for y in rect.from.y..=rect.to.y {
-                if !self.points.contains(&GridPoint::new(x, y)) {
-                    return false;
-                }
-            }
+    /// Changing the method discards the rects kept by [`GridCombiner::recombine`].
+    pub fn set_method(&mut self, method: CombineMethod) {
+        if self.method != method {
+            self.method = method;
+            self.rects.clear();
+            self.removed.clear();
+            self.added = self.points.clone();
         }
-        true
     }
 
-    fn remove_rect(&mut self, rect: GridRect) -> bool {
-        for x in rect.from.x..=rect.to.x {
-            for y in rect.from.y..=rect.to.y {
-                self.points.remove(&GridPoint::new(x, y));
-            }
-        }
-        true
-    }
-
-    fn find_largest_rects(&self) -> Vec<GridRect> {
-        let mut rects = vec![];
-        for point in self.points.iter() {
-            let mut width_first_rect = GridRect::from_point(*point);
-            while self.points.contains(&GridPoint::new(
-                width_first_rect.from.x - 1,
-                width_first_rect.from.y,
-            )) {
-                width_first_rect.from.x -= 1;
-            }
-            while self.points.contains(&GridPoint::new(
-                width_first_rect.to.x + 1,
-                width_first_rect.to.y,
-            )) {
-                width_first_rect.to.x += 1;
-            }
-            while self.contains_rect(width_first_rect.row_above()) {
-                width_first_rect.from.y -= 1;
-            }
-            while self.contains_rect(width_first_rect.row_below()) {
-                width_first_rect.to.y += 1;
-            }
-            let width_tuple = (width_first_rect, width_first_rect.size());
-            if !rects.contains(&width_tuple) {
-                rects.push(width_tuple);
-            }
-
-            let mut height_first_rect = GridRect::from_point(*point);
-            while self.points.contains(&GridPoint::new(
-                height_first_rect.from.x,
-                height_first_rect.from.y - 1,
-            )) {
-                height_first_rect.from.y -= 1;
-            }
-            while self.points.contains(&GridPoint::new(
-                height_first_rect.to.x,
-                height_first_rect.to.y + 1,
-            )) {
-                height_first_rect.to.y += 1;
-            }
-            while self.contains_rect(height_first_rect.col_left()) {
-                height_first_rect.from.x -= 1;
-            }
-            while self.contains_rect(height_first_rect.col_right()) {
-                height_first_rect.to.x += 1;
-            }
-            let height_tuple = (height_first_rect, height_first_rect.size());
-            if !rects.contains(&height_tuple) {
-                rects.push(height_tuple);
-            }
+    pub fn add_point(&mut self, point: GridPoint) {
+        if self.points.insert(point) {
+            self.removed.remove(&point);
+            self.added.insert(point);
         }
+    }
 
-        rects.sort_by(|a, b| a.1.cmp(&b.1));
-        if rects.len() > 0 {
-            let mut results: Vec<GridRect> = vec![];
-            'outer: while let Some(candidate_rect) = rects.pop() {
-                for other in results.iter() {
-                    if other.overlaps(candidate_rect.0) {
-                        continue 'outer;
-                    }
-                }
-                results.push(candidate_rect.0);
-            }
-            results
+    pub fn remove_point(&mut self, point: GridPoint) -> bool {
+        if self.points.remove(&point) {
+            self.added.remove(&point);
+            self.removed.insert(point);
+            true
         } else {
-            vec![]
+            false
         }
     }
 
+    pub fn contains(&self, point: GridPoint) -> bool {
+        self.points.contains(&point)
+    }
+
+    pub fn points(&self) -> &HashSet<GridPoint> {
+        &self.points
+    }
+
+    /// Combines every point from scratch.
     pub fn combine(&self) -> Vec<GridRect> {
-        let mut clone = self.clone();
-        let mut rects = vec![];
-        loop {
-            let largest_rects = clone.find_largest_rects();
-            if largest_rects.len() == 0 {
-                break;
-            }
-            for rect in largest_rects.into_iter() {
-                rects.push(rect);
-                clone.remove_rect(rect);
+        combine_points(self.points.clone(), self.method)
+    }
+
+    /// Updates the rects returned by [`GridCombiner::rects`] after points were added or removed.
+    /// Only rects containing a removed point or bordering an added point are rebuilt.
+    pub fn recombine(&mut self) {
+        if self.added.is_empty() && self.removed.is_empty() {
+            return;
+        }
+        let added = std::mem::take(&mut self.added);
+        let removed = std::mem::take(&mut self.removed);
+        let mut dirty = added.clone();
+        let mut kept = Vec::with_capacity(self.rects.len());
+        for rect in self.rects.drain(..) {
+            let invalidated = removed.iter().any(|point| rect.contains(*point))
+                || added.iter().any(|point| rect.touches(*point));
+            if invalidated {
+                dirty.extend(rect.points().filter(|point| self.points.contains(point)));
+            } else {
+                kept.push(rect);
             }
         }
-        rects
+        kept.extend(combine_points(dirty, self.method));
+        self.rects = kept;
+    }
+
+    /// Rects as of the last [`GridCombiner::recombine`].
+    pub fn rects(&self) -> impl Iterator<Item = &GridRect> {
+        self.rects.iter()
+    }
+}
+
+fn combine_points(points: HashSet<GridPoint>, method: CombineMethod) -> Vec<GridRect> {
+    match method {
+        CombineMethod::Greedy => greedy::combine(points),
+        CombineMethod::Optimal => optimal::combine(points),
     }
 }
@@ -0,0 +1,294 @@
+//! Minimum rectangle partition of a set of grid cells.
+//!
+//! Corners are lattice points, where corner `(x, y)` touches the cells `(x - 1, y - 1)`,
+//! `(x, y - 1)`, `(x - 1, y)` and `(x, y)`. A corner with exactly three filled cells is concave,
+//! and every concave corner needs a cut. A chord is a straight cut joining two concave corners,
+//! resolving both at once, so the fewest rectangles come from cutting along the largest set of
+//! chords that don't touch each other and then giving every remaining concave corner a cut of
+//! its own.
+
+use crate::{GridPoint, GridRect};
+use std::collections::HashSet;
+
+#[derive(Clone, Copy)]
+struct ConcaveCorner {
+    x: i64,
+    y: i64,
+    x_direction: i64,
+    y_direction: i64,
+}
+
+/// Cuts along line `y` between corners `from` and `to`, or along line `x` when vertical.
+#[derive(Clone, Copy)]
+struct Chord {
+    line: i64,
+    from: i64,
+    to: i64,
+}
+
+#[derive(Default)]
+struct Walls {
+    /// `(x, y)` separates cell `(x, y - 1)` from cell `(x, y)`.
+    horizontal: HashSet<(i64, i64)>,
+    /// `(x, y)` separates cell `(x - 1, y)` from cell `(x, y)`.
+    vertical: HashSet<(i64, i64)>,
+}
+
+impl Walls {
+    fn touches_corner(&self, x: i64, y: i64) -> bool {
+        self.horizontal.contains(&(x - 1, y))
+            || self.horizontal.contains(&(x, y))
+            || self.vertical.contains(&(x, y - 1))
+            || self.vertical.contains(&(x, y))
+    }
+}
+
+pub(crate) fn combine(points: HashSet<GridPoint>) -> Vec<GridRect> {
+    let filled = |x: i64, y: i64| points.contains(&GridPoint::new(x, y));
+    let concave_corners = concave_corners(&points);
+
+    let mut horizontal_chords = vec![];
+    let mut vertical_chords = vec![];
+    for corner in concave_corners.iter() {
+        if corner.x_direction > 0 {
+            let mut end = corner.x;
+            while filled(end, corner.y - 1) && filled(end, corner.y) {
+                end += 1;
+            }
+            if filled(end, corner.y - 1) != filled(end, corner.y) {
+                horizontal_chords.push(Chord {
+                    line: corner.y,
+                    from: corner.x,
+                    to: end,
+                });
+            }
+        }
+        if corner.y_direction > 0 {
+            let mut end = corner.y;
+            while filled(corner.x - 1, end) && filled(corner.x, end) {
+                end += 1;
+            }
+            if filled(corner.x - 1, end) != filled(corner.x, end) {
+                vertical_chords.push(Chord {
+                    line: corner.x,
+                    from: corner.y,
+                    to: end,
+                });
+            }
+        }
+    }
+
+    let mut walls = Walls::default();
+    let (horizontal_chosen, vertical_chosen) =
+        independent_chords(&horizontal_chords, &vertical_chords);
+    for chord in horizontal_chosen {
+        for x in chord.from..chord.to {
+            walls.horizontal.insert((x, chord.line));
+        }
+    }
+    for chord in vertical_chosen {
+        for y in chord.from..chord.to {
+            walls.vertical.insert((chord.line, y));
+        }
+    }
+
+    for corner in concave_corners.iter() {
+        if walls.touches_corner(corner.x, corner.y) {
+            continue;
+        }
+        let mut x = corner.x;
+        loop {
+            let column = if corner.x_direction > 0 { x } else { x - 1 };
+            if !filled(column, corner.y - 1) || !filled(column, corner.y) {
+                break;
+            }
+            if x != corner.x
+                && (walls.vertical.contains(&(x, corner.y - 1))
+                    || walls.vertical.contains(&(x, corner.y)))
+            {
+                break;
+            }
+            walls.horizontal.insert((column, corner.y));
+            x += corner.x_direction;
+        }
+    }
+
+    // every concave corner now has a cut, so each region is a rectangle
+    regions(&points, &walls)
+        .into_iter()
+        .map(|region| {
+            let rect = bounding_rect(&region);
+            debug_assert_eq!(rect.size(), region.len() as i64);
+            rect
+        })
+        .collect()
+}
+
+fn concave_corners(points: &HashSet<GridPoint>) -> Vec<ConcaveCorner> {
+    let corners: HashSet<(i64, i64)> = points
+        .iter()
+        .flat_map(|point| {
+            [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(dx, dy)| (point.x + dx, point.y + dy))
+        })
+        .collect();
+    let mut concave_corners: Vec<ConcaveCorner> = corners
+        .into_iter()
+        .filter_map(|(x, y)| {
+            let missing: Vec<(i64, i64)> = [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+                .into_iter()
+                .filter(|(dx, dy)| {
+                    let cell_x = if *dx < 0 { x - 1 } else { x };
+                    let cell_y = if *dy < 0 { y - 1 } else { y };
+                    !points.contains(&GridPoint::new(cell_x, cell_y))
+                })
+                .collect();
+            if let [(dx, dy)] = missing[..] {
+                Some(ConcaveCorner {
+                    x,
+                    y,
+                    x_direction: -dx,
+                    y_direction: -dy,
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    concave_corners.sort_by_key(|corner| (corner.y, corner.x));
+    concave_corners
+}
+
+/// Largest set of chords where no two touch, from a maximum matching of the bipartite
+/// horizontal/vertical intersection graph (König's theorem).
+fn independent_chords(horizontal: &[Chord], vertical: &[Chord]) -> (Vec<Chord>, Vec<Chord>) {
+    let adjacency: Vec<Vec<usize>> = horizontal
+        .iter()
+        .map(|h| {
+            vertical
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| {
+                    h.from <= v.line && v.line <= h.to && v.from <= h.line && h.line <= v.to
+                })
+                .map(|(index, _)| index)
+                .collect()
+        })
+        .collect();
+
+    let mut horizontal_match: Vec<Option<usize>> = vec![None; horizontal.len()];
+    let mut vertical_match: Vec<Option<usize>> = vec![None; vertical.len()];
+    for h in 0..horizontal.len() {
+        let mut visited = vec![false; vertical.len()];
+        augment(
+            h,
+            &adjacency,
+            &mut horizontal_match,
+            &mut vertical_match,
+            &mut visited,
+        );
+    }
+
+    let mut horizontal_visited = vec![false; horizontal.len()];
+    let mut vertical_visited = vec![false; vertical.len()];
+    let mut stack: Vec<usize> = (0..horizontal.len())
+        .filter(|h| horizontal_match[*h].is_none())
+        .collect();
+    while let Some(h) = stack.pop() {
+        if horizontal_visited[h] {
+            continue;
+        }
+        horizontal_visited[h] = true;
+        for &v in adjacency[h].iter() {
+            if vertical_visited[v] {
+                continue;
+            }
+            vertical_visited[v] = true;
+            if let Some(next) = vertical_match[v] {
+                stack.push(next);
+            }
+        }
+    }
+
+    (
+        horizontal
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| horizontal_visited[*index])
+            .map(|(_, chord)| *chord)
+            .collect(),
+        vertical
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !vertical_visited[*index])
+            .map(|(_, chord)| *chord)
+            .collect(),
+    )
+}
+
+fn augment(
+    h: usize,
+    adjacency: &[Vec<usize>],
+    horizontal_match: &mut [Option<usize>],
+    vertical_match: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for &v in adjacency[h].iter() {
+        if visited[v] {
+            continue;
+        }
+        visited[v] = true;
+        let free = match vertical_match[v] {
+            Some(other) => augment(other, adjacency, horizontal_match, vertical_match, visited),
+            None => true,
+        };
+        if free {
+            horizontal_match[h] = Some(v);
+            vertical_match[v] = Some(h);
+            return true;
+        }
+    }
+    false
+}
+
+fn regions(points: &HashSet<GridPoint>, walls: &Walls) -> Vec<HashSet<GridPoint>> {
+    let mut remaining = points.clone();
+    let mut regions = vec![];
+    let mut sorted: Vec<GridPoint> = points.iter().copied().collect();
+    sorted.sort_by_key(|point| (point.y, point.x));
+    for start in sorted {
+        if !remaining.remove(&start) {
+            continue;
+        }
+        let mut region = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(point) = stack.pop() {
+            let (x, y) = (point.x, point.y);
+            let neighbors = [
+                (x + 1, y, walls.vertical.contains(&(x + 1, y))),
+                (x - 1, y, walls.vertical.contains(&(x, y))),
+                (x, y + 1, walls.horizontal.contains(&(x, y + 1))),
+                (x, y - 1, walls.horizontal.contains(&(x, y))),
+            ];
+            for (neighbor_x, neighbor_y, blocked) in neighbors {
+                let neighbor = GridPoint::new(neighbor_x, neighbor_y);
+                if !blocked && remaining.remove(&neighbor) {
+                    region.insert(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+fn bounding_rect(region: &HashSet<GridPoint>) -> GridRect {
+    let mut points = region.iter();
+    let first = *points.next().unwrap();
+    points.fold(GridRect::from_point(first), |rect, point| {
+        GridRect::new(
+            GridPoint::new(rect.from.x.min(point.x), rect.from.y.min(point.y)),
+            GridPoint::new(rect.to.x.max(point.x), rect.to.y.max(point.y)),
+        )
+    })
+}
@@ -0,0 +1,179 @@
+use grid_combiner::{CombineMethod, GridCombiner, GridPoint, GridRect};
+use std::collections::HashSet;
+
+const METHODS: [CombineMethod; 2] = [CombineMethod::Greedy, CombineMethod::Optimal];
+
+/// Small deterministic generator so the cases are reproducible without extra dependencies.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn range(&mut self, max: i64) -> i64 {
+        (self.next() % max as u64) as i64
+    }
+
+    fn points(&mut self, size: i64) -> HashSet<GridPoint> {
+        let density = 40 + self.range(55);
+        let mut points = HashSet::new();
+        for x in 0..size {
+            for y in 0..size {
+                if self.range(100) < density {
+                    points.insert(GridPoint::new(x - size / 2, y - size / 2));
+                }
+            }
+        }
+        points
+    }
+}
+
+fn combiner(points: &HashSet<GridPoint>, method: CombineMethod) -> GridCombiner {
+    let mut grid_combiner = GridCombiner::new().with_method(method);
+    for point in points.iter() {
+        grid_combiner.add_point(*point);
+    }
+    grid_combiner
+}
+
+fn assert_tiling(points: &HashSet<GridPoint>, rects: &[GridRect], case: usize) {
+    let mut covered = HashSet::new();
+    for rect in rects {
+        for point in rect.points() {
+            assert!(
+                points.contains(&point),
+                "case {}: {:?} covers missing point {:?}",
+                case,
+                rect,
+                point
+            );
+            assert!(
+                covered.insert(point),
+                "case {}: {:?} overlaps another rect at {:?}",
+                case,
+                rect,
+                point
+            );
+        }
+    }
+    assert_eq!(
+        covered.len(),
+        points.len(),
+        "case {}: points left uncovered",
+        case
+    );
+}
+
+/// Fewest rects found by trying every rect at the first uncovered point, which has to be the
+/// corner of whichever rect covers it.
+fn minimum_rects(points: &HashSet<GridPoint>, count: usize, best: &mut usize) {
+    if count >= *best {
+        return;
+    }
+    let Some(start) = points
+        .iter()
+        .copied()
+        .min_by_key(|point| (point.y, point.x))
+    else {
+        *best = count;
+        return;
+    };
+    let mut width = 0;
+    while points.contains(&GridPoint::new(start.x + width, start.y)) {
+        width += 1;
+    }
+    for width in (1..=width).rev() {
+        let row_filled =
+            |y: i64| (0..width).all(|dx| points.contains(&GridPoint::new(start.x + dx, y)));
+        let mut height = 0;
+        while row_filled(start.y + height) {
+            height += 1;
+        }
+        for height in (1..=height).rev() {
+            let rect = GridRect::new(
+                start,
+                GridPoint::new(start.x + width - 1, start.y + height - 1),
+            );
+            let mut remaining = points.clone();
+            for point in rect.points() {
+                remaining.remove(&point);
+            }
+            minimum_rects(&remaining, count + 1, best);
+        }
+    }
+}
+
+#[test]
+fn combine_tiles_points() {
+    let mut random = Random(0x5eed);
+    for case in 0..300 {
+        let size = 4 + random.range(20);
+        let points = random.points(size);
+        for method in METHODS {
+            let rects = combiner(&points, method).combine();
+            assert_tiling(&points, &rects, case);
+        }
+    }
+}
+
+#[test]
+fn recombine_tiles_points_after_edits() {
+    let mut random = Random(0xed17);
+    for case in 0..200 {
+        let size = 4 + random.range(20);
+        let points = random.points(size);
+        for method in METHODS {
+            let mut grid_combiner = combiner(&points, method);
+            grid_combiner.recombine();
+            let mut edited = points.clone();
+            for _ in 0..50 {
+                let point =
+                    GridPoint::new(random.range(size) - size / 2, random.range(size) - size / 2);
+                if edited.remove(&point) {
+                    assert!(grid_combiner.remove_point(point));
+                } else {
+                    edited.insert(point);
+                    grid_combiner.add_point(point);
+                }
+                if random.range(4) == 0 {
+                    grid_combiner.recombine();
+                    let rects: Vec<GridRect> = grid_combiner.rects().copied().collect();
+                    assert_tiling(&edited, &rects, case);
+                }
+            }
+            grid_combiner.recombine();
+            let rects: Vec<GridRect> = grid_combiner.rects().copied().collect();
+            assert_tiling(&edited, &rects, case);
+        }
+    }
+}
+
+#[test]
+fn optimal_uses_fewest_rects() {
+    let mut random = Random(0xbe57);
+    for case in 0..500 {
+        let size = 2 + random.range(4);
+        let points = random.points(size);
+        let rects = combiner(&points, CombineMethod::Optimal).combine();
+        let mut best = usize::MAX;
+        minimum_rects(&points, 0, &mut best);
+        assert_eq!(rects.len(), best, "case {}: {:?}", case, points);
+        assert!(rects.len() <= combiner(&points, CombineMethod::Greedy).combine().len());
+    }
+}
+
+#[test]
+fn optimal_cuts_around_holes() {
+    // 5x5 square with the middle point missing
+    let points: HashSet<GridPoint> = (0..5)
+        .flat_map(|x| (0..5).map(move |y| GridPoint::new(x, y)))
+        .filter(|point| *point != GridPoint::new(2, 2))
+        .collect();
+    let rects = combiner(&points, CombineMethod::Optimal).combine();
+    assert_tiling(&points, &rects, 0);
+    assert_eq!(rects.len(), 4);
+}
@@ -19,13 +19,19 @@ fn main() {
         }))
         .add_plugins(jam::common::CommonPlugin)
         .add_systems(Startup, init)
-        .add_systems(Update, player_update)
+        .add_systems(
+            Update,
+            (player_update, spinner_update, draw_collisions, draw_raycast),
+        )
         .run();
 }
 
 #[derive(Component)]
 pub struct Player;
 
+#[derive(Component)]
+pub struct Spinner;
+
 pub fn init(
     mut commands: Commands,
     mut asset_library: ResMut<AssetLibrary>,
@@ -34,15 +40,10 @@ pub fn init(
     asset_library.load_assets(&asset_server);
     commands.spawn(Camera2dBundle::default());
     commands.spawn((
-        SpriteBundle {
-            sprite: Sprite {
-                custom_size: Vec2::new(32., 32.).into(),
-                color: Color::GREEN,
-                ..Default::default()
-            },
+        Transform2Bundle {
+            transform2: Transform2::from_xy(0., 0.),
             ..Default::default()
         },
-        Transform2::from_xy(0., 0.),
         Collision {
             shape: CollisionShape::Rect {
                 size: Vec2::new(32., 32.),
@@ -51,49 +52,71 @@ pub fn init(
         },
         Player,
     ));
-    commands.spawn((
-        SpriteBundle {
-            sprite: Sprite {
-                custom_size: Vec2::new(32., 32.).into(),
-                color: Color::RED,
-                ..Default::default()
+    for (position, shape) in [
+        (
+            Vec2::new(100., 0.),
+            CollisionShape::Rect {
+                size: Vec2::new(32., 32.),
             },
-            ..Default::default()
-        },
-        Transform2::from_xy(100., 0.),
-        Collision {
-            shape: CollisionShape::Rect {
+        ),
+        (
+            Vec2::new(30., 40.),
+            CollisionShape::Rect {
                 size: Vec2::new(32., 32.),
             },
-            flags: 1,
-        },
-    ));
-    commands.spawn((
-        SpriteBundle {
-            sprite: Sprite {
-                custom_size: Vec2::new(32., 32.).into(),
-                color: Color::RED,
-                ..Default::default()
+        ),
+        (
+            Vec2::new(-150., 80.),
+            CollisionShape::Circle { radius: 40. },
+        ),
+        (Vec2::new(-100., -120.), CollisionShape::Point),
+        (
+            Vec2::new(180., -120.),
+            CollisionShape::OrientedRect {
+                size: Vec2::new(160., 24.),
+                rotation: 0.,
             },
-            ..Default::default()
-        },
-        Transform2::from_xy(30., 40.),
-        Collision {
-            shape: CollisionShape::Rect {
-                size: Vec2::new(32., 32.),
+        ),
+    ] {
+        let mut entity = commands.spawn((
+            Transform2Bundle {
+                transform2: Transform2::from_translation(position),
+                ..Default::default()
             },
-            flags: 1,
-        },
-    ));
+            Collision { shape, flags: 1 },
+        ));
+        if matches!(shape, CollisionShape::OrientedRect { .. }) {
+            entity.insert(Spinner);
+        }
+    }
 }
 
 fn player_update(
-    mut query: Query<(Entity, &mut Transform2, &Collision), With<Player>>,
+    mut query: Query<(Entity, &mut Transform2, &mut Collision), With<Player>>,
     collision_query: Res<CollisionQuery>,
     input: Res<Input<KeyCode>>,
     time: Res<Time>,
 ) {
-    for (entity, mut transform, collision) in query.iter_mut() {
+    for (entity, mut transform, mut collision) in query.iter_mut() {
+        if input.just_pressed(KeyCode::Space) {
+            collision.shape = match collision.shape {
+                CollisionShape::Rect { .. } => CollisionShape::Circle { radius: 16. },
+                CollisionShape::Circle { .. } => CollisionShape::OrientedRect {
+                    size: Vec2::new(48., 16.),
+                    rotation: 0.,
+                },
+                _ => CollisionShape::Rect {
+                    size: Vec2::new(32., 32.),
+                },
+            };
+        }
+        if input.pressed(KeyCode::Q) {
+            transform.rotation += 2. * time.delta_seconds();
+        }
+        if input.pressed(KeyCode::E) {
+            transform.rotation -= 2. * time.delta_seconds();
+        }
+        let shape = collision.shape.rotated(transform.rotation);
         let mut velocity = Vec2::new(0., 0.);
         if input.pressed(KeyCode::W) {
             velocity.y += 1.;
@@ -118,7 +141,7 @@ fn player_update(
             if let Some((_, distance)) = collision_query.check_moving(
                 transform.translation,
                 velocity_x,
-                collision.shape,
+                shape,
                 Some(collision_filters),
             ) {
                 velocity_x *= distance;
@@ -128,7 +151,7 @@ fn player_update(
             if let Some((_, distance)) = collision_query.check_moving(
                 transform.translation,
                 velocity_y,
-                collision.shape,
+                shape,
                 Some(collision_filters),
             ) {
                 velocity_y *= distance;
@@ -138,3 +161,82 @@ fn player_update(
         }
     }
 }
+
+fn spinner_update(mut query: Query<&mut Transform2, With<Spinner>>, time: Res<Time>) {
+    for mut transform in query.iter_mut() {
+        transform.rotation += 0.5 * time.delta_seconds();
+    }
+}
+
+fn draw_collisions(
+    query: Query<(Entity, &GlobalTransform, &Collision, Option<&Player>)>,
+    collision_query: Res<CollisionQuery>,
+    mut gizmos: Gizmos,
+) {
+    for (entity, transform, collision, player) in query.iter() {
+        let position = transform.translation().truncate();
+        let shape = collision.shape.with_global_rotation(transform);
+        let color = if player.is_none() {
+            Color::RED
+        } else if collision_query
+            .check(
+                position,
+                shape,
+                Some(CollisionFilter {
+                    exclude_entity: entity,
+                    flags: 1,
+                }),
+            )
+            .is_some()
+        {
+            Color::YELLOW
+        } else {
+            Color::GREEN
+        };
+        match shape {
+            CollisionShape::None => {}
+            CollisionShape::Point => {
+                gizmos.circle_2d(position, 2., color);
+            }
+            CollisionShape::Rect { size } => {
+                gizmos.rect_2d(position, 0., size, color);
+            }
+            CollisionShape::Circle { radius } => {
+                gizmos.circle_2d(position, radius, color);
+            }
+            CollisionShape::OrientedRect { size, rotation } => {
+                gizmos.rect_2d(position, rotation, size, color);
+            }
+        }
+    }
+}
+
+fn draw_raycast(
+    query: Query<(Entity, &GlobalTransform, &Collision), With<Player>>,
+    collision_query: Res<CollisionQuery>,
+    mouse: Res<Mouse>,
+    input: Res<Input<KeyCode>>,
+    mut gizmos: Gizmos,
+) {
+    for (entity, transform, collision) in query.iter() {
+        let position = transform.translation().truncate();
+        let difference = mouse.position - position;
+        let filter = Some(CollisionFilter {
+            exclude_entity: entity,
+            flags: 1,
+        });
+        let shape = collision.shape.with_global_rotation(transform);
+        let hit = if input.pressed(KeyCode::ShiftLeft) {
+            collision_query.shape_cast(position, difference, difference.length(), shape, filter)
+        } else {
+            collision_query.raycast(position, difference, difference.length(), filter)
+        };
+        if let Some(hit) = hit {
+            let point = position + difference.normalize_or_zero() * hit.distance;
+            gizmos.line_2d(position, point, Color::YELLOW);
+            gizmos.line_2d(point, point + hit.normal * 24., Color::CYAN);
+        } else {
+            gizmos.line_2d(position, mouse.position, Color::GRAY);
+        }
+    }
+}
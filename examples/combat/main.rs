@@ -39,8 +39,8 @@ fn main() {
             jam::game::overworld::camera::OverworldCameraPlugin,
             jam::game::overworld::ocean::OceanPlugin,
             jam::game::overworld::attacks::AttacksPlugin,
-            jam::game::overworld::octopus::OctopusPlugin,
-            jam::game::overworld::turtle::TurtlePlugin,
+            jam::game::overworld::enemy::EnemyPlugin,
+            jam::game::overworld::enemy_ai::EnemyAiPlugin,
             jam::game::overworld::enemy_spawns::EnemySpawnsPlugin,
             jam::game::overworld::threat_level::ThreatLevelPlugin,
         ))
@@ -85,8 +85,7 @@ struct DebugState {
 
 fn debug(
     mut egui_query: Query<&mut EguiContext>,
-    mut ev_octopus_spawn: EventWriter<OctopusSpawnEvent>,
-    mut ev_turtle_spawn: EventWriter<TurtleSpawnEvent>,
+    mut ev_enemy_spawn: EventWriter<EnemySpawnEvent>,
     mut ev_jagerossa_spawn: EventWriter<JagerossaSpawnEvent>,
     mut ev_ringo_spawn: EventWriter<RingoSpawnEvent>,
     mut ev_plank_spawn: EventWriter<PlankSpawnEvent>,
@@ -112,6 +111,7 @@ fn debug(
     egui::Window::new("Combat").show(egui_context.get_mut(), |ui| {
         ui.label("1) Octopus");
         ui.label("2) Turtle");
+        ui.label("3) Octopus Gunner");
         ui.label("6) Jagerossa");
         ui.label("7) Ringo");
         ui.label("8) Plank");
@@ -188,33 +188,41 @@ fn debug(
         }
     }
     if input.just_pressed(KeyCode::Key1) {
-        let level = if input.pressed(KeyCode::ShiftLeft) {
-            OctopusLevel::Hard
+        let archetype = if input.pressed(KeyCode::ShiftLeft) {
+            "octopus_hard"
         } else if input.pressed(KeyCode::ControlLeft) {
-            OctopusLevel::Medium
+            "octopus_medium"
         } else {
-            OctopusLevel::Easy
+            "octopus_easy"
         };
         let spawn_pos = Vec2::from_angle(rand::random::<f32>() * std::f32::consts::TAU) * 500.;
-        ev_octopus_spawn.send(OctopusSpawnEvent {
+        ev_enemy_spawn.send(EnemySpawnEvent {
             entity: None,
             position: player_position + spawn_pos,
-            level,
+            archetype: archetype.to_owned(),
         });
     }
     if input.just_pressed(KeyCode::Key2) {
-        let level = if input.pressed(KeyCode::ShiftLeft) {
-            TurtleLevel::Hard
+        let archetype = if input.pressed(KeyCode::ShiftLeft) {
+            "turtle_hard"
         } else if input.pressed(KeyCode::ControlLeft) {
-            TurtleLevel::Medium
+            "turtle_medium"
         } else {
-            TurtleLevel::Easy
+            "turtle_easy"
         };
         let spawn_pos = Vec2::from_angle(rand::random::<f32>() * std::f32::consts::TAU) * 500.;
-        ev_turtle_spawn.send(TurtleSpawnEvent {
+        ev_enemy_spawn.send(EnemySpawnEvent {
             entity: None,
             position: player_position + spawn_pos,
-            level,
+            archetype: archetype.to_owned(),
+        });
+    }
+    if input.just_pressed(KeyCode::Key3) {
+        let spawn_pos = Vec2::from_angle(rand::random::<f32>() * std::f32::consts::TAU) * 500.;
+        ev_enemy_spawn.send(EnemySpawnEvent {
+            entity: None,
+            position: player_position + spawn_pos,
+            archetype: "octopus_gunner".to_owned(),
         });
     }
     if input.just_pressed(KeyCode::L) {
@@ -20,7 +20,7 @@ fn main() {
         }))
         .add_plugins((CommonPlugin, quest1::Q1A1Plugin))
         .add_systems(Startup, init)
-        .add_systems(Update, my_system)
+        .add_systems(Update, (my_system, my_complete))
         .run();
 }
 
@@ -44,4 +44,10 @@ pub fn my_system(
     }
 }
 
+pub fn my_complete(mut ev_complete: EventReader<CutsceneCompleteEvent<Q1A1Cutscene>>) {
+    for event in ev_complete.iter() {
+        println!("complete (cancelled: {})", event.cancelled);
+    }
+}
+
 pub mod quest1;
@@ -5,7 +5,8 @@ pub struct Q1A1Plugin;
 
 impl Plugin for Q1A1Plugin {
     fn build(&self, app: &mut App) {
-        app.add_cutscene::<Q1A1Cutscene>();
+        app.add_cutscene::<Q1A1Cutscene>()
+            .add_cutscene::<Q1A2Cutscene>();
     }
 }
 
@@ -19,6 +20,7 @@ impl Cutscene for Q1A1Cutscene {
     fn build(cutscene: &mut CutsceneBuilder) {
         cutscene.add_step(start1, update1);
         cutscene.add_step(start2, update2);
+        cutscene.add_nested_step(nested);
         cutscene.add_quick_step(end1);
         cutscene.add_quick_step(end2);
     }
@@ -48,6 +50,12 @@ fn update2(
     }
 }
 
+fn nested(cutscene: Res<Q1A1Cutscene>) -> Q1A2Cutscene {
+    Q1A2Cutscene {
+        birdup: cutscene.birdup * 2.,
+    }
+}
+
 fn end1(cutscene: Res<Q1A1Cutscene>) {
     println!("end1 {:?}", cutscene);
 }
@@ -55,3 +63,23 @@ fn end1(cutscene: Res<Q1A1Cutscene>) {
 fn end2(cutscene: Res<Q1A1Cutscene>) {
     println!("end2 {:?}", cutscene);
 }
+
+#[derive(Default, Debug, Clone, Resource)]
+pub struct Q1A2Cutscene {
+    pub birdup: f32,
+}
+
+impl Cutscene for Q1A2Cutscene {
+    fn build(cutscene: &mut CutsceneBuilder) {
+        cutscene.add_timed_step(nested_start, 1.);
+        cutscene.add_quick_step(nested_end);
+    }
+}
+
+fn nested_start(cutscene: Res<Q1A2Cutscene>) {
+    println!("nested_start {:?}", cutscene);
+}
+
+fn nested_end(cutscene: Res<Q1A2Cutscene>) {
+    println!("nested_end {:?}", cutscene);
+}
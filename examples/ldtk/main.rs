@@ -34,5 +34,6 @@ pub fn init(
         entity: None,
         asset: asset_library.level.clone(),
         position: Vec2::new(-800., 350.),
+        ..Default::default()
     });
 }
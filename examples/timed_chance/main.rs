@@ -32,12 +32,13 @@ pub fn init(
     mut commands: Commands,
     mut asset_library: ResMut<AssetLibrary>,
     asset_server: Res<AssetServer>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     asset_library.load_assets(&asset_server);
     commands.spawn((
         Camera2dBundle::default(),
         Timed {
-            chance: TimedChance::new(),
+            chance: game_rng.timed_chance(RngStream::Fx),
         },
     ));
 }
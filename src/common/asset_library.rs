@@ -62,6 +62,26 @@ pub struct AssetLibrary {
     #[asset("audio/sfx/ui_button_click_01.ogg")]
     pub audio_sfx_preview: Handle<AudioSource>,
 
+    /************
+     * Dialogue *
+     ************/
+    #[asset("dialogue/main.dialogue.ron")]
+    pub dialogue: Handle<DialogueAsset>,
+
+    /**********
+     * Locale *
+     **********/
+    #[asset("locale/index.locales.ron")]
+    pub locales: Handle<LocaleIndexAsset>,
+
+    /***********
+     * Enemies *
+     ***********/
+    #[asset("enemies/archetypes.enemies.ron")]
+    pub enemy_archetypes: Handle<EnemyArchetypesAsset>,
+    #[asset("enemies/overworld.spawns.ron")]
+    pub enemy_spawns: Handle<EnemySpawnsAsset>,
+
     /*********
      * Level *
      *********/
@@ -192,30 +212,6 @@ pub struct AssetLibrary {
     #[asset("sprites/WaterRingVFX.png")]
     pub sprite_water_ring_vfx: Handle<Image>,
 
-    #[asset("sprites/Turtle.png")]
-    pub sprite_turtle: Handle<Image>,
-    pub sprite_turtle_atlas: Handle<TextureAtlas>,
-
-    #[asset("sprites/Octopus.png")]
-    pub sprite_octopus_easy: Handle<Image>,
-    pub sprite_octopus_easy_atlas: Handle<TextureAtlas>,
-    #[asset("sprites/Octopus_Blue.png")]
-    pub sprite_octopus_medium: Handle<Image>,
-    pub sprite_octopus_medium_atlas: Handle<TextureAtlas>,
-    #[asset("sprites/Octopus_Red.png")]
-    pub sprite_octopus_hard: Handle<Image>,
-    pub sprite_octopus_hard_atlas: Handle<TextureAtlas>,
-
-    #[asset("sprites/Turtle.png")]
-    pub sprite_turtle_easy: Handle<Image>,
-    pub sprite_turtle_easy_atlas: Handle<TextureAtlas>,
-    #[asset("sprites/Turtle_Blue.png")]
-    pub sprite_turtle_medium: Handle<Image>,
-    pub sprite_turtle_medium_atlas: Handle<TextureAtlas>,
-    #[asset("sprites/Turtle_Red.png")]
-    pub sprite_turtle_hard: Handle<Image>,
-    pub sprite_turtle_hard_atlas: Handle<TextureAtlas>,
-
     #[asset("sprites/AnglerFish.png")]
     pub sprite_angler_fish: Handle<Image>,
     pub sprite_angler_fish_atlas: Handle<TextureAtlas>,
@@ -517,76 +513,6 @@ impl AssetLibrary {
         );
         self.sprite_ship_purple_atlas = texture_atlas_assets.add(texture_atlas);
 
-        let texture_atlas = TextureAtlas::from_grid(
-            self.sprite_octopus_easy.clone(),
-            Vec2::new(131., 130.),
-            2,
-            1,
-            None,
-            None,
-        );
-        self.sprite_octopus_easy_atlas = texture_atlas_assets.add(texture_atlas);
-
-        let texture_atlas = TextureAtlas::from_grid(
-            self.sprite_octopus_medium.clone(),
-            Vec2::new(131., 130.),
-            2,
-            1,
-            None,
-            None,
-        );
-        self.sprite_octopus_medium_atlas = texture_atlas_assets.add(texture_atlas);
-
-        let texture_atlas = TextureAtlas::from_grid(
-            self.sprite_octopus_hard.clone(),
-            Vec2::new(131., 130.),
-            2,
-            1,
-            None,
-            None,
-        );
-        self.sprite_octopus_hard_atlas = texture_atlas_assets.add(texture_atlas);
-
-        let texture_atlas = TextureAtlas::from_grid(
-            self.sprite_turtle_easy.clone(),
-            Vec2::new(131., 130.),
-            2,
-            1,
-            None,
-            None,
-        );
-        self.sprite_turtle_easy_atlas = texture_atlas_assets.add(texture_atlas);
-
-        let texture_atlas = TextureAtlas::from_grid(
-            self.sprite_turtle_medium.clone(),
-            Vec2::new(131., 130.),
-            2,
-            1,
-            None,
-            None,
-        );
-        self.sprite_turtle_medium_atlas = texture_atlas_assets.add(texture_atlas);
-
-        let texture_atlas = TextureAtlas::from_grid(
-            self.sprite_turtle_hard.clone(),
-            Vec2::new(131., 130.),
-            2,
-            1,
-            None,
-            None,
-        );
-        self.sprite_turtle_hard_atlas = texture_atlas_assets.add(texture_atlas);
-
-        let texture_atlas = TextureAtlas::from_grid(
-            self.sprite_turtle.clone(),
-            Vec2::new(131., 130.),
-            2,
-            1,
-            None,
-            None,
-        );
-        self.sprite_turtle_atlas = texture_atlas_assets.add(texture_atlas);
-
         let texture_atlas = TextureAtlas::from_grid(
             self.sprite_bomb.clone(),
             Vec2::new(131., 130.),
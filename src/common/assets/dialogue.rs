@@ -0,0 +1,159 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::{BoxedFuture, HashMap},
+};
+use serde::Deserialize;
+
+pub struct DialogueAssetPlugin;
+
+impl Plugin for DialogueAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<DialogueAsset>()
+            .init_asset_loader::<DialogueAssetLoader>();
+    }
+}
+
+#[derive(Debug, Deserialize, TypeUuid, TypePath)]
+#[uuid = "5f0e5c37-3a0b-4d8e-9f4a-8c1f0e7d2b61"]
+pub struct DialogueAsset {
+    pub conversations: HashMap<String, Vec<DialogueNode>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum DialogueNode {
+    /// `id` keys the text in locale files as `dialogue.{id}`, so it must stay the same when lines
+    /// around it change.
+    Line {
+        id: String,
+        speaker: DialoguePortrait,
+        text: String,
+    },
+    Choice {
+        id: String,
+        speaker: DialoguePortrait,
+        text: String,
+        options: Vec<DialogueOption>,
+    },
+    Branch(Vec<DialogueBranch>),
+    Effect(DialogueEffect),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueOption {
+    pub id: String,
+    pub text: String,
+    #[serde(default)]
+    pub condition: Option<DialogueCondition>,
+    #[serde(default)]
+    pub effects: Vec<DialogueEffect>,
+    #[serde(default)]
+    pub goto: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueBranch {
+    #[serde(default)]
+    pub condition: Option<DialogueCondition>,
+    pub goto: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum DialogueCondition {
+    Quest(String),
+    QuestStage(String, String),
+    LevelAtLeast(u32),
+    BandMember(String),
+    Flag(String),
+    Counter(String, u32, u32),
+    Not(Box<DialogueCondition>),
+    All(Vec<DialogueCondition>),
+    Any(Vec<DialogueCondition>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum DialogueEffect {
+    SetFlag(String),
+    ClearFlag(String),
+    IncrementCounter(String),
+    GrantExperience(f32),
+}
+
+impl DialogueAsset {
+    /// Checks that quest conditions name quests and stages that exist.
+    pub fn validate(&self) -> Result<(), String> {
+        for (key, nodes) in self.conversations.iter() {
+            for node in nodes.iter() {
+                let conditions: Vec<&DialogueCondition> = match node {
+                    DialogueNode::Choice { options, .. } => options
+                        .iter()
+                        .filter_map(|option| option.condition.as_ref())
+                        .collect(),
+                    DialogueNode::Branch(branches) => branches
+                        .iter()
+                        .filter_map(|branch| branch.condition.as_ref())
+                        .collect(),
+                    _ => vec![],
+                };
+                for condition in conditions {
+                    condition
+                        .validate()
+                        .map_err(|error| format!("{}: {}", key, error))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DialogueCondition {
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            DialogueCondition::Quest(name) => find_quest(name).map(|_| ()),
+            DialogueCondition::QuestStage(name, stage) => {
+                if find_quest(name)?.stage_names().contains(&stage.as_str()) {
+                    Ok(())
+                } else {
+                    Err(format!("unknown stage {} for quest {}", stage, name))
+                }
+            }
+            DialogueCondition::Not(condition) => condition.validate(),
+            DialogueCondition::All(conditions) | DialogueCondition::Any(conditions) => conditions
+                .iter()
+                .try_for_each(|condition| condition.validate()),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn find_quest(name: &str) -> Result<Quest, String> {
+    Quest::all()
+        .into_iter()
+        .find(|quest| quest.name() == name)
+        .ok_or_else(|| format!("unknown quest {}", name))
+}
+
+#[derive(Default)]
+pub struct DialogueAssetLoader;
+
+impl AssetLoader for DialogueAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let asset = ron::de::from_bytes::<DialogueAsset>(bytes)?;
+            asset.validate().map_err(bevy::asset::Error::msg)?;
+            load_context.set_default_asset(LoadedAsset::new(asset));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["dialogue.ron"]
+    }
+}
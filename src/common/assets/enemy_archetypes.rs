@@ -0,0 +1,173 @@
+use bevy::{
+    asset::{AssetLoader, AssetPath, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::{BoxedFuture, HashMap},
+};
+use serde::Deserialize;
+
+pub struct EnemyArchetypesAssetPlugin;
+
+impl Plugin for EnemyArchetypesAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<EnemyArchetypesAsset>()
+            .init_asset_loader::<EnemyArchetypesAssetLoader>();
+    }
+}
+
+#[derive(Debug, Deserialize, TypeUuid, TypePath)]
+#[uuid = "8a3f1c52-7e94-4b6d-b2a8-0d5e9f3c6a17"]
+pub struct EnemyArchetypesAsset {
+    pub archetypes: HashMap<String, EnemyArchetype>,
+}
+
+impl EnemyArchetypesAsset {
+    pub fn get(&self, name: &str) -> Option<&EnemyArchetype> {
+        self.archetypes.get(name)
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.archetypes.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnemyArchetype {
+    pub texture: String,
+    pub frame_size: Vec2,
+    pub frames: usize,
+    pub animation_period: f32,
+    #[serde(default)]
+    pub sprite_rotation: Option<f32>,
+    pub scale: f32,
+    pub health: f32,
+    pub speed: f32,
+    pub knockback_resistance: f32,
+    pub damage: f32,
+    pub experience: f32,
+    pub experience_count: u32,
+    pub hitbox_size: Vec2,
+    pub hurtbox_size: Vec2,
+    pub collision_size: Vec2,
+    pub behavior: EnemyBehavior,
+    #[serde(default = "enemy_archetype_spawn_count")]
+    pub spawn_count: u32,
+    #[serde(skip)]
+    pub atlas: Handle<TextureAtlas>,
+}
+
+fn enemy_archetype_spawn_count() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnemyBehavior {
+    pub steering: Vec<EnemySteering>,
+    #[serde(default)]
+    pub turn_speed: Option<f32>,
+    #[serde(default)]
+    pub knockback_stagger: f32,
+    #[serde(default)]
+    pub attack: Option<EnemyAttack>,
+}
+
+/// Steering modes are checked in order, the first active one drives movement.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum EnemySteering {
+    Seek,
+    Orbit {
+        radius: f32,
+        #[serde(default)]
+        approach: f32,
+        #[serde(default)]
+        angular_speed: f32,
+    },
+    KeepDistance {
+        min: f32,
+        max: f32,
+    },
+    Wander {
+        seconds: f32,
+        variance: f32,
+        duration: f32,
+    },
+    Flee {
+        health: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct EnemyAttack {
+    pub range: f32,
+    pub cooldown: f32,
+    pub damage: f32,
+    pub speed: f32,
+    pub size: f32,
+    #[serde(default = "enemy_attack_count")]
+    pub count: u32,
+    #[serde(default)]
+    pub spread: f32,
+    #[serde(default)]
+    pub knockback: f32,
+    #[serde(default = "enemy_attack_lifetime")]
+    pub lifetime: f32,
+}
+
+fn enemy_attack_count() -> u32 {
+    1
+}
+
+fn enemy_attack_lifetime() -> f32 {
+    2.
+}
+
+#[derive(Default)]
+pub struct EnemyArchetypesAssetLoader;
+
+impl AssetLoader for EnemyArchetypesAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let mut asset = ron::de::from_bytes::<EnemyArchetypesAsset>(bytes)?;
+            for (name, archetype) in asset.archetypes.iter_mut() {
+                if archetype.behavior.steering.is_empty() {
+                    return Err(bevy::asset::Error::msg(format!(
+                        "{}: behavior needs at least one steering mode",
+                        name
+                    )));
+                }
+                if archetype.frames == 0 || archetype.animation_period <= 0. {
+                    return Err(bevy::asset::Error::msg(format!(
+                        "{}: frames and animation_period must be greater than zero",
+                        name
+                    )));
+                }
+                let texture_path = AssetPath::new(archetype.texture.clone().into(), None);
+                let texture: Handle<Image> = load_context.get_handle(texture_path.clone());
+                let texture_atlas = TextureAtlas::from_grid(
+                    texture,
+                    archetype.frame_size,
+                    archetype.frames,
+                    1,
+                    None,
+                    None,
+                );
+                archetype.atlas = load_context.set_labeled_asset(
+                    name,
+                    LoadedAsset::new(texture_atlas).with_dependency(texture_path),
+                );
+            }
+            load_context.set_default_asset(LoadedAsset::new(asset));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["enemies.ron"]
+    }
+}
@@ -0,0 +1,120 @@
+use crate::game::prelude::*;
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::BoxedFuture,
+};
+use serde::{Deserialize, Serialize};
+
+pub struct EnemySpawnsAssetPlugin;
+
+impl Plugin for EnemySpawnsAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<EnemySpawnsAsset>()
+            .init_asset_loader::<EnemySpawnsAssetLoader>();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TypeUuid, TypePath)]
+#[uuid = "2c4e8f61-9b3d-4a7e-8d05-6f1b2a9c3e57"]
+pub struct EnemySpawnsAsset {
+    pub none: EnemySpawnLevel,
+    pub easy: EnemySpawnLevel,
+    pub medium: EnemySpawnLevel,
+    pub hard: EnemySpawnLevel,
+    pub midnight: EnemySpawnLevel,
+    pub davy: EnemySpawnLevel,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemySpawnLevel {
+    pub spawn_chances: Vec<EnemySpawnChance>,
+    pub seconds_per_spawn: f32,
+    pub spawn_max: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemySpawnChance {
+    pub chance: f32,
+    pub enemy: String,
+}
+
+impl EnemySpawnsAsset {
+    pub fn level(&self, threat_level: ThreatLevel) -> &EnemySpawnLevel {
+        match threat_level {
+            ThreatLevel::None => &self.none,
+            ThreatLevel::Easy => &self.easy,
+            ThreatLevel::Medium => &self.medium,
+            ThreatLevel::Hard => &self.hard,
+            ThreatLevel::Midnight => &self.midnight,
+            ThreatLevel::Davy => &self.davy,
+        }
+    }
+
+    pub fn level_mut(&mut self, threat_level: ThreatLevel) -> &mut EnemySpawnLevel {
+        match threat_level {
+            ThreatLevel::None => &mut self.none,
+            ThreatLevel::Easy => &mut self.easy,
+            ThreatLevel::Medium => &mut self.medium,
+            ThreatLevel::Hard => &mut self.hard,
+            ThreatLevel::Midnight => &mut self.midnight,
+            ThreatLevel::Davy => &mut self.davy,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for threat_level in ThreatLevel::all() {
+            let level = self.level(threat_level);
+            if !level.seconds_per_spawn.is_finite() || level.seconds_per_spawn <= 0. {
+                return Err(format!(
+                    "{:?}: seconds_per_spawn must be greater than zero",
+                    threat_level
+                ));
+            }
+            if level.spawn_max < 0 {
+                return Err(format!(
+                    "{:?}: spawn_max must not be negative",
+                    threat_level
+                ));
+            }
+            if level.spawn_max > 0 && level.spawn_chances.is_empty() {
+                return Err(format!(
+                    "{:?}: spawn_chances must not be empty when spawn_max is set",
+                    threat_level
+                ));
+            }
+            for spawn_chance in level.spawn_chances.iter() {
+                if !(0. ..=1.).contains(&spawn_chance.chance) {
+                    return Err(format!(
+                        "{:?}: chance for {} must be between 0 and 1",
+                        threat_level, spawn_chance.enemy
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct EnemySpawnsAssetLoader;
+
+impl AssetLoader for EnemySpawnsAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let asset = ron::de::from_bytes::<EnemySpawnsAsset>(bytes)?;
+            asset.validate().map_err(bevy::asset::Error::msg)?;
+            load_context.set_default_asset(LoadedAsset::new(asset));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["spawns.ron"]
+    }
+}
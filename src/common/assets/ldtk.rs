@@ -5,6 +5,7 @@ use bevy::{
     utils::BoxedFuture,
 };
 use serde::Deserialize;
+use std::path::Path;
 
 pub struct LdtkAssetPlugin;
 
@@ -21,6 +22,11 @@ pub struct LdtkAsset {
     pub map: ldtk2::Ldtk,
 }
 
+/// Reads external level files along with the project, since collision, navigation, the map and
+/// world locations are built from every level when the project loads. Spawning the tiles and
+/// entities of each level is what waits, see [`LdtkSpawnEvent::stream_distance`].
+///
+/// [`LdtkSpawnEvent::stream_distance`]: crate::common::ldtk::LdtkSpawnEvent::stream_distance
 #[derive(Default)]
 pub struct LdtkAssetLoader;
 
@@ -31,7 +37,28 @@ impl AssetLoader for LdtkAssetLoader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
         Box::pin(async move {
-            let map = ldtk2::Ldtk::from_str(std::str::from_utf8(bytes)?)?;
+            let mut map = ldtk2::Ldtk::from_str(std::str::from_utf8(bytes)?)?;
+            let directory = load_context
+                .path()
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            let levels = map.levels.iter_mut().chain(
+                map.worlds
+                    .iter_mut()
+                    .flat_map(|world| world.levels.iter_mut()),
+            );
+            for level in levels {
+                if level.layer_instances.is_some() {
+                    continue;
+                }
+                if let Some(rel_path) = level.external_rel_path.clone() {
+                    let bytes = load_context
+                        .read_asset_bytes(directory.join(rel_path))
+                        .await?;
+                    *level = serde_json::from_slice(&bytes)?;
+                }
+            }
             load_context.set_default_asset(LoadedAsset::new(LdtkAsset { map }));
             Ok(())
         })
@@ -0,0 +1,127 @@
+use bevy::{
+    asset::{AssetLoader, AssetPath, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    utils::{BoxedFuture, HashMap},
+};
+use serde::Deserialize;
+
+pub struct LocaleAssetPlugin;
+
+impl Plugin for LocaleAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<LocaleAsset>()
+            .add_asset::<LocaleIndexAsset>()
+            .init_asset_loader::<LocaleAssetLoader>()
+            .init_asset_loader::<LocaleIndexAssetLoader>();
+    }
+}
+
+#[derive(Debug, Deserialize, TypeUuid, TypePath)]
+#[uuid = "b1d7a3f2-64c9-4e08-a5d1-2f3c9e8b7a40"]
+pub struct LocaleAsset {
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub plural: LocalePlural,
+    pub strings: HashMap<String, LocaleString>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum LocalePlural {
+    /// Singular for exactly one.
+    #[default]
+    One,
+    /// Singular for zero and one.
+    ZeroOrOne,
+    /// No plural forms, always uses `other`.
+    None,
+}
+
+impl LocalePlural {
+    pub fn is_one(&self, count: u32) -> bool {
+        match *self {
+            Self::One => count == 1,
+            Self::ZeroOrOne => count <= 1,
+            Self::None => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum LocaleString {
+    Text(String),
+    Lines(Vec<String>),
+    Plural { one: String, other: String },
+}
+
+/// Lists the locale files to load, relative to the index.
+#[derive(Debug, TypeUuid, TypePath)]
+#[uuid = "6c2e8f41-9d3a-4b7e-8a15-3e0d7c9b2f58"]
+pub struct LocaleIndexAsset {
+    pub locales: Vec<Handle<LocaleAsset>>,
+}
+
+#[derive(Deserialize)]
+struct LocaleIndexFile {
+    locales: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct LocaleAssetLoader;
+
+impl AssetLoader for LocaleAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let asset = ron::de::from_bytes::<LocaleAsset>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(asset));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["locale.ron"]
+    }
+}
+
+#[derive(Default)]
+pub struct LocaleIndexAssetLoader;
+
+impl AssetLoader for LocaleIndexAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let index = ron::de::from_bytes::<LocaleIndexFile>(bytes)?;
+            let directory = load_context
+                .path()
+                .parent()
+                .map(|path| path.to_path_buf())
+                .unwrap_or_default();
+            let paths: Vec<AssetPath> = index
+                .locales
+                .iter()
+                .map(|file| AssetPath::new(directory.join(file), None))
+                .collect();
+            let locales = paths
+                .iter()
+                .map(|path| load_context.get_handle(path.clone()))
+                .collect();
+            load_context.set_default_asset(
+                LoadedAsset::new(LocaleIndexAsset { locales }).with_dependencies(paths),
+            );
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["locales.ron"]
+    }
+}
@@ -4,8 +4,18 @@ pub struct AssetsPlugin;
 
 impl Plugin for AssetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ldtk::LdtkAssetPlugin);
+        app.add_plugins((
+            dialogue::DialogueAssetPlugin,
+            enemy_archetypes::EnemyArchetypesAssetPlugin,
+            enemy_spawns::EnemySpawnsAssetPlugin,
+            ldtk::LdtkAssetPlugin,
+            locale::LocaleAssetPlugin,
+        ));
     }
 }
 
+pub mod dialogue;
+pub mod enemy_archetypes;
+pub mod enemy_spawns;
 pub mod ldtk;
+pub mod locale;
@@ -0,0 +1,51 @@
+use crate::common::{label::Label, prelude::*};
+use audio_plus::prelude::*;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContext};
+
+pub struct AudioDebugPlugin;
+
+impl Plugin for AudioDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, audio_debug);
+    }
+}
+
+fn audio_debug(
+    mut egui_query: Query<&mut EguiContext>,
+    mut menu_bar: ResMut<MenuBar>,
+    allocator: Res<AudioPlusVoiceAllocator>,
+    label_query: Query<&Label>,
+) {
+    menu_bar.item("Audio Voices", |open| {
+        let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
+        egui::Window::new("Audio Voices")
+            .open(open)
+            .show(egui_context.get_mut(), |ui| {
+                ui.label(format!(
+                    "Channels: {} / {}",
+                    allocator.used_channels(),
+                    allocator.channel_count()
+                ));
+                ui.label(format!("Pending: {}", allocator.pending_voices()));
+                ui.label(format!("Stolen: {}", allocator.stolen_voices()));
+                ui.separator();
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for (index, usage) in allocator.channels().enumerate() {
+                        let Some(entity) = usage.entity else { continue };
+                        let name = label_query
+                            .get(entity)
+                            .map(|label| label.0.clone())
+                            .unwrap_or_else(|_| format!("{:?}", entity));
+                        ui.label(format!(
+                            "{:>2}: {} (priority {}, volume {:.2})",
+                            index + 1,
+                            name,
+                            usage.priority,
+                            usage.volume
+                        ));
+                    }
+                });
+            });
+    });
+}
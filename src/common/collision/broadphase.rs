@@ -0,0 +1,159 @@
+use bevy::{prelude::*, utils::HashMap};
+
+const CELL_SIZE: f32 = 256.;
+const MAX_CELLS_PER_ENTRY: u64 = 64;
+
+/// Uniform grid of entry indices, rebuilt whenever the collision query is updated.
+#[derive(Default)]
+pub struct CollisionBroadphase {
+    cells: HashMap<IVec2, Vec<usize>>,
+    oversized: Vec<usize>,
+}
+
+impl CollisionBroadphase {
+    pub fn clear(&mut self) {
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        self.oversized.clear();
+    }
+
+    pub fn insert(&mut self, index: usize, min: Vec2, max: Vec2) {
+        let (min_cell, max_cell) = cell_range(min, max);
+        if !min.is_finite()
+            || !max.is_finite()
+            || cell_count(min_cell, max_cell) > MAX_CELLS_PER_ENTRY
+        {
+            self.oversized.push(index);
+            return;
+        }
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(index);
+            }
+        }
+    }
+
+    /// Entry indices whose bounds may touch `min..max`, sorted and without duplicates.
+    pub fn candidates(&self, min: Vec2, max: Vec2) -> Vec<usize> {
+        let mut candidates = self.oversized.clone();
+        let (min_cell, max_cell) = cell_range(min, max);
+        if cell_count(min_cell, max_cell) > self.cells.len() as u64 {
+            for cell in self.cells.values() {
+                candidates.extend_from_slice(cell);
+            }
+        } else {
+            for x in min_cell.x..=max_cell.x {
+                for y in min_cell.y..=max_cell.y {
+                    if let Some(cell) = self.cells.get(&IVec2::new(x, y)) {
+                        candidates.extend_from_slice(cell);
+                    }
+                }
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
+}
+
+fn cell_range(min: Vec2, max: Vec2) -> (IVec2, IVec2) {
+    (
+        (min / CELL_SIZE).floor().as_ivec2(),
+        (max / CELL_SIZE).floor().as_ivec2(),
+    )
+}
+
+fn cell_count(min_cell: IVec2, max_cell: IVec2) -> u64 {
+    let width = (max_cell.x as i64 - min_cell.x as i64 + 1) as u64;
+    let height = (max_cell.y as i64 - min_cell.y as i64 + 1) as u64;
+    width.saturating_mul(height)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::collision::{
+        shape::CollisionShape, CollisionFilter, CollisionQuery, CollisionQueryEntry,
+    };
+    use bevy::prelude::*;
+    use rand::prelude::*;
+
+    const ENTRY_COUNT: u32 = 2000;
+    const QUERY_COUNT: usize = 2000;
+    const WORLD_SIZE: f32 = 8000.;
+
+    fn random_position(rng: &mut StdRng) -> Vec2 {
+        Vec2::new(
+            rng.gen_range(-WORLD_SIZE..WORLD_SIZE),
+            rng.gen_range(-WORLD_SIZE..WORLD_SIZE),
+        )
+    }
+
+    fn random_shape(rng: &mut StdRng) -> CollisionShape {
+        match rng.gen_range(0..10) {
+            0 => CollisionShape::None,
+            1..=2 => CollisionShape::Point,
+            3 => CollisionShape::Rect {
+                size: Vec2::new(rng.gen_range(500. ..4000.), rng.gen_range(500. ..4000.)),
+            },
+            4 => CollisionShape::Circle {
+                radius: rng.gen_range(1. ..200.),
+            },
+            5 => CollisionShape::OrientedRect {
+                size: Vec2::new(rng.gen_range(1. ..300.), rng.gen_range(1. ..300.)),
+                rotation: rng.gen_range(0. ..std::f32::consts::TAU),
+            },
+            _ => CollisionShape::Rect {
+                size: Vec2::new(rng.gen_range(1. ..300.), rng.gen_range(1. ..300.)),
+            },
+        }
+    }
+
+    #[test]
+    fn broadphase_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut collision_query = CollisionQuery::default();
+        collision_query.set_entries(
+            (0..ENTRY_COUNT)
+                .map(|index| CollisionQueryEntry {
+                    entity: Entity::from_raw(index),
+                    position: random_position(&mut rng),
+                    shape: random_shape(&mut rng),
+                    flags: rng.gen_range(0..4),
+                })
+                .collect(),
+        );
+        let mut hits = 0;
+        for _ in 0..QUERY_COUNT {
+            let position = random_position(&mut rng);
+            let velocity = Vec2::new(rng.gen_range(-300. ..300.), rng.gen_range(-300. ..300.));
+            let shape = random_shape(&mut rng);
+            let filter = if rng.gen_bool(0.5) {
+                Some(CollisionFilter {
+                    exclude_entity: Entity::from_raw(rng.gen_range(0..ENTRY_COUNT)),
+                    flags: rng.gen_range(1..4),
+                })
+            } else {
+                None
+            };
+            let [broadphase, brute_force] = [true, false].map(|enabled| {
+                collision_query.set_broadphase_enabled(enabled);
+                (
+                    collision_query.check(position, shape, filter),
+                    collision_query.check_moving(position, velocity, shape, filter),
+                    collision_query.check_all(position, shape, filter),
+                    collision_query.shape_cast(
+                        position,
+                        velocity,
+                        velocity.length(),
+                        shape,
+                        filter,
+                    ),
+                )
+            });
+            assert_eq!(broadphase, brute_force, "query {:?} {:?}", position, shape);
+            hits += brute_force.2.len();
+        }
+        assert!(hits > 0, "queries should overlap some entries");
+    }
+}
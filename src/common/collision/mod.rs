@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use bevy::transform::TransformSystem;
+use broadphase::CollisionBroadphase;
 use shape::CollisionShape;
 
 pub const COLLISION_FLAG: u32 = 1;
@@ -28,9 +29,28 @@ pub struct CollisionQueryEntry {
     pub flags: u32,
 }
 
-#[derive(Default, Resource)]
+#[derive(Resource)]
 pub struct CollisionQuery {
     entries: Vec<CollisionQueryEntry>,
+    broadphase: CollisionBroadphase,
+    broadphase_enabled: bool,
+}
+
+impl Default for CollisionQuery {
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            broadphase: CollisionBroadphase::default(),
+            broadphase_enabled: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CollisionHit {
+    pub entity: Entity,
+    pub distance: f32,
+    pub normal: Vec2,
 }
 
 #[derive(Copy, Clone)]
@@ -39,6 +59,12 @@ pub struct CollisionFilter {
     pub flags: u32,
 }
 
+impl CollisionFilter {
+    fn allows(&self, entry: &CollisionQueryEntry) -> bool {
+        self.exclude_entity != entry.entity && self.flags & entry.flags != 0
+    }
+}
+
 impl CollisionQuery {
     pub fn check(
         &self,
@@ -46,15 +72,11 @@ impl CollisionQuery {
         shape: CollisionShape,
         filter: Option<CollisionFilter>,
     ) -> Option<(Entity, Vec2)> {
-        for entry in self.entries.iter() {
-            if shape.overlaps(position, entry.shape, entry.position) {
-                if let Some(ref filter) = filter {
-                    if filter.exclude_entity != entry.entity && filter.flags & entry.flags != 0 {
-                        return Some((entry.entity, entry.position - position));
-                    }
-                } else {
-                    return Some((entry.entity, entry.position - position));
-                }
+        for entry in self.candidates(shape.bounds(position)) {
+            if shape.overlaps(position, entry.shape, entry.position)
+                && filter.map_or(true, |filter| filter.allows(entry))
+            {
+                return Some((entry.entity, entry.position - position));
             }
         }
         None
@@ -68,28 +90,16 @@ impl CollisionQuery {
         filter: Option<CollisionFilter>,
     ) -> Option<(Entity, f32)> {
         let mut result: Option<(Entity, f32)> = None;
-        for entry in self.entries.iter() {
+        for entry in self.candidates(shape.bounds_moving(position, velocity)) {
             if let Some(collide_time) =
                 shape.overlaps_moving(position, velocity, entry.shape, entry.position, Vec2::ZERO)
             {
-                if let Some(ref filter) = filter {
-                    if filter.exclude_entity != entry.entity && filter.flags & entry.flags != 0 {
-                        if let Some((_, other_collide_time)) = result {
-                            if collide_time < other_collide_time {
-                                result = Some((entry.entity, collide_time));
-                            }
-                        } else {
-                            result = Some((entry.entity, collide_time));
-                        }
-                    }
-                } else {
-                    if let Some((_, other_collide_time)) = result {
-                        if collide_time < other_collide_time {
-                            result = Some((entry.entity, collide_time));
-                        }
-                    } else {
-                        result = Some((entry.entity, collide_time));
-                    }
+                if !filter.map_or(true, |filter| filter.allows(entry)) {
+                    continue;
+                }
+                let closer = result.map_or(true, |(_, other_time)| collide_time < other_time);
+                if closer {
+                    result = Some((entry.entity, collide_time));
                 }
             }
         }
@@ -103,33 +113,109 @@ impl CollisionQuery {
         filter: Option<CollisionFilter>,
     ) -> Vec<Entity> {
         let mut vec: Vec<Entity> = vec![];
-        for entry in self.entries.iter() {
-            if shape.overlaps(position, entry.shape, entry.position) {
-                if let Some(ref filter) = filter {
-                    if filter.exclude_entity != entry.entity && filter.flags & entry.flags != 0 {
-                        vec.push(entry.entity);
-                    }
-                } else {
-                    vec.push(entry.entity);
-                }
+        for entry in self.candidates(shape.bounds(position)) {
+            if shape.overlaps(position, entry.shape, entry.position)
+                && filter.map_or(true, |filter| filter.allows(entry))
+            {
+                vec.push(entry.entity);
             }
         }
         vec
     }
 
+    pub fn raycast(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        max_distance: f32,
+        filter: Option<CollisionFilter>,
+    ) -> Option<CollisionHit> {
+        self.shape_cast(
+            origin,
+            direction,
+            max_distance,
+            CollisionShape::Point,
+            filter,
+        )
+    }
+
+    /// Sweeps `shape` from `position` along `direction` and returns the closest hit within
+    /// `max_distance`. Entries already overlapping `shape` at `position` are hit at distance 0.
+    pub fn shape_cast(
+        &self,
+        position: Vec2,
+        direction: Vec2,
+        max_distance: f32,
+        shape: CollisionShape,
+        filter: Option<CollisionFilter>,
+    ) -> Option<CollisionHit> {
+        let velocity = direction.normalize_or_zero() * max_distance;
+        let mut result: Option<CollisionHit> = None;
+        for entry in self.candidates(shape.bounds_moving(position, velocity)) {
+            if !filter.map_or(true, |filter| filter.allows(entry)) {
+                continue;
+            }
+            if let Some((time, normal)) =
+                shape.cast(position, velocity, entry.shape, entry.position)
+            {
+                let distance = time * max_distance;
+                if result.map_or(true, |hit| distance < hit.distance) {
+                    result = Some(CollisionHit {
+                        entity: entry.entity,
+                        distance,
+                        normal,
+                    });
+                }
+            }
+        }
+        result
+    }
+
     pub fn update(&mut self, query: &Query<(Entity, &GlobalTransform, &Collision)>) {
-        self.entries.clear();
-        for (entity, transform, collision) in query.iter() {
-            if transform.translation().is_finite() {
-                self.entries.push(CollisionQueryEntry {
+        self.set_entries(
+            query
+                .iter()
+                .filter(|(_, transform, _)| transform.translation().is_finite())
+                .map(|(entity, transform, collision)| CollisionQueryEntry {
                     entity,
                     position: transform.translation().truncate(),
-                    shape: collision.shape,
+                    shape: collision.shape.with_global_rotation(transform),
                     flags: collision.flags,
-                });
+                })
+                .collect(),
+        );
+    }
+
+    pub fn set_entries(&mut self, entries: Vec<CollisionQueryEntry>) {
+        self.entries = entries;
+        self.broadphase.clear();
+        for (index, entry) in self.entries.iter().enumerate() {
+            if let Some((min, max)) = entry.shape.bounds(entry.position) {
+                self.broadphase.insert(index, min, max);
             }
         }
     }
+
+    /// Disabling the broadphase scans every entry, which is only useful to verify results.
+    pub fn set_broadphase_enabled(&mut self, enabled: bool) {
+        self.broadphase_enabled = enabled;
+    }
+
+    fn candidates(
+        &self,
+        bounds: Option<(Vec2, Vec2)>,
+    ) -> Box<dyn Iterator<Item = &CollisionQueryEntry> + '_> {
+        let Some((min, max)) = bounds else { return Box::new(std::iter::empty()) };
+        if !self.broadphase_enabled || !min.is_finite() || !max.is_finite() {
+            return Box::new(self.entries.iter());
+        }
+        Box::new(
+            self.broadphase
+                .candidates(min, max)
+                .into_iter()
+                .map(|index| &self.entries[index]),
+        )
+    }
 }
 
 fn update_collision_query(
@@ -139,4 +225,5 @@ fn update_collision_query(
     collision_query.update(&query);
 }
 
+mod broadphase;
 pub mod shape;
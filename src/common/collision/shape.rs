@@ -4,27 +4,45 @@ use bevy::prelude::*;
 pub enum CollisionShape {
     None,
     Point,
-    Rect { size: Vec2 },
+    Rect {
+        size: Vec2,
+    },
+    Circle {
+        radius: f32,
+    },
+    /// Rectangle rotated around its center. `rotation` is added to the owner's rotation by
+    /// [`CollisionShape::rotated`], whereas `Rect` always stays axis aligned.
+    OrientedRect {
+        size: Vec2,
+        rotation: f32,
+    },
 }
 
 impl CollisionShape {
     pub fn overlaps(&self, a_position: Vec2, b_shape: CollisionShape, b_position: Vec2) -> bool {
-        match *self {
-            CollisionShape::None => false,
-            CollisionShape::Point => match b_shape {
-                CollisionShape::None => false,
-                CollisionShape::Point => check_point_point(a_position, b_position),
-                CollisionShape::Rect { size: b_size } => {
-                    check_point_rect(a_position, b_position, b_size)
-                }
-            },
-            CollisionShape::Rect { size: a_size } => match b_shape {
-                CollisionShape::None => false,
-                CollisionShape::Point => check_point_rect(b_position, a_position, a_size),
-                CollisionShape::Rect { size: b_size } => {
-                    check_rect_rect(a_position, a_size, b_position, b_size)
-                }
-            },
+        match (*self, b_shape) {
+            (CollisionShape::None, _) | (_, CollisionShape::None) => false,
+            (CollisionShape::Point, CollisionShape::Point) => {
+                check_point_point(a_position, b_position)
+            }
+            (CollisionShape::Point, CollisionShape::Rect { size: b_size }) => {
+                check_point_rect(a_position, b_position, b_size)
+            }
+            (CollisionShape::Rect { size: a_size }, CollisionShape::Point) => {
+                check_point_rect(b_position, a_position, a_size)
+            }
+            (CollisionShape::Rect { size: a_size }, CollisionShape::Rect { size: b_size }) => {
+                check_rect_rect(a_position, a_size, b_position, b_size)
+            }
+            _ => {
+                let (Some(a), Some(b)) = (
+                    ConvexShape::new(*self, a_position),
+                    ConvexShape::new(b_shape, b_position),
+                ) else {
+                    return false;
+                };
+                check_convex_convex(&a, &b)
+            }
         }
     }
 
@@ -36,26 +54,87 @@ impl CollisionShape {
         b_position: Vec2,
         b_velocity: Vec2,
     ) -> Option<f32> {
-        match *self {
-            CollisionShape::None => None,
-            CollisionShape::Point => match b_shape {
-                CollisionShape::None => None,
-                CollisionShape::Point => None,
-                CollisionShape::Rect { .. } => None,
-            },
-            CollisionShape::Rect { size: a_size } => match b_shape {
-                CollisionShape::None => None,
-                CollisionShape::Point => None,
-                CollisionShape::Rect { size: b_size } => check_rect_moving_rect(
+        match (*self, b_shape) {
+            (CollisionShape::None, _) | (_, CollisionShape::None) => None,
+            (CollisionShape::Rect { size: a_size }, CollisionShape::Rect { size: b_size }) => {
+                check_rect_moving_rect(
                     a_position,
                     a_size,
                     b_position,
                     b_size,
                     b_velocity - a_velocity,
-                ),
+                )
+            }
+            _ => {
+                let a = ConvexShape::new(*self, a_position)?;
+                let b = ConvexShape::new(b_shape, b_position)?;
+                cast_convex_convex(&a, &b, a_velocity - b_velocity).map(|(t, _)| t)
+            }
+        }
+    }
+
+    /// Like [`CollisionShape::overlaps_moving`] against a static `b_shape`, but also returns the
+    /// surface normal of `b_shape` at the point of contact.
+    pub fn cast(
+        &self,
+        a_position: Vec2,
+        a_velocity: Vec2,
+        b_shape: CollisionShape,
+        b_position: Vec2,
+    ) -> Option<(f32, Vec2)> {
+        let a = ConvexShape::new(*self, a_position)?;
+        let b = ConvexShape::new(b_shape, b_position)?;
+        cast_convex_convex(&a, &b, a_velocity)
+    }
+
+    /// Adds `angle` to oriented shapes. Other shapes are unaffected by rotation.
+    pub fn rotated(&self, angle: f32) -> Self {
+        match *self {
+            CollisionShape::OrientedRect { size, rotation } => CollisionShape::OrientedRect {
+                size,
+                rotation: rotation + angle,
             },
+            shape => shape,
+        }
+    }
+
+    pub fn with_global_rotation(&self, transform: &GlobalTransform) -> Self {
+        let (_, rotation, _) = transform.to_scale_rotation_translation();
+        self.rotated(rotation.to_euler(EulerRot::ZYX).0)
+    }
+
+    pub fn bounds(&self, position: Vec2) -> Option<(Vec2, Vec2)> {
+        match *self {
+            CollisionShape::None => None,
+            CollisionShape::Point => Some((position, position)),
+            CollisionShape::Rect { size } => {
+                let rect = rect_extents(position, size);
+                Some((
+                    Vec2::new(rect.left, rect.bottom),
+                    Vec2::new(rect.right, rect.top),
+                ))
+            }
+            CollisionShape::Circle { radius } => Some((
+                position - Vec2::splat(radius),
+                position + Vec2::splat(radius),
+            )),
+            CollisionShape::OrientedRect { size, rotation } => {
+                let axis = Vec2::from_angle(rotation).abs();
+                let half_size = size * 0.5;
+                let extents = Vec2::new(
+                    axis.x * half_size.x + axis.y * half_size.y,
+                    axis.y * half_size.x + axis.x * half_size.y,
+                );
+                Some((position - extents, position + extents))
+            }
         }
     }
+
+    pub fn bounds_moving(&self, position: Vec2, velocity: Vec2) -> Option<(Vec2, Vec2)> {
+        let (start_min, start_max) = self.bounds(position)?;
+        let (end_min, end_max) = self.bounds(position + velocity)?;
+        Some((start_min.min(end_min), start_max.max(end_max)))
+    }
 }
 
 impl Default for CollisionShape {
@@ -178,3 +257,180 @@ pub fn check_rect_moving_rect(
         Some(t_first)
     }
 }
+
+/// Convex polygon (up to four corners) inflated by `radius`, used for every pair of shapes that
+/// the axis aligned checks above don't cover.
+struct ConvexShape {
+    points: [Vec2; 4],
+    len: usize,
+    radius: f32,
+}
+
+impl ConvexShape {
+    fn new(shape: CollisionShape, position: Vec2) -> Option<Self> {
+        let (size, rotation, radius) = match shape {
+            CollisionShape::None => return None,
+            CollisionShape::Point => (None, 0., 0.),
+            CollisionShape::Rect { size } => (Some(size), 0., 0.),
+            CollisionShape::Circle { radius } => (None, 0., radius),
+            CollisionShape::OrientedRect { size, rotation } => (Some(size), rotation, 0.),
+        };
+        if let Some(size) = size {
+            let half_size = size * 0.5;
+            let axis = Vec2::from_angle(rotation);
+            let corners = [
+                Vec2::new(-half_size.x, -half_size.y),
+                Vec2::new(half_size.x, -half_size.y),
+                Vec2::new(half_size.x, half_size.y),
+                Vec2::new(-half_size.x, half_size.y),
+            ];
+            Some(Self {
+                points: corners.map(|corner| position + axis.rotate(corner)),
+                len: 4,
+                radius,
+            })
+        } else {
+            Some(Self {
+                points: [position; 4],
+                len: 1,
+                radius,
+            })
+        }
+    }
+
+    fn points(&self) -> &[Vec2] {
+        &self.points[..self.len]
+    }
+}
+
+/// Convex hull of every `b - a`. `a` overlaps `b` when the origin is inside the hull.
+fn minkowski_difference(a: &ConvexShape, b: &ConvexShape) -> Vec<Vec2> {
+    let mut points = Vec::with_capacity(a.len * b.len);
+    for b_point in b.points() {
+        for a_point in a.points() {
+            points.push(*b_point - *a_point);
+        }
+    }
+    convex_hull(points)
+}
+
+/// Counter-clockwise hull without collinear points. Degenerate input returns one or two points.
+fn convex_hull(mut points: Vec<Vec2>) -> Vec<Vec2> {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    let mut hull: Vec<Vec2> = Vec::with_capacity(points.len() + 1);
+    for &point in points.iter() {
+        while hull.len() >= 2 && !is_left_turn(&hull, point) {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+    let lower_len = hull.len() + 1;
+    for &point in points.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !is_left_turn(&hull, point) {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+    hull.pop();
+    hull
+}
+
+fn is_left_turn(hull: &[Vec2], point: Vec2) -> bool {
+    let a = hull[hull.len() - 2];
+    let b = hull[hull.len() - 1];
+    (b - a).perp_dot(point - a) > 0.
+}
+
+fn hull_edges(hull: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    let len = if hull.len() >= 2 { hull.len() } else { 0 };
+    (0..len).map(move |i| (hull[i], hull[(i + 1) % hull.len()]))
+}
+
+fn hull_distance_to_origin(hull: &[Vec2]) -> f32 {
+    if hull.len() == 1 {
+        return hull[0].length();
+    }
+    if hull.len() >= 3 && hull_edges(hull).all(|(a, b)| (b - a).perp_dot(-a) >= 0.) {
+        return 0.;
+    }
+    hull_edges(hull)
+        .map(|(a, b)| {
+            let edge = b - a;
+            let t = (-a.dot(edge) / edge.length_squared()).clamp(0., 1.);
+            (a + edge * t).length()
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+fn check_convex_convex(a: &ConvexShape, b: &ConvexShape) -> bool {
+    hull_distance_to_origin(&minkowski_difference(a, b)) <= a.radius + b.radius
+}
+
+/// Casts a ray from the origin along `a_velocity` (relative to `b`) against the inflated
+/// Minkowski difference. Hitting an inflated edge or a corner circle first is exact. The normal
+/// is the surface normal of `b` at the point of contact, facing `a`.
+fn cast_convex_convex(a: &ConvexShape, b: &ConvexShape, a_velocity: Vec2) -> Option<(f32, Vec2)> {
+    let hull = minkowski_difference(a, b);
+    let radius = a.radius + b.radius;
+    let fallback_normal = -a_velocity.normalize_or_zero();
+    if hull_distance_to_origin(&hull) <= radius {
+        return Some((0., fallback_normal));
+    }
+    if a_velocity.length_squared() == 0. {
+        return None;
+    }
+    let edges = hull_edges(&hull).filter_map(|(start, end)| {
+        let edge = end - start;
+        let normal = Vec2::new(edge.y, -edge.x).normalize_or_zero();
+        let offset = normal * radius;
+        ray_segment(a_velocity, start + offset, end + offset).map(|t| (t, normal))
+    });
+    let corners = hull.iter().filter_map(|point| {
+        ray_circle(a_velocity, *point, radius).map(|t| {
+            let normal = (a_velocity * t - *point).try_normalize();
+            (t, normal.unwrap_or(fallback_normal))
+        })
+    });
+    edges
+        .chain(corners)
+        .fold(None, |result: Option<(f32, Vec2)>, hit| match result {
+            Some(result) if result.0 <= hit.0 => Some(result),
+            _ => Some(hit),
+        })
+        .filter(|(t, _)| *t <= 1.)
+}
+
+fn ray_circle(direction: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let a = direction.length_squared();
+    let b = -2. * direction.dot(center);
+    let c = center.length_squared() - radius * radius;
+    let discriminant = b * b - 4. * a * c;
+    if discriminant < 0. {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / (2. * a);
+    if t >= 0. {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+fn ray_segment(direction: Vec2, start: Vec2, end: Vec2) -> Option<f32> {
+    let edge = end - start;
+    let denominator = direction.perp_dot(edge);
+    if denominator == 0. {
+        return None;
+    }
+    let t = start.perp_dot(edge) / denominator;
+    let s = start.perp_dot(direction) / denominator;
+    if t >= 0. && (0. ..=1.).contains(&s) {
+        Some(t)
+    } else {
+        None
+    }
+}
@@ -3,92 +3,170 @@ use bevy::prelude::*;
 use bevy_egui::{egui, EguiContext};
 use std::{
     any::{type_name, TypeId},
-    collections::VecDeque,
+    collections::{HashSet, VecDeque},
     marker::PhantomData,
-    sync::{RwLock, RwLockWriteGuard},
 };
 
 pub trait CutsceneType: Cutscene + Default + Clone + Send + Sync + 'static {}
 impl<T> CutsceneType for T where T: Cutscene + Default + Clone + Send + Sync + 'static {}
 
-const UPDATE_BUFFER: u32 = 3;
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum CutsceneSystem {
+    Events,
+    Init,
+    Update,
+    Advance,
+    Finish,
+}
 
 #[derive(Default, Debug, Resource)]
 pub struct Cutscenes {
-    // FIXME: the way cutscenes works is dreadful. it was a quick hack during the jam, made worse
-    // by Bevy's API forbidding our awful practices, but us doing it anyway. this class needs a
-    // serious overhaul, and it shouldn't be too difficult to do!
-    data: RwLock<CutscenesData>,
+    running: Vec<RunningCutscene>,
+    backlog: VecDeque<QueuedCutscene>,
+    nested_steps: HashSet<(TypeId, usize)>,
+}
+
+#[derive(Debug)]
+struct QueuedCutscene {
+    name: &'static str,
+    type_id: TypeId,
+    parent: Option<TypeId>,
+    exclusive: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CutscenePhase {
+    Start,
+    Pending,
+    Init,
+    Update,
+}
+
+#[derive(Debug)]
+struct RunningCutscene {
+    name: &'static str,
+    type_id: TypeId,
+    parent: Option<TypeId>,
+    exclusive: bool,
+    phase: CutscenePhase,
+    step: usize,
+    skip: bool,
+    cancel: bool,
+    time: f32,
 }
 
-#[derive(Default, Debug)]
-pub struct CutscenesData {
-    running_cutscene: Option<RunningCutscene>,
-    backlog_cutscenes: VecDeque<(String, TypeId)>,
+impl RunningCutscene {
+    fn set_step(&mut self, step: usize) {
+        self.phase = CutscenePhase::Pending;
+        self.step = step;
+        self.time = 0.;
+    }
 }
 
 impl Cutscenes {
-    fn queue(&mut self, name: String, type_id: TypeId) {
-        let mut lock = self.data.write().unwrap();
-        lock.backlog_cutscenes.push_back((name, type_id));
+    fn queue(&mut self, queued: QueuedCutscene) {
+        self.backlog.push_back(queued);
+        self.try_run_next();
     }
 
     fn try_run_next(&mut self) {
-        let mut lock = self.data.write().unwrap();
-        if matches!(lock.running_cutscene, None) {
-            if let Some((name, type_id)) = lock.backlog_cutscenes.pop_front() {
-                lock.running_cutscene = Some(RunningCutscene {
-                    name,
-                    type_id,
-                    started: false,
-                    init: true,
-                    updates: 0,
-                    step: 0,
-                    skip: false,
-                    time: 0.,
-                });
+        let mut exclusive_blocked = self.running.iter().any(|running| running.exclusive);
+        let mut index = 0;
+        while index < self.backlog.len() {
+            let queued = &self.backlog[index];
+            let blocked =
+                self.get(queued.type_id).is_some() || (queued.exclusive && exclusive_blocked);
+            if blocked {
+                exclusive_blocked |= queued.exclusive;
+                index += 1;
+                continue;
+            }
+            let queued = self.backlog.remove(index).unwrap();
+            exclusive_blocked |= queued.exclusive;
+            let skip = queued
+                .parent
+                .and_then(|parent| self.get(parent))
+                .map_or(false, |parent| parent.skip);
+            self.running.push(RunningCutscene {
+                name: queued.name,
+                type_id: queued.type_id,
+                parent: queued.parent,
+                exclusive: queued.exclusive,
+                phase: CutscenePhase::Start,
+                step: 0,
+                skip,
+                cancel: false,
+                time: 0.,
+            });
+        }
+    }
+
+    fn get(&self, type_id: TypeId) -> Option<&RunningCutscene> {
+        self.running
+            .iter()
+            .find(|running| running.type_id == type_id)
+    }
+
+    fn get_mut(&mut self, type_id: TypeId) -> Option<&mut RunningCutscene> {
+        self.running
+            .iter_mut()
+            .find(|running| running.type_id == type_id)
+    }
+
+    fn in_step(&self, type_id: TypeId, step: usize, phase: CutscenePhase) -> bool {
+        self.get(type_id).map_or(false, |running| {
+            running.step == step
+                && running.phase == phase
+                && !running.cancel
+                && (phase != CutscenePhase::Update || !running.skip)
+        })
+    }
+
+    fn has_children(&self, type_id: TypeId) -> bool {
+        self.running
+            .iter()
+            .any(|running| running.parent == Some(type_id))
+            || self
+                .backlog
+                .iter()
+                .any(|queued| queued.parent == Some(type_id))
+    }
+
+    fn mark_children(&mut self, type_id: TypeId, f: fn(&mut RunningCutscene)) {
+        let mut parents = vec![type_id];
+        while let Some(parent) = parents.pop() {
+            for running in self.running.iter_mut() {
+                if running.parent == Some(parent) {
+                    f(running);
+                    parents.push(running.type_id);
+                }
             }
         }
     }
 
     pub fn skipping(&self) -> bool {
-        let lock = self.data.read().unwrap();
-        if let Some(running_cutscene) = &lock.running_cutscene {
-            running_cutscene.skip
-        } else {
-            false
-        }
+        self.running.iter().any(|running| running.skip)
     }
 
     pub fn running(&self) -> bool {
-        let lock = self.data.read().unwrap();
-        !matches!(lock.running_cutscene, None)
+        !self.running.is_empty()
     }
 
-    pub fn clear(&mut self) {
-        let mut lock = self.data.write().unwrap();
-        lock.running_cutscene = None;
-        lock.backlog_cutscenes = VecDeque::new();
+    pub fn is_running<T>(&self) -> bool
+    where
+        T: CutsceneType,
+    {
+        self.get(TypeId::of::<T>()).is_some()
     }
-}
 
-#[derive(Debug)]
-struct RunningCutscene {
-    name: String,
-    type_id: TypeId,
-    started: bool,
-    init: bool,
-    updates: u32,
-    step: usize,
-    skip: bool,
-    time: f32,
-}
+    pub fn clear(&mut self) {
+        self.running = vec![];
+        self.backlog = VecDeque::new();
+    }
 
-impl RunningCutscene {
-    fn reset(&mut self) {
-        self.init = true;
-        self.updates = 0;
-        self.time = 0.;
+    fn waiting_for_children(&self, running: &RunningCutscene) -> bool {
+        self.nested_steps.contains(&(running.type_id, running.step))
+            && self.has_children(running.type_id)
     }
 }
 
@@ -97,8 +175,27 @@ pub struct CutscenePlugin;
 impl Plugin for CutscenePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Cutscenes>()
-            .init_resource::<Cutscenes>()
-            .add_systems(Update, cutscene_debug);
+            .configure_sets(
+                Update,
+                (
+                    CutsceneSystem::Events,
+                    CutsceneSystem::Init,
+                    CutsceneSystem::Update,
+                    CutsceneSystem::Advance,
+                    CutsceneSystem::Finish,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_deferred
+                        .after(CutsceneSystem::Init)
+                        .before(CutsceneSystem::Update),
+                    cutscene_advance.in_set(CutsceneSystem::Advance),
+                    cutscene_debug,
+                ),
+            );
     }
 }
 
@@ -116,59 +213,49 @@ impl AddAppCutscene for App {
         self.init_resource::<T>();
         self.init_resource::<CutsceneInitialValues<T>>();
         self.add_event::<CutsceneStartEvent<T>>();
+        self.add_event::<CutsceneStartParallelEvent<T>>();
         self.add_event::<CutsceneContinueEvent<T>>();
         self.add_event::<CutsceneSkipEvent<T>>();
+        self.add_event::<CutsceneCancelEvent<T>>();
+        self.add_event::<CutsceneCompleteEvent<T>>();
+        let mut builder = CutsceneBuilder {
+            app: self,
+            type_id: TypeId::of::<T>(),
+            step: 0,
+        };
+        T::build(&mut builder);
+        let steps = builder.step;
         self.add_systems(
             Update,
             (
                 cutscene_start::<T>,
                 cutscene_continue::<T>,
                 cutscene_skip::<T>,
-            ),
+                cutscene_cancel::<T>,
+                cutscene_begin::<T>,
+            )
+                .chain()
+                .in_set(CutsceneSystem::Events),
         );
-        let mut builder = CutsceneBuilder {
-            app: self,
-            type_id: TypeId::of::<T>(),
-            step: 0,
-        };
-        T::build(&mut builder);
-        let step = builder.step;
         self.add_systems(
             Update,
-            move |mut cutscene_state: ResMut<Cutscenes>,
-                  mut state: ResMut<T>,
-                  mut initial_values: ResMut<CutsceneInitialValues<T>>| {
-                let mut try_next_run = false;
-                let mut reset = false;
-                {
-                    let mut lock = cutscene_state.data.write().unwrap();
-                    if let Some(running_cutscene) = &mut lock.running_cutscene {
-                        if running_cutscene.type_id == TypeId::of::<T>() {
-                            if !running_cutscene.started {
-                                *state = initial_values.0.pop_front().unwrap();
-                                running_cutscene.started = true;
-                            } else if running_cutscene.step == step {
-                                lock.running_cutscene = None;
-                                try_next_run = true;
-                            } else if running_cutscene.skip {
-                                if running_cutscene.updates == UPDATE_BUFFER {
-                                    reset = true;
-                                    running_cutscene.step += 1;
-                                }
-                            }
-                        }
-                    }
-                }
-                if try_next_run {
-                    cutscene_state.try_run_next();
+            (move |mut cutscenes: ResMut<Cutscenes>,
+                   mut ev_complete: EventWriter<CutsceneCompleteEvent<T>>| {
+                let Some(running) = cutscenes.get(TypeId::of::<T>()) else { return };
+                if running.step < steps && !running.cancel {
+                    return;
                 }
-                if reset {
-                    let mut lock = cutscene_state.data.write().unwrap();
-                    if let Some(running_cutscene) = &mut lock.running_cutscene {
-                        running_cutscene.reset();
-                    }
-                }
-            },
+                let cancelled = running.cancel;
+                cutscenes
+                    .running
+                    .retain(|running| running.type_id != TypeId::of::<T>());
+                cutscenes.try_run_next();
+                ev_complete.send(CutsceneCompleteEvent {
+                    cancelled,
+                    _phantom: PhantomData,
+                });
+            })
+            .in_set(CutsceneSystem::Finish),
         );
         self
     }
@@ -190,40 +277,18 @@ impl<'a> CutsceneBuilder<'a> {
         let step = self.step;
         self.app.add_systems(
             Update,
-            init.run_if(move |cutscene_state: Res<Cutscenes>| {
-                let mut lock = cutscene_state.data.write().unwrap();
-                if let Some(running_cutscene) = &mut lock.running_cutscene {
-                    if running_cutscene.type_id == type_id
-                        && running_cutscene.init
-                        && running_cutscene.started
-                        && running_cutscene.step == step
-                    {
-                        running_cutscene.init = false;
-                        return true;
-                    }
-                }
-                false
-            }),
+            init.run_if(move |cutscenes: Res<Cutscenes>| {
+                cutscenes.in_step(type_id, step, CutscenePhase::Init)
+            })
+            .in_set(CutsceneSystem::Init),
         );
         self.app.add_systems(
             Update,
-            update.run_if(move |cutscene_state: Res<Cutscenes>| {
-                let mut lock = cutscene_state.data.write().unwrap();
-                if let Some(running_cutscene) = &mut lock.running_cutscene {
-                    if running_cutscene.type_id == type_id
-                        && !running_cutscene.init
-                        && running_cutscene.started
-                        && running_cutscene.step == step
-                    {
-                        if running_cutscene.updates == UPDATE_BUFFER {
-                            return true;
-                        } else {
-                            running_cutscene.updates += 1;
-                        }
-                    }
-                }
-                false
-            }),
+            update
+                .run_if(move |cutscenes: Res<Cutscenes>| {
+                    cutscenes.in_step(type_id, step, CutscenePhase::Update)
+                })
+                .in_set(CutsceneSystem::Update),
         );
         self.step += 1;
         self
@@ -240,10 +305,12 @@ impl<'a> CutsceneBuilder<'a> {
         &mut self,
         init: impl IntoSystemConfigs<ParamsA> + IntoSystemConfigs<ParamsA>,
     ) -> &mut Self {
+        let type_id = self.type_id;
         let step = self.step;
-        self.add_step(init, move |state: Res<Cutscenes>| {
-            let mut lock = state.data.write().unwrap();
-            Self::to_step(&mut lock, step + 1);
+        self.add_step(init, move |mut cutscenes: ResMut<Cutscenes>| {
+            if let Some(running) = cutscenes.get_mut(type_id) {
+                running.set_step(step + 1);
+            }
         });
         self
     }
@@ -252,19 +319,16 @@ impl<'a> CutsceneBuilder<'a> {
         &mut self,
         init: impl IntoSystemConfigs<ParamsA> + IntoSystemConfigs<ParamsA>,
     ) -> &mut Self {
+        let type_id = self.type_id;
         let step = self.step;
         self.add_step(
             init,
-            move |state: Res<Cutscenes>, time: Res<Time>, dialogue: Res<Dialogue>| {
-                let mut lock = state.data.write().unwrap();
-                let advance = if let Some(running_cutscene) = &mut lock.running_cutscene {
-                    running_cutscene.time += time.delta_seconds();
-                    running_cutscene.time > 0.2 && !dialogue.visible()
-                } else {
-                    false
-                };
-                if advance {
-                    Self::to_step(&mut lock, step + 1);
+            move |mut cutscenes: ResMut<Cutscenes>, time: Res<Time>, dialogue: Res<Dialogue>| {
+                if let Some(running) = cutscenes.get_mut(type_id) {
+                    running.time += time.delta_seconds();
+                    if running.time > 0.2 && !dialogue.visible() {
+                        running.set_step(step + 1);
+                    }
                 }
             },
         );
@@ -276,27 +340,59 @@ impl<'a> CutsceneBuilder<'a> {
         init: impl IntoSystemConfigs<ParamsA> + IntoSystemConfigs<ParamsA>,
         seconds: f32,
     ) -> &mut Self {
+        let type_id = self.type_id;
         let step = self.step;
-        self.add_step(init, move |state: Res<Cutscenes>, time: Res<Time>| {
-            let mut lock = state.data.write().unwrap();
-            let advance = if let Some(running_cutscene) = &mut lock.running_cutscene {
-                running_cutscene.time += time.delta_seconds();
-                running_cutscene.time > seconds
-            } else {
-                false
-            };
-            if advance {
-                Self::to_step(&mut lock, step + 1);
-            }
-        });
+        self.add_step(
+            init,
+            move |mut cutscenes: ResMut<Cutscenes>, time: Res<Time>| {
+                if let Some(running) = cutscenes.get_mut(type_id) {
+                    running.time += time.delta_seconds();
+                    if running.time > seconds {
+                        running.set_step(step + 1);
+                    }
+                }
+            },
+        );
         self
     }
 
-    fn to_step(lock: &mut RwLockWriteGuard<CutscenesData>, step: usize) {
-        if let Some(running_cutscene) = &mut lock.running_cutscene {
-            running_cutscene.reset();
-            running_cutscene.step = step;
-        }
+    pub fn add_nested_step<C, ParamsA>(
+        &mut self,
+        init: impl IntoSystem<(), C, ParamsA>,
+    ) -> &mut Self
+    where
+        C: CutsceneType + Resource,
+    {
+        let type_id = self.type_id;
+        let step = self.step;
+        self.app
+            .world
+            .get_resource_or_insert_with(Cutscenes::default)
+            .nested_steps
+            .insert((type_id, step));
+        self.add_step(
+            init.pipe(
+                move |In(child): In<C>,
+                      mut cutscenes: ResMut<Cutscenes>,
+                      mut initial_values: ResMut<CutsceneInitialValues<C>>| {
+                    initial_values.0.push_back(child);
+                    cutscenes.queue(QueuedCutscene {
+                        name: type_name::<C>(),
+                        type_id: TypeId::of::<C>(),
+                        parent: Some(type_id),
+                        exclusive: false,
+                    });
+                },
+            ),
+            move |mut cutscenes: ResMut<Cutscenes>| {
+                if !cutscenes.has_children(type_id) {
+                    if let Some(running) = cutscenes.get_mut(type_id) {
+                        running.set_step(step + 1);
+                    }
+                }
+            },
+        );
+        self
     }
 }
 
@@ -309,6 +405,11 @@ pub struct CutsceneStartEvent<T>(pub T)
 where
     T: CutsceneType;
 
+#[derive(Event, Default, Clone, Copy)]
+pub struct CutsceneStartParallelEvent<T>(pub T)
+where
+    T: CutsceneType;
+
 #[derive(Event, Default, Clone, Copy)]
 pub struct CutsceneContinueEvent<T>
 where
@@ -325,6 +426,23 @@ where
     _phantom: PhantomData<T>,
 }
 
+#[derive(Event, Default, Clone, Copy)]
+pub struct CutsceneCancelEvent<T>
+where
+    T: CutsceneType,
+{
+    _phantom: PhantomData<T>,
+}
+
+#[derive(Event, Clone, Copy)]
+pub struct CutsceneCompleteEvent<T>
+where
+    T: CutsceneType,
+{
+    pub cancelled: bool,
+    _phantom: PhantomData<T>,
+}
+
 #[derive(Default, Resource)]
 struct CutsceneInitialValues<T>(VecDeque<T>)
 where
@@ -332,36 +450,42 @@ where
 
 fn cutscene_start<T>(
     mut ev_cutscene_start: EventReader<CutsceneStartEvent<T>>,
-    mut cutscene_state: ResMut<Cutscenes>,
+    mut ev_cutscene_start_parallel: EventReader<CutsceneStartParallelEvent<T>>,
+    mut cutscenes: ResMut<Cutscenes>,
     mut initial_values: ResMut<CutsceneInitialValues<T>>,
 ) where
     T: CutsceneType,
 {
     for event in ev_cutscene_start.iter() {
         initial_values.0.push_back(event.0.clone());
-        cutscene_state.queue(String::from(type_name::<T>()), TypeId::of::<T>());
-        cutscene_state.try_run_next();
+        cutscenes.queue(QueuedCutscene {
+            name: type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+            parent: None,
+            exclusive: true,
+        });
+    }
+    for event in ev_cutscene_start_parallel.iter() {
+        initial_values.0.push_back(event.0.clone());
+        cutscenes.queue(QueuedCutscene {
+            name: type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+            parent: None,
+            exclusive: false,
+        });
     }
 }
 
 fn cutscene_continue<T>(
     mut ev_cutscene_continue: EventReader<CutsceneContinueEvent<T>>,
-    cutscene_state: Res<Cutscenes>,
+    mut cutscenes: ResMut<Cutscenes>,
 ) where
     T: CutsceneType,
 {
-    let mut lock = cutscene_state.data.write().unwrap();
-    let mut continued = false;
-    for _ in ev_cutscene_continue.iter() {
-        if !continued {
-            continued = true;
-            if let Some(running_cutscene) = &mut lock.running_cutscene {
-                if running_cutscene.type_id == TypeId::of::<T>()
-                    && running_cutscene.updates == UPDATE_BUFFER
-                {
-                    running_cutscene.reset();
-                    running_cutscene.step += 1;
-                }
+    if ev_cutscene_continue.iter().count() > 0 {
+        if let Some(running) = cutscenes.get_mut(TypeId::of::<T>()) {
+            if running.phase == CutscenePhase::Update {
+                running.set_step(running.step + 1);
             }
         }
     }
@@ -369,16 +493,83 @@ fn cutscene_continue<T>(
 
 fn cutscene_skip<T>(
     mut ev_cutscene_skip: EventReader<CutsceneSkipEvent<T>>,
-    cutscene_state: Res<Cutscenes>,
+    mut cutscenes: ResMut<Cutscenes>,
+) where
+    T: CutsceneType,
+{
+    if ev_cutscene_skip.iter().count() > 0 {
+        if let Some(running) = cutscenes.get_mut(TypeId::of::<T>()) {
+            running.skip = true;
+            cutscenes.mark_children(TypeId::of::<T>(), |running| running.skip = true);
+        }
+    }
+}
+
+fn cutscene_cancel<T>(
+    mut ev_cutscene_cancel: EventReader<CutsceneCancelEvent<T>>,
+    mut cutscenes: ResMut<Cutscenes>,
 ) where
     T: CutsceneType,
 {
-    let mut lock = cutscene_state.data.write().unwrap();
-    for _ in ev_cutscene_skip.iter() {
-        if let Some(running_cutscene) = &mut lock.running_cutscene {
-            if running_cutscene.type_id == TypeId::of::<T>() {
-                running_cutscene.skip = true;
+    if ev_cutscene_cancel.iter().count() > 0 {
+        if let Some(running) = cutscenes.get_mut(TypeId::of::<T>()) {
+            running.cancel = true;
+        }
+    }
+}
+
+fn cutscene_begin<T>(
+    mut cutscenes: ResMut<Cutscenes>,
+    mut state: ResMut<T>,
+    mut initial_values: ResMut<CutsceneInitialValues<T>>,
+) where
+    T: CutsceneType + Resource,
+{
+    if let Some(running) = cutscenes.get_mut(TypeId::of::<T>()) {
+        if running.phase == CutscenePhase::Start {
+            *state = initial_values.0.pop_front().unwrap();
+            running.phase = CutscenePhase::Init;
+        }
+    }
+}
+
+fn cutscene_advance(mut cutscenes: ResMut<Cutscenes>) {
+    let cancelled: Vec<TypeId> = cutscenes
+        .running
+        .iter()
+        .filter(|running| running.cancel)
+        .map(|running| running.type_id)
+        .collect();
+    for type_id in cancelled {
+        cutscenes.mark_children(type_id, |running| running.cancel = true);
+    }
+    let waiting: Vec<bool> = cutscenes
+        .running
+        .iter()
+        .map(|running| cutscenes.waiting_for_children(running))
+        .collect();
+    for (running, waiting) in cutscenes.running.iter_mut().zip(waiting) {
+        match running.phase {
+            CutscenePhase::Init => {
+                if running.skip && !waiting {
+                    running.step += 1;
+                } else {
+                    running.phase = CutscenePhase::Update;
+                }
+            }
+            CutscenePhase::Update => {
+                // skipped steps don't run their update, so they finish here once nothing nested
+                // under them is still playing
+                if running.skip && !waiting {
+                    running.step += 1;
+                    running.phase = CutscenePhase::Init;
+                    running.time = 0.;
+                }
+            }
+            CutscenePhase::Pending => {
+                running.phase = CutscenePhase::Init;
             }
+            _ => {}
         }
     }
 }
@@ -386,27 +577,38 @@ fn cutscene_skip<T>(
 fn cutscene_debug(
     mut egui_query: Query<&mut EguiContext>,
     mut menu_bar: ResMut<MenuBar>,
-    cutscene_state: Res<Cutscenes>,
+    mut cutscenes: ResMut<Cutscenes>,
 ) {
     menu_bar.item("Cutscenes", |open| {
         let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
         egui::Window::new("Cutscenes")
             .open(open)
             .show(egui_context.get_mut(), |ui| {
-                let mut lock = cutscene_state.data.write().unwrap();
-                if let Some(cutscene) = &mut lock.running_cutscene {
-                    ui.label(format!("Cutscene running: {}", cutscene.name));
-                    ui.label(format!("Step: {}", cutscene.step));
-                    if ui.button("Next").clicked() {
-                        cutscene.reset();
-                        cutscene.step += 1;
-                    }
-                    if ui.button("Skip All").clicked() {
-                        cutscene.skip = true;
-                    }
-                } else {
+                if cutscenes.running.is_empty() {
                     ui.label("No cutscene running");
                 }
+                for running in cutscenes.running.iter_mut() {
+                    ui.separator();
+                    ui.label(format!("Cutscene running: {}", running.name));
+                    ui.label(format!("Step: {} ({:?})", running.step, running.phase));
+                    ui.horizontal(|ui| {
+                        if ui.button("Next").clicked() {
+                            running.set_step(running.step + 1);
+                        }
+                        if ui.button("Skip All").clicked() {
+                            running.skip = true;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            running.cancel = true;
+                        }
+                    });
+                }
+                if !cutscenes.backlog.is_empty() {
+                    ui.separator();
+                    for queued in cutscenes.backlog.iter() {
+                        ui.label(format!("Queued: {}", queued.name));
+                    }
+                }
             });
     });
 }
@@ -52,6 +52,8 @@ pub const DEPTH_LAYER_UI_BOSS_HEALTHBAR: Layer = (DepthLayer::Front, 0.861);
 pub const DEPTH_LAYER_UI_HEALTH_BOTTLE: Layer = (DepthLayer::Front, 0.905);
 pub const DEPTH_LAYER_UI_CONTROLS: Layer = (DepthLayer::Front, 0.905);
 pub const DEPTH_LAYER_UI_CONTROLS_KEY: Layer = (DepthLayer::Front, 0.906);
+pub const DEPTH_LAYER_UI_CONTROLS_BINDING: Layer = (DepthLayer::Front, 0.907);
+pub const DEPTH_LAYER_UI_CONTROLS_BINDING_TEXT: Layer = (DepthLayer::Front, 0.908);
 pub const DEPTH_LAYER_UI_EXPERIENCE_BAR_BACK: Layer = (DepthLayer::Front, 0.905);
 pub const DEPTH_LAYER_UI_EXPERIENCE_BAR: Layer = (DepthLayer::Front, 0.906);
 pub const DEPTH_LAYER_UI_EXPERIENCE_LEVEL: Layer = (DepthLayer::Front, 0.907);
@@ -1,35 +1,109 @@
-use crate::common::prelude::*;
+use crate::{common::prelude::*, DEV_BUILD};
 use audio_plus::prelude::*;
-use bevy::{prelude::*, sprite::Anchor};
+use bevy::{prelude::*, sprite::Anchor, utils::HashMap};
+use serde::Deserialize;
 use std::collections::VecDeque;
 
+const DIALOGUE_OPTION_COUNT: usize = 4;
+const DIALOGUE_LOGIC_LIMIT: usize = 64;
+
 pub struct DialoguePlugin;
 
 impl Plugin for DialoguePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Dialogue>()
             .add_event::<DialogueInitEvent>()
-            .add_systems(Update, (dialogue_init, dialogue_update));
+            .add_systems(
+                Update,
+                (
+                    dialogue_init,
+                    (dialogue_update, dialogue_choice)
+                        .chain()
+                        .in_set(DialogueSystem::Update),
+                    dialogue_asset_sync,
+                ),
+            );
     }
 }
 
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum DialogueSystem {
+    Update,
+}
+
 #[derive(Event, Default, Clone, Copy)]
 pub struct DialogueInitEvent;
 
+pub trait DialogueContext {
+    fn check(&self, condition: &DialogueCondition) -> bool;
+    fn apply(&mut self, effect: &DialogueEffect);
+}
+
 #[derive(Default, Resource)]
 pub struct Dialogue {
     entries: VecDeque<DialogueEntry>,
+    conversations: HashMap<String, Vec<DialogueNode>>,
+    localization_keys: Vec<String>,
     time: f32,
     last_characters: usize,
+    selected: usize,
 }
 
 #[derive(Clone)]
-struct DialogueEntry {
-    portrait: DialoguePortrait,
-    text: String,
+enum DialogueEntry {
+    Text {
+        portrait: DialoguePortrait,
+        text: String,
+    },
+    Choice {
+        portrait: DialoguePortrait,
+        text: String,
+        options: Vec<DialogueOption>,
+        resolved: bool,
+    },
+    Branch(Vec<DialogueBranch>),
+    Effect(DialogueEffect),
+}
+
+impl DialogueEntry {
+    fn from_node(node: &DialogueNode) -> Self {
+        match node {
+            DialogueNode::Line { speaker, text, .. } => Self::Text {
+                portrait: *speaker,
+                text: text.clone(),
+            },
+            DialogueNode::Choice {
+                speaker,
+                text,
+                options,
+                ..
+            } => Self::Choice {
+                portrait: *speaker,
+                text: text.clone(),
+                options: options.clone(),
+                resolved: options.iter().all(|option| option.condition.is_none()),
+            },
+            DialogueNode::Branch(branches) => Self::Branch(branches.clone()),
+            DialogueNode::Effect(effect) => Self::Effect(effect.clone()),
+        }
+    }
+
+    fn display(&self) -> Option<(DialoguePortrait, &str)> {
+        match self {
+            Self::Text { portrait, text } => Some((*portrait, text)),
+            Self::Choice {
+                portrait,
+                text,
+                resolved: true,
+                ..
+            } => Some((*portrait, text)),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DialoguePortrait {
     None,
     Jagerossa,
@@ -41,22 +115,130 @@ pub enum DialoguePortrait {
 }
 
 impl DialoguePortrait {
-    fn name(&self) -> &'static str {
+    fn name_key(&self) -> &'static str {
         match *self {
-            Self::None => "???",
-            Self::Jagerossa => "Captain Mick Jagerossa",
-            Self::Ringo => "Captain Ringo Yarr",
-            Self::Plank => "Captain Plank Presley",
-            Self::Davy => "Captain Davy Bowie",
-            Self::Mayor => "Governor",
-            Self::Barkeep => "Barkeep",
+            Self::None => "portrait.none",
+            Self::Jagerossa => "portrait.jagerossa",
+            Self::Ringo => "portrait.ringo",
+            Self::Plank => "portrait.plank",
+            Self::Davy => "portrait.davy",
+            Self::Mayor => "portrait.mayor",
+            Self::Barkeep => "portrait.barkeep",
         }
     }
 }
 
 impl Dialogue {
     pub fn add_text(&mut self, portrait: DialoguePortrait, text: String) {
-        self.entries.push_back(DialogueEntry { portrait, text });
+        self.entries
+            .push_back(DialogueEntry::Text { portrait, text });
+    }
+
+    pub fn add_conversation(&mut self, key: &str) {
+        if let Some(conversation) = self.conversations.get(key) {
+            self.entries
+                .extend(conversation.iter().map(DialogueEntry::from_node));
+        } else if DEV_BUILD {
+            self.add_text(
+                DialoguePortrait::None,
+                format!("Missing dialogue conversation: {}", key),
+            );
+        }
+    }
+
+    fn insert_conversation(&mut self, key: &str) {
+        if let Some(conversation) = self.conversations.get(key) {
+            for node in conversation.iter().rev() {
+                self.entries.push_front(DialogueEntry::from_node(node));
+            }
+        } else if DEV_BUILD {
+            self.entries.push_front(DialogueEntry::Text {
+                portrait: DialoguePortrait::None,
+                text: format!("Missing dialogue conversation: {}", key),
+            });
+        }
+    }
+
+    /// Locale keys of every line and option, whose reference text is in the dialogue asset.
+    pub fn localization_keys(&self) -> &[String] {
+        &self.localization_keys
+    }
+
+    pub fn conversation_keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self.conversations.keys().map(String::as_str).collect();
+        keys.sort();
+        keys
+    }
+
+    pub fn resolve_logic(&mut self, context: &mut impl DialogueContext) {
+        for _ in 0..DIALOGUE_LOGIC_LIMIT {
+            match self.entries.front_mut() {
+                Some(DialogueEntry::Branch(branches)) => {
+                    let goto = branches
+                        .iter()
+                        .find(|branch| {
+                            branch
+                                .condition
+                                .as_ref()
+                                .map_or(true, |condition| context.check(condition))
+                        })
+                        .map(|branch| branch.goto.clone());
+                    self.entries.pop_front();
+                    if let Some(goto) = goto {
+                        self.insert_conversation(&goto);
+                    }
+                }
+                Some(DialogueEntry::Effect(effect)) => {
+                    context.apply(effect);
+                    self.entries.pop_front();
+                }
+                Some(DialogueEntry::Choice {
+                    options, resolved, ..
+                }) if !*resolved => {
+                    options.retain(|option| {
+                        option
+                            .condition
+                            .as_ref()
+                            .map_or(true, |condition| context.check(condition))
+                    });
+                    *resolved = true;
+                }
+                _ => return,
+            }
+        }
+    }
+
+    pub fn choosing(&self) -> bool {
+        !self.options().is_empty()
+    }
+
+    fn options(&self) -> &[DialogueOption] {
+        match self.entries.front() {
+            Some(DialogueEntry::Choice {
+                options,
+                resolved: true,
+                ..
+            }) => options,
+            _ => &[],
+        }
+    }
+
+    pub fn choose(&mut self, index: usize) {
+        if index >= self.options().len() {
+            return;
+        }
+        let Some(DialogueEntry::Choice { mut options, .. }) = self.entries.pop_front() else {
+            return;
+        };
+        let option = options.swap_remove(index);
+        if let Some(goto) = &option.goto {
+            self.insert_conversation(goto);
+        }
+        for effect in option.effects.into_iter().rev() {
+            self.entries.push_front(DialogueEntry::Effect(effect));
+        }
+        self.time = 0.;
+        self.selected = 0;
     }
 
     pub fn clear(&mut self) {
@@ -67,17 +249,20 @@ impl Dialogue {
         self.entries.len() > 0
     }
 
+    fn text_len(&self) -> usize {
+        self.entries
+            .front()
+            .and_then(|entry| entry.display())
+            .map_or(0, |(_, text)| text.chars().count())
+    }
+
     pub fn characters(&self) -> usize {
-        if self.entries.len() > 0 {
-            ((self.time * 50.) as usize).clamp(0, self.entries[0].text.len())
-        } else {
-            0
-        }
+        ((self.time * 50.) as usize).clamp(0, self.text_len())
     }
 
     pub fn all_characters_visible(&self) -> bool {
         if self.entries.len() > 0 {
-            self.characters() == self.entries[0].text.len()
+            self.characters() == self.text_len()
         } else {
             false
         }
@@ -96,6 +281,11 @@ pub struct DialogueText;
 #[derive(Component)]
 pub struct DialogueName;
 
+#[derive(Component)]
+pub struct DialogueOptionText {
+    index: usize,
+}
+
 #[derive(Component)]
 pub struct DialoguePortraitComp {
     portrait: DialoguePortrait,
@@ -182,6 +372,38 @@ fn dialogue_init(
                     DialogueName,
                     AudioPlusSource::new(asset_library.sound_effects.sfx_dialogue_repeat.clone()),
                 ));
+                for index in 0..DIALOGUE_OPTION_COUNT {
+                    parent.spawn((
+                        Text2dBundle {
+                            text: Text::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_library.font_default.clone(),
+                                    font_size: 36.0,
+                                    color: Color::WHITE,
+                                },
+                            )
+                            .with_alignment(TextAlignment::Left),
+                            text_anchor: Anchor::TopLeft,
+                            visibility: Visibility::Hidden,
+                            ..Default::default()
+                        },
+                        Transform2::from_xy(
+                            -540.,
+                            150. + (DIALOGUE_OPTION_COUNT - 1 - index) as f32 * 44.,
+                        )
+                        .with_depth(DEPTH_LAYER_DIALOGUE_TEXT),
+                        Clickable {
+                            shape: CollisionShape::Rect {
+                                size: Vec2::new(600., 36.),
+                            },
+                            use_global: true,
+                            offset: Vec2::new(300., -18.),
+                            ..Default::default()
+                        },
+                        DialogueOptionText { index },
+                    ));
+                }
                 parent.spawn((
                     SpriteBundle {
                         texture: asset_library.sprite_dialogue_portrait_jagerossa.clone(),
@@ -252,6 +474,93 @@ fn dialogue_init(
     }
 }
 
+fn dialogue_asset_sync(
+    mut ev_asset: EventReader<AssetEvent<DialogueAsset>>,
+    mut dialogue: ResMut<Dialogue>,
+    dialogue_assets: Res<Assets<DialogueAsset>>,
+    asset_library: Res<AssetLibrary>,
+    localization: Res<Localization>,
+) {
+    let mut reload = localization.is_changed();
+    for event in ev_asset.iter() {
+        let (AssetEvent::Created { handle } | AssetEvent::Modified { handle }) = event else {
+            continue;
+        };
+        if *handle == asset_library.dialogue {
+            reload = true;
+        }
+    }
+    if !reload {
+        return;
+    }
+    if let Some(asset) = dialogue_assets.get(&asset_library.dialogue) {
+        dialogue.conversations = asset
+            .conversations
+            .iter()
+            .map(|(key, nodes)| {
+                let nodes = nodes
+                    .iter()
+                    .map(|node| localize_node(localization.as_ref(), node))
+                    .collect();
+                (key.clone(), nodes)
+            })
+            .collect();
+        let mut keys: Vec<String> = asset
+            .conversations
+            .values()
+            .flatten()
+            .flat_map(node_ids)
+            .map(|id| format!("dialogue.{}", id))
+            .collect();
+        keys.sort();
+        for pair in keys.windows(2) {
+            if pair[0] == pair[1] {
+                warn!("Duplicate dialogue id: {}", pair[0]);
+            }
+        }
+        keys.dedup();
+        dialogue.localization_keys = keys;
+    }
+}
+
+fn node_ids(node: &DialogueNode) -> Vec<&str> {
+    match node {
+        DialogueNode::Line { id, .. } => vec![id.as_str()],
+        DialogueNode::Choice { id, options, .. } => std::iter::once(id.as_str())
+            .chain(options.iter().map(|option| option.id.as_str()))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn localize_node(localization: &Localization, node: &DialogueNode) -> DialogueNode {
+    match node {
+        DialogueNode::Line { id, speaker, text } => DialogueNode::Line {
+            id: id.clone(),
+            speaker: *speaker,
+            text: localization.text_or(&format!("dialogue.{}", id), text),
+        },
+        DialogueNode::Choice {
+            id,
+            speaker,
+            text,
+            options,
+        } => DialogueNode::Choice {
+            id: id.clone(),
+            speaker: *speaker,
+            text: localization.text_or(&format!("dialogue.{}", id), text),
+            options: options
+                .iter()
+                .map(|option| DialogueOption {
+                    text: localization.text_or(&format!("dialogue.{}", option.id), &option.text),
+                    ..option.clone()
+                })
+                .collect(),
+        },
+        _ => node.clone(),
+    }
+}
+
 fn dialogue_update(
     mut dialogue: ResMut<Dialogue>,
     mut queries: ParamSet<(
@@ -265,24 +574,25 @@ fn dialogue_update(
         Query<&mut Sprite, With<DialogueFade>>,
     )>,
     screen_fade: Res<ScreenFade>,
-    mut input: ResMut<Input<KeyCode>>,
+    mut actions: ResMut<Actions>,
     mut mouse: ResMut<Input<MouseButton>>,
     time: Res<Time>,
+    localization: Res<Localization>,
 ) {
     let allow = screen_fade.faded_in();
-    let space_pressed = input.just_pressed(KeyCode::Space);
-    let mouse_pressed = mouse.just_pressed(MouseButton::Left);
-    if (space_pressed || mouse_pressed) && allow {
-        if !dialogue.entries.is_empty() {
-            if dialogue.all_characters_visible() {
-                dialogue.entries.pop_front();
-                dialogue.time = 0.;
-                if space_pressed {
-                    input.reset(KeyCode::Space);
-                }
-                if mouse_pressed {
+    if actions.just_pressed(Action::Advance) && allow {
+        if dialogue.text_len() > 0 {
+            if dialogue.choosing() {
+                if !dialogue.all_characters_visible() {
+                    dialogue.time = 999999.;
+                    actions.reset(Action::Advance);
                     mouse.reset(MouseButton::Left);
                 }
+            } else if dialogue.all_characters_visible() {
+                dialogue.entries.pop_front();
+                dialogue.time = 0.;
+                actions.reset(Action::Advance);
+                mouse.reset(MouseButton::Left);
                 for mut sound in queries.p4().iter_mut() {
                     sound.play();
                 }
@@ -292,7 +602,12 @@ fn dialogue_update(
         }
     }
     let mut hide = false;
-    if let Some(entry) = dialogue.entries.get(0).cloned() {
+    let entry = dialogue
+        .entries
+        .front()
+        .and_then(|entry| entry.display())
+        .map(|(portrait, text)| (portrait, text.to_owned()));
+    if let Some((entry_portrait, entry_text)) = entry {
         if allow {
             let characters = dialogue.characters();
             let characters_sfx = characters / 8;
@@ -311,13 +626,14 @@ fn dialogue_update(
                 fade_sprite.color.set_a(0.1_f32.lerp(a, 1.));
             }
             for mut dialogue_text in queries.p1().iter_mut() {
-                dialogue_text.sections[0].value = String::from(&entry.text[0..characters]);
+                dialogue_text.sections[0].value =
+                    entry_text.chars().take(characters).collect::<String>();
             }
             for mut dialogue_name in queries.p2().iter_mut() {
-                dialogue_name.sections[0].value = String::from(entry.portrait.name());
+                dialogue_name.sections[0].value = localization.text(entry_portrait.name_key());
             }
             for (mut portrait_visibility, portrait) in queries.p3().iter_mut() {
-                *portrait_visibility = if entry.portrait == portrait.portrait {
+                *portrait_visibility = if entry_portrait == portrait.portrait {
                     Visibility::Inherited
                 } else {
                     Visibility::Hidden
@@ -352,3 +668,93 @@ fn dialogue_update(
         }
     }
 }
+
+fn dialogue_choice(
+    mut dialogue: ResMut<Dialogue>,
+    mut query: Query<(
+        &mut Text,
+        &mut Visibility,
+        &mut Clickable,
+        &DialogueOptionText,
+    )>,
+    mut sound_query: Query<&mut AudioPlusSource, With<DialogueBack>>,
+    screen_fade: Res<ScreenFade>,
+    mut mouse: ResMut<Input<MouseButton>>,
+    mut actions: ResMut<Actions>,
+) {
+    let active = dialogue.choosing() && dialogue.all_characters_visible() && screen_fade.faded_in();
+    let option_count = dialogue.options().len();
+    let mut chosen = None;
+    if active && option_count > 0 {
+        if actions.just_pressed(Action::ChoiceUp) {
+            dialogue.selected = (dialogue.selected + option_count - 1) % option_count;
+            actions.reset(Action::ChoiceUp);
+        }
+        if actions.just_pressed(Action::ChoiceDown) {
+            dialogue.selected = (dialogue.selected + 1) % option_count;
+            actions.reset(Action::ChoiceDown);
+        }
+        for (index, action) in Action::choices().iter().enumerate() {
+            if index < option_count && actions.just_pressed(*action) {
+                actions.reset(*action);
+                chosen = Some(index);
+            }
+        }
+        // mouse clicks pick the option they land on instead
+        let advance_without_mouse = actions
+            .held_bindings(Action::Advance)
+            .iter()
+            .any(|binding| !matches!(binding, Binding::Mouse(..)));
+        if actions.just_pressed(Action::Advance) && advance_without_mouse {
+            actions.reset(Action::Advance);
+            chosen = Some(dialogue.selected.min(option_count - 1));
+        }
+        for (_, _, clickable, option) in query.iter() {
+            if option.index >= option_count {
+                continue;
+            }
+            if clickable.just_hovered() {
+                dialogue.selected = option.index;
+            }
+            if clickable.confirmed {
+                mouse.reset(MouseButton::Left);
+                chosen = Some(option.index);
+            }
+        }
+        if chosen.is_some() {
+            actions.reset(Action::Advance);
+        }
+    }
+    for (mut text, mut visibility, mut clickable, option) in query.iter_mut() {
+        let label = if active {
+            dialogue
+                .options()
+                .get(option.index)
+                .map(|dialogue_option| dialogue_option.text.as_str())
+        } else {
+            None
+        };
+        if let Some(label) = label {
+            *visibility = Visibility::Inherited;
+            clickable.disabled = false;
+            text.sections[0].value = format!("{}. {}", option.index + 1, label);
+            text.sections[0].style.color = if option.index == dialogue.selected {
+                Color::rgb_u8(255, 220, 100)
+            } else {
+                Color::WHITE
+            };
+        } else {
+            *visibility = Visibility::Hidden;
+            clickable.disabled = true;
+            if text.sections[0].value != "" {
+                text.sections[0].value = "".to_owned();
+            }
+        }
+    }
+    if let Some(index) = chosen {
+        dialogue.choose(index);
+        for mut sound in sound_query.iter_mut() {
+            sound.play();
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use bevy::{prelude::*, transform::TransformSystem};
 use global_state::Persistent;
 
-use super::transform2::Transform2System;
+use super::{
+    settings::{CameraRatio, Settings},
+    transform2::Transform2System,
+};
 
 const DESIRED_SIZE: Vec2 = Vec2::new(1280., 768.);
 const RATIO_BAR_SIZE: f32 = 100000.;
@@ -76,6 +79,7 @@ fn force_ratio_update(
     camera_query: Query<Entity, With<Camera>>,
     bar_query: Query<(Entity, &ForceRatioBar)>,
     window_query: Query<&Window>,
+    settings: Res<Settings>,
 ) {
     let mut camera_position = Vec3::ZERO;
     if let Some(window) = window_query.get_single().ok() {
@@ -85,10 +89,12 @@ fn force_ratio_update(
                 let mut desired_width = DESIRED_SIZE.x;
                 let mut desired_height = DESIRED_SIZE.y;
                 let desired_ratio = desired_width / desired_height;
-                if ratio > desired_ratio {
-                    desired_width *= ratio / desired_ratio;
-                } else {
-                    desired_height *= desired_ratio / ratio;
+                if settings.camera_ratio == CameraRatio::Letterbox {
+                    if ratio > desired_ratio {
+                        desired_width *= ratio / desired_ratio;
+                    } else {
+                        desired_height *= desired_ratio / ratio;
+                    }
                 }
                 camera_transform.scale.x = desired_width / window.width();
                 camera_transform.scale.y = desired_height / window.height();
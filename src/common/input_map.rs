@@ -0,0 +1,548 @@
+use crate::common::storage::{storage_read, storage_remove, storage_write};
+use bevy::{
+    input::InputSystem,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const GAMEPAD_DEADZONE: f32 = 0.2;
+
+pub struct InputMapPlugin;
+
+impl Plugin for InputMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputMap>()
+            .init_resource::<Actions>()
+            .add_systems(PreUpdate, actions_update.after(InputSystem));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Action {
+    Move,
+    Dash,
+    Jam,
+    OpenMap,
+    Advance,
+    ChoiceUp,
+    ChoiceDown,
+    Choice1,
+    Choice2,
+    Choice3,
+    Choice4,
+}
+
+/// Actions in the same context are used at the same time, so they can't share a binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionContext {
+    Sailing,
+    Dialogue,
+}
+
+impl Action {
+    pub fn all() -> &'static [Action] {
+        &[
+            Self::Move,
+            Self::Dash,
+            Self::Jam,
+            Self::OpenMap,
+            Self::Advance,
+            Self::ChoiceUp,
+            Self::ChoiceDown,
+            Self::Choice1,
+            Self::Choice2,
+            Self::Choice3,
+            Self::Choice4,
+        ]
+    }
+
+    /// Quick pick actions for dialogue options, in order.
+    pub fn choices() -> &'static [Action] {
+        &[Self::Choice1, Self::Choice2, Self::Choice3, Self::Choice4]
+    }
+
+    pub fn context(&self) -> ActionContext {
+        match *self {
+            Self::Move | Self::Dash | Self::Jam | Self::OpenMap => ActionContext::Sailing,
+            Self::Advance
+            | Self::ChoiceUp
+            | Self::ChoiceDown
+            | Self::Choice1
+            | Self::Choice2
+            | Self::Choice3
+            | Self::Choice4 => ActionContext::Dialogue,
+        }
+    }
+
+    pub fn name_key(&self) -> &'static str {
+        match *self {
+            Self::Move => "controls.move",
+            Self::Dash => "controls.dash",
+            Self::Jam => "controls.jam",
+            Self::OpenMap => "controls.open_map",
+            Self::Advance => "controls.advance",
+            Self::ChoiceUp => "controls.choice_up",
+            Self::ChoiceDown => "controls.choice_down",
+            Self::Choice1 => "controls.choice_1",
+            Self::Choice2 => "controls.choice_2",
+            Self::Choice3 => "controls.choice_3",
+            Self::Choice4 => "controls.choice_4",
+        }
+    }
+
+    fn default_bindings(&self) -> Vec<Binding> {
+        match *self {
+            Self::Move => vec![Binding::Mouse(MouseButton::Left)],
+            Self::Dash => vec![
+                Binding::Key(KeyCode::Space),
+                Binding::Gamepad(GamepadButtonType::South),
+            ],
+            Self::Jam => vec![
+                Binding::Key(KeyCode::F),
+                Binding::Gamepad(GamepadButtonType::West),
+            ],
+            Self::OpenMap => vec![
+                Binding::Key(KeyCode::M),
+                Binding::Gamepad(GamepadButtonType::Select),
+            ],
+            Self::Advance => vec![
+                Binding::Key(KeyCode::Space),
+                Binding::Mouse(MouseButton::Left),
+                Binding::Key(KeyCode::Return),
+                Binding::Gamepad(GamepadButtonType::South),
+            ],
+            Self::ChoiceUp => vec![
+                Binding::Key(KeyCode::W),
+                Binding::Key(KeyCode::Up),
+                Binding::Gamepad(GamepadButtonType::DPadUp),
+            ],
+            Self::ChoiceDown => vec![
+                Binding::Key(KeyCode::S),
+                Binding::Key(KeyCode::Down),
+                Binding::Gamepad(GamepadButtonType::DPadDown),
+            ],
+            Self::Choice1 => vec![Binding::Key(KeyCode::Key1)],
+            Self::Choice2 => vec![Binding::Key(KeyCode::Key2)],
+            Self::Choice3 => vec![Binding::Key(KeyCode::Key3)],
+            Self::Choice4 => vec![Binding::Key(KeyCode::Key4)],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButtonType),
+}
+
+const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::Key0,
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Space,
+    KeyCode::Return,
+    KeyCode::Tab,
+    KeyCode::Back,
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Comma,
+    KeyCode::Period,
+    KeyCode::Slash,
+    KeyCode::Semicolon,
+];
+
+const BINDABLE_MOUSE_BUTTONS: &[MouseButton] =
+    &[MouseButton::Left, MouseButton::Right, MouseButton::Middle];
+
+const BINDABLE_GAMEPAD_BUTTONS: &[GamepadButtonType] = &[
+    GamepadButtonType::South,
+    GamepadButtonType::East,
+    GamepadButtonType::North,
+    GamepadButtonType::West,
+    GamepadButtonType::LeftTrigger,
+    GamepadButtonType::RightTrigger,
+    GamepadButtonType::LeftTrigger2,
+    GamepadButtonType::RightTrigger2,
+    GamepadButtonType::Select,
+    GamepadButtonType::Start,
+    GamepadButtonType::DPadUp,
+    GamepadButtonType::DPadDown,
+    GamepadButtonType::DPadLeft,
+    GamepadButtonType::DPadRight,
+];
+
+impl Binding {
+    pub fn is_gamepad(&self) -> bool {
+        matches!(self, Self::Gamepad(..))
+    }
+
+    pub fn name(&self) -> String {
+        match *self {
+            Self::Key(key) => match key {
+                KeyCode::Key0 => "0".to_owned(),
+                KeyCode::Key1 => "1".to_owned(),
+                KeyCode::Key2 => "2".to_owned(),
+                KeyCode::Key3 => "3".to_owned(),
+                KeyCode::Key4 => "4".to_owned(),
+                KeyCode::Key5 => "5".to_owned(),
+                KeyCode::Key6 => "6".to_owned(),
+                KeyCode::Key7 => "7".to_owned(),
+                KeyCode::Key8 => "8".to_owned(),
+                KeyCode::Key9 => "9".to_owned(),
+                KeyCode::Return => "Enter".to_owned(),
+                KeyCode::Back => "Backspace".to_owned(),
+                KeyCode::ShiftLeft | KeyCode::ShiftRight => "Shift".to_owned(),
+                KeyCode::ControlLeft | KeyCode::ControlRight => "Ctrl".to_owned(),
+                KeyCode::AltLeft | KeyCode::AltRight => "Alt".to_owned(),
+                KeyCode::Comma => ",".to_owned(),
+                KeyCode::Period => ".".to_owned(),
+                KeyCode::Slash => "/".to_owned(),
+                KeyCode::Semicolon => ";".to_owned(),
+                _ => format!("{:?}", key),
+            },
+            Self::Mouse(button) => match button {
+                MouseButton::Left => "Left Click".to_owned(),
+                MouseButton::Right => "Right Click".to_owned(),
+                MouseButton::Middle => "Middle Click".to_owned(),
+                MouseButton::Other(index) => format!("Mouse {}", index),
+            },
+            Self::Gamepad(button) => match button {
+                GamepadButtonType::South => "Pad A".to_owned(),
+                GamepadButtonType::East => "Pad B".to_owned(),
+                GamepadButtonType::North => "Pad Y".to_owned(),
+                GamepadButtonType::West => "Pad X".to_owned(),
+                GamepadButtonType::LeftTrigger => "Pad LB".to_owned(),
+                GamepadButtonType::RightTrigger => "Pad RB".to_owned(),
+                GamepadButtonType::LeftTrigger2 => "Pad LT".to_owned(),
+                GamepadButtonType::RightTrigger2 => "Pad RT".to_owned(),
+                GamepadButtonType::Select => "Pad Select".to_owned(),
+                GamepadButtonType::Start => "Pad Start".to_owned(),
+                _ => format!("Pad {:?}", button),
+            },
+        }
+    }
+
+    fn id(&self) -> String {
+        match *self {
+            Self::Key(key) => format!("key:{:?}", key),
+            Self::Mouse(button) => format!("mouse:{:?}", button),
+            Self::Gamepad(button) => format!("gamepad:{:?}", button),
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        let (device, name) = id.split_once(':')?;
+        match device {
+            "key" => BINDABLE_KEYS
+                .iter()
+                .find(|key| format!("{:?}", key) == name)
+                .map(|key| Self::Key(*key)),
+            "mouse" => BINDABLE_MOUSE_BUTTONS
+                .iter()
+                .find(|button| format!("{:?}", button) == name)
+                .map(|button| Self::Mouse(*button)),
+            "gamepad" => BINDABLE_GAMEPAD_BUTTONS
+                .iter()
+                .find(|button| format!("{:?}", button) == name)
+                .map(|button| Self::Gamepad(*button)),
+            _ => None,
+        }
+    }
+
+    /// Finds a bindable key or button pressed this frame, used when rebinding.
+    pub fn just_pressed(
+        keys: &Input<KeyCode>,
+        mouse: &Input<MouseButton>,
+        gamepad_buttons: &Input<GamepadButton>,
+    ) -> Option<Self> {
+        if let Some(key) = BINDABLE_KEYS.iter().find(|key| keys.just_pressed(**key)) {
+            return Some(Self::Key(*key));
+        }
+        if let Some(button) = BINDABLE_MOUSE_BUTTONS
+            .iter()
+            .find(|button| mouse.just_pressed(**button))
+        {
+            return Some(Self::Mouse(*button));
+        }
+        gamepad_buttons
+            .get_just_pressed()
+            .find(|button| BINDABLE_GAMEPAD_BUTTONS.contains(&button.button_type))
+            .map(|button| Self::Gamepad(button.button_type))
+    }
+}
+
+#[derive(Resource)]
+pub struct InputMap {
+    bindings: HashMap<Action, Vec<Binding>>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        let mut input_map = Self::defaults();
+        let saved = storage_read("input_map")
+            .and_then(|data| ron::from_str::<BTreeMap<Action, Vec<String>>>(&data).ok());
+        if let Some(saved) = saved {
+            for (action, ids) in saved {
+                let bindings: Vec<Binding> =
+                    ids.iter().filter_map(|id| Binding::from_id(id)).collect();
+                if !bindings.is_empty() {
+                    input_map.bindings.insert(action, bindings);
+                }
+            }
+        }
+        input_map
+    }
+}
+
+impl InputMap {
+    fn defaults() -> Self {
+        Self {
+            bindings: Action::all()
+                .iter()
+                .map(|action| (*action, action.default_bindings()))
+                .collect(),
+        }
+    }
+
+    pub fn bindings(&self, action: Action) -> &[Binding] {
+        self.bindings.get(&action).map_or(&[][..], Vec::as_slice)
+    }
+
+    pub fn binding(&self, action: Action, gamepad: bool) -> Option<Binding> {
+        self.bindings(action)
+            .iter()
+            .copied()
+            .find(|binding| binding.is_gamepad() == gamepad)
+    }
+
+    pub fn label(&self, action: Action, gamepad: bool) -> String {
+        self.binding(action, gamepad)
+            .map_or_else(|| "-".to_owned(), |binding| binding.name())
+    }
+
+    pub fn is_default(&self, action: Action, gamepad: bool) -> bool {
+        let default = action
+            .default_bindings()
+            .into_iter()
+            .find(|binding| binding.is_gamepad() == gamepad);
+        self.binding(action, gamepad) == default
+    }
+
+    /// Another action in the same context that already uses `binding`.
+    pub fn conflict(&self, action: Action, binding: Binding) -> Option<Action> {
+        Action::all().iter().copied().find(|other| {
+            *other != action
+                && other.context() == action.context()
+                && self.bindings(*other).contains(&binding)
+        })
+    }
+
+    /// Replaces the binding for the same kind of device, keeping any others. Fails with the
+    /// conflicting action if another action in the same context already uses `binding`.
+    pub fn rebind(&mut self, action: Action, binding: Binding) -> Result<(), Action> {
+        if let Some(conflict) = self.conflict(action, binding) {
+            return Err(conflict);
+        }
+        let bindings = self.bindings.entry(action).or_default();
+        if let Some(existing) = bindings
+            .iter_mut()
+            .find(|existing| existing.is_gamepad() == binding.is_gamepad())
+        {
+            *existing = binding;
+        } else {
+            bindings.push(binding);
+        }
+        self.save();
+        Ok(())
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::defaults();
+        storage_remove("input_map");
+    }
+
+    fn save(&self) {
+        let saved: BTreeMap<Action, Vec<String>> = self
+            .bindings
+            .iter()
+            .map(|(action, bindings)| (*action, bindings.iter().map(Binding::id).collect()))
+            .collect();
+        if let Ok(data) = ron::to_string(&saved) {
+            storage_write("input_map", &data);
+        }
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct Actions {
+    pressed: HashSet<Action>,
+    just_pressed: HashSet<Action>,
+    just_released: HashSet<Action>,
+    consumed: HashSet<Action>,
+    held_bindings: HashMap<Action, Vec<Binding>>,
+    movement: Option<Vec2>,
+    gamepad: bool,
+}
+
+impl Actions {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.pressed.contains(&action)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed.contains(&action)
+    }
+
+    pub fn just_released(&self, action: Action) -> bool {
+        self.just_released.contains(&action)
+    }
+
+    /// Bindings currently holding the action down.
+    pub fn held_bindings(&self, action: Action) -> &[Binding] {
+        self.held_bindings
+            .get(&action)
+            .map_or(&[][..], Vec::as_slice)
+    }
+
+    /// Ignores the action, and every other action held by the same key or button, until all of
+    /// their bindings are released.
+    pub fn reset(&mut self, action: Action) {
+        let held = self.held_bindings(action).to_vec();
+        let shared: Vec<Action> = self
+            .held_bindings
+            .iter()
+            .filter(|(other, bindings)| {
+                **other != action && bindings.iter().any(|binding| held.contains(binding))
+            })
+            .map(|(other, _)| *other)
+            .collect();
+        self.consume(action);
+        for other in shared {
+            self.consume(other);
+        }
+    }
+
+    fn consume(&mut self, action: Action) {
+        self.consumed.insert(action);
+        self.pressed.remove(&action);
+        self.just_pressed.remove(&action);
+        self.just_released.remove(&action);
+    }
+
+    /// Analog movement from a gamepad stick, if one is being pushed.
+    pub fn movement(&self) -> Option<Vec2> {
+        self.movement
+    }
+
+    /// Whether the most recent input came from a gamepad.
+    pub fn gamepad(&self) -> bool {
+        self.gamepad
+    }
+}
+
+fn actions_update(
+    mut actions: ResMut<Actions>,
+    input_map: Res<InputMap>,
+    keys: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+) {
+    let binding_pressed = |binding: &Binding| match *binding {
+        Binding::Key(key) => keys.pressed(key),
+        Binding::Mouse(button) => mouse.pressed(button),
+        Binding::Gamepad(button_type) => gamepads
+            .iter()
+            .any(|gamepad| gamepad_buttons.pressed(GamepadButton::new(gamepad, button_type))),
+    };
+    for action in Action::all() {
+        let held: Vec<Binding> = input_map
+            .bindings(*action)
+            .iter()
+            .copied()
+            .filter(binding_pressed)
+            .collect();
+        let mut pressed = !held.is_empty();
+        actions.held_bindings.insert(*action, held);
+        if !pressed {
+            actions.consumed.remove(action);
+        } else if actions.consumed.contains(action) {
+            pressed = false;
+        }
+        let was_pressed = actions.pressed.contains(action);
+        actions.just_pressed.remove(action);
+        actions.just_released.remove(action);
+        if pressed && !was_pressed {
+            actions.pressed.insert(*action);
+            actions.just_pressed.insert(*action);
+        } else if !pressed && was_pressed {
+            actions.pressed.remove(action);
+            actions.just_released.insert(*action);
+        }
+    }
+    actions.movement = gamepads
+        .iter()
+        .map(|gamepad| {
+            Vec2::new(
+                gamepad_axes
+                    .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))
+                    .unwrap_or(0.),
+                gamepad_axes
+                    .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY))
+                    .unwrap_or(0.),
+            )
+        })
+        .find(|stick| stick.length() > GAMEPAD_DEADZONE)
+        .map(|stick| stick.clamp_length_max(1.));
+    if actions.movement.is_some() || gamepad_buttons.get_just_pressed().next().is_some() {
+        actions.gamepad = true;
+    } else if keys.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some()
+    {
+        actions.gamepad = false;
+    }
+}
@@ -0,0 +1,173 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde_json::Value;
+
+/// Reference to another LDtk entity. Resolve it with [`super::LdtkEntities`] once spawned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LdtkEntityRef {
+    pub entity_iid: String,
+    pub layer_iid: String,
+    pub level_iid: String,
+    pub world_iid: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LdtkFieldValue {
+    Null,
+    Int(i64),
+    Float(f32),
+    Bool(bool),
+    /// String, multiline, color and file path fields.
+    String(String),
+    /// Enum value, without the enum name.
+    Enum(String),
+    /// Grid cell within the layer.
+    Point(IVec2),
+    EntityRef(LdtkEntityRef),
+    Array(Vec<LdtkFieldValue>),
+}
+
+impl LdtkFieldValue {
+    fn parse(field_type: &str, value: Option<&Value>) -> Self {
+        let Some(value) = value.filter(|value| !value.is_null()) else {
+            return Self::Null;
+        };
+        if let Some(item_type) = field_type
+            .strip_prefix("Array<")
+            .and_then(|field_type| field_type.strip_suffix('>'))
+        {
+            return Self::Array(
+                value
+                    .as_array()
+                    .map(|items| {
+                        items
+                            .iter()
+                            .map(|item| Self::parse(item_type, Some(item)))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            );
+        }
+        let parsed = match field_type {
+            "Int" => value.as_i64().map(Self::Int),
+            "Float" => value.as_f64().map(|value| Self::Float(value as f32)),
+            "Bool" => value.as_bool().map(Self::Bool),
+            "String" | "Multilines" | "Color" | "FilePath" => {
+                value.as_str().map(|value| Self::String(value.to_owned()))
+            }
+            "Point" => {
+                let cx = value.get("cx").and_then(Value::as_i64);
+                let cy = value.get("cy").and_then(Value::as_i64);
+                cx.zip(cy)
+                    .map(|(cx, cy)| Self::Point(IVec2::new(cx as i32, cy as i32)))
+            }
+            "EntityRef" => {
+                let iid = |key: &str| {
+                    value
+                        .get(key)
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_owned()
+                };
+                Some(Self::EntityRef(LdtkEntityRef {
+                    entity_iid: iid("entityIid"),
+                    layer_iid: iid("layerIid"),
+                    level_iid: iid("levelIid"),
+                    world_iid: iid("worldIid"),
+                }))
+            }
+            _ if field_type.starts_with("LocalEnum.")
+                || field_type.starts_with("ExternalEnum.") =>
+            {
+                value.as_str().map(|value| Self::Enum(value.to_owned()))
+            }
+            _ => None,
+        };
+        parsed.unwrap_or(Self::Null)
+    }
+}
+
+/// Custom fields set on an LDtk entity or level, keyed by field identifier.
+#[derive(Component, Clone, Debug, Default)]
+pub struct LdtkFields {
+    values: HashMap<String, LdtkFieldValue>,
+}
+
+impl LdtkFields {
+    pub fn from_instances(instances: &[ldtk2::FieldInstance]) -> Self {
+        Self {
+            values: instances
+                .iter()
+                .map(|instance| {
+                    (
+                        instance.identifier.clone(),
+                        LdtkFieldValue::parse(
+                            &instance.field_instance_type,
+                            instance.value.as_ref(),
+                        ),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&LdtkFieldValue> {
+        self.values.get(name)
+    }
+
+    pub fn int(&self, name: &str) -> Option<i64> {
+        match self.get(name) {
+            Some(LdtkFieldValue::Int(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn float(&self, name: &str) -> Option<f32> {
+        match self.get(name) {
+            Some(LdtkFieldValue::Float(value)) => Some(*value),
+            Some(LdtkFieldValue::Int(value)) => Some(*value as f32),
+            _ => None,
+        }
+    }
+
+    pub fn bool(&self, name: &str) -> Option<bool> {
+        match self.get(name) {
+            Some(LdtkFieldValue::Bool(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn string(&self, name: &str) -> Option<&str> {
+        match self.get(name) {
+            Some(LdtkFieldValue::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn enum_value(&self, name: &str) -> Option<&str> {
+        match self.get(name) {
+            Some(LdtkFieldValue::Enum(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn point(&self, name: &str) -> Option<IVec2> {
+        match self.get(name) {
+            Some(LdtkFieldValue::Point(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn entity_ref(&self, name: &str) -> Option<&LdtkEntityRef> {
+        match self.get(name) {
+            Some(LdtkFieldValue::EntityRef(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn array(&self, name: &str) -> Option<&[LdtkFieldValue]> {
+        match self.get(name) {
+            Some(LdtkFieldValue::Array(values)) => Some(values),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    common::{
+        prelude::*,
+        tilemap::{spawn_tile_chunks, TilemapTile, Tileset},
+    },
+    game::data::town_data::{town_safe_name, TOWN_NAMES},
+};
+use asset_struct::AssetStruct;
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use std::collections::{HashMap, HashSet};
+
+use grid_combiner::{GridCombiner, GridPoint};
+
+pub use fields::{LdtkEntityRef, LdtkFieldValue, LdtkFields};
+
+mod fields;
+
+/// Streamed levels are kept until the camera is this much further than the stream distance, so
+/// levels on the edge don't spawn and despawn every frame.
+const LDTK_STREAM_UNLOAD_FACTOR: f32 = 1.25;
+
+pub struct LdtkPlugin;
+
+impl Plugin for LdtkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<LdtkSpawnEvent>()
+            .init_resource::<LdtkEntityRegistry>()
+            .init_resource::<LdtkEntities>()
+            .add_systems(Update, (ldtk_spawn, (ldtk_load, ldtk_stream).chain()));
+    }
+}
+
+#[derive(Event, Default)]
+pub struct LdtkSpawnEvent {
+    pub entity: Option<Entity>,
+    pub asset: Handle<LdtkAsset>,
+    pub position: Vec2,
+    /// World identifier in multi-world projects. Defaults to the first world.
+    pub world: Option<String>,
+    /// Only levels within this distance of the camera are spawned. `None` spawns every level.
+    pub stream_distance: Option<f32>,
+}
+
+#[derive(Component)]
+pub struct Ldtk {
+    asset: Handle<LdtkAsset>,
+    state: LdtkState,
+    world: Option<String>,
+    stream_distance: Option<f32>,
+    tilesets: HashMap<i64, Tileset>,
+    levels: Vec<LdtkLevel>,
+}
+
+enum LdtkState {
+    Unloaded,
+    Loaded,
+}
+
+impl LdtkState {
+    pub fn is_loaded(&self) -> bool {
+        if let LdtkState::Loaded = self {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+struct LdtkLevel {
+    min: Vec2,
+    max: Vec2,
+    entity: Option<Entity>,
+    iids: Vec<String>,
+}
+
+/// Spawned for each LDtk entity instance with a registered identifier, alongside its
+/// [`LdtkFields`].
+#[derive(Component, Clone, Debug)]
+pub struct LdtkEntity {
+    pub identifier: String,
+    pub iid: String,
+    pub size: Vec2,
+    pub tags: Vec<String>,
+}
+
+pub type LdtkEntitySpawner = fn(&mut EntityCommands, &LdtkEntity, &LdtkFields);
+
+#[derive(Resource, Default)]
+pub struct LdtkEntityRegistry {
+    spawners: HashMap<String, LdtkEntitySpawner>,
+}
+
+impl LdtkEntityRegistry {
+    pub fn register(&mut self, identifier: &str, spawner: LdtkEntitySpawner) {
+        self.spawners.insert(String::from(identifier), spawner);
+    }
+
+    pub fn get(&self, identifier: &str) -> Option<LdtkEntitySpawner> {
+        self.spawners.get(identifier).copied()
+    }
+}
+
+pub trait AddAppLdtkEntity {
+    fn add_ldtk_entity(&mut self, identifier: &str, spawner: LdtkEntitySpawner) -> &mut Self;
+}
+
+impl AddAppLdtkEntity for App {
+    fn add_ldtk_entity(&mut self, identifier: &str, spawner: LdtkEntitySpawner) -> &mut Self {
+        self.init_resource::<LdtkEntityRegistry>();
+        self.world
+            .resource_mut::<LdtkEntityRegistry>()
+            .register(identifier, spawner);
+        self
+    }
+}
+
+/// Spawned LDtk entities by iid, for resolving [`LdtkEntityRef`] fields.
+#[derive(Resource, Default)]
+pub struct LdtkEntities {
+    iids: HashMap<String, Entity>,
+    /// Entities despawned by the game rather than by streaming, which stay gone when their level
+    /// is streamed back in.
+    removed: HashSet<String>,
+}
+
+impl LdtkEntities {
+    pub fn get(&self, iid: &str) -> Option<Entity> {
+        self.iids.get(iid).copied()
+    }
+
+    pub fn is_removed(&self, iid: &str) -> bool {
+        self.removed.contains(iid)
+    }
+
+    pub fn resolve(&self, entity_ref: &LdtkEntityRef) -> Option<Entity> {
+        self.get(&entity_ref.entity_iid)
+    }
+}
+
+fn ldtk_spawn(mut ev_spawn: EventReader<LdtkSpawnEvent>, mut commands: Commands) {
+    for event in ev_spawn.iter() {
+        let mut ldtk_entity = if let Some(entity) = event.entity {
+            commands.entity(entity)
+        } else {
+            commands.spawn_empty()
+        };
+        ldtk_entity.insert((
+            Transform2Bundle {
+                transform2: Transform2::from_translation(event.position),
+                ..Default::default()
+            },
+            VisibilityBundle::default(),
+            Ldtk {
+                asset: event.asset.clone(),
+                state: LdtkState::Unloaded,
+                world: event.world.clone(),
+                stream_distance: event.stream_distance,
+                tilesets: HashMap::new(),
+                levels: vec![],
+            },
+        ));
+    }
+}
+
+fn ldtk_levels<'a>(map: &'a ldtk2::Ldtk, world: Option<&str>) -> &'a [ldtk2::Level] {
+    if map.worlds.is_empty() {
+        return &map.levels;
+    }
+    let found = if let Some(world) = world {
+        map.worlds.iter().find(|i| i.identifier == world)
+    } else {
+        map.worlds.first()
+    };
+    if let Some(found) = found {
+        &found.levels
+    } else {
+        warn!("LDtk world not found: {:?}", world);
+        &[]
+    }
+}
+
+fn ldtk_load(
+    mut query: Query<&mut Ldtk>,
+    mut commands: Commands,
+    mut color_materials: ResMut<Assets<ColorMaterial>>,
+    ldtk_assets: Res<Assets<LdtkAsset>>,
+    asset_library: Res<AssetLibrary>,
+    mut ev_world_locations_spawn: EventWriter<WorldLocationsSpawnEvent>,
+    mut world_location: ResMut<WorldLocations>,
+    mut map_builder: ResMut<MapBuilder>,
+    mut nav_grid: ResMut<NavGrid>,
+    mut ldtk_entities: ResMut<LdtkEntities>,
+) {
+    for mut ldtk in query.iter_mut() {
+        if ldtk.state.is_loaded() {
+            continue;
+        }
+        let Some(ldtk_asset) = ldtk_assets.get(&ldtk.asset) else { continue };
+        let ldtk_map = &ldtk_asset.map;
+        let mut grid_combiner = GridCombiner::new();
+        world_location.clear();
+        map_builder.reset();
+        nav_grid.reset();
+        ldtk_entities.iids.clear();
+        ldtk_entities.removed.clear();
+        ldtk.tilesets.clear();
+        for tileset in ldtk_map.defs.tilesets.iter() {
+            let Some(rel_path) = tileset.rel_path.as_ref() else { continue };
+            let texture_handle = asset_library.from_filename(&format!("levels/{}", rel_path));
+            ldtk.tilesets.insert(
+                tileset.uid,
+                Tileset::new(
+                    color_materials.add(ColorMaterial::from(texture_handle)),
+                    Vec2::new(tileset.px_wid as f32, tileset.px_hei as f32),
+                    tileset.tile_grid_size,
+                ),
+            );
+        }
+        let world = ldtk.world.clone();
+        ldtk.levels.clear();
+        for level in ldtk_levels(ldtk_map, world.as_deref()) {
+            let level_position = Vec2::new(level.world_x as f32, level.world_y as f32 * -1.0);
+            ldtk.levels.push(LdtkLevel {
+                min: level_position - Vec2::new(0., level.px_hei as f32),
+                max: level_position + Vec2::new(level.px_wid as f32, 0.),
+                entity: None,
+                iids: vec![],
+            });
+            for layer in level.layer_instances.iter().flatten() {
+                match layer.layer_instance_type.as_str() {
+                    "IntGrid" => {
+                        if layer.tileset_def_uid.is_some() {
+                            for tile in layer.auto_layer_tiles.iter() {
+                                let point = GridPoint::new(
+                                    (tile.px[0] + level.world_x) / 100,
+                                    (tile.px[1] + level.world_y) / -100,
+                                );
+                                grid_combiner.add_point(point);
+                                nav_grid.block(IVec2::new(point.x as i32, point.y as i32));
+                                map_builder.add_tile(Vec2::new(
+                                    tile.px[0] as f32 + level.world_x as f32,
+                                    (tile.px[1] as f32 + level.world_y as f32) * -1.0,
+                                ));
+                            }
+                        }
+                    }
+                    "Entities" => {
+                        for entity in layer.entity_instances.iter() {
+                            for name in TOWN_NAMES.iter() {
+                                let town_name = town_safe_name(*name);
+                                if town_name == entity.identifier {
+                                    map_builder.add_label(
+                                        Vec2::new(
+                                            entity.px[0] as f32 + level.world_x as f32,
+                                            (entity.px[1] as f32 + level.world_y as f32) * -1.0,
+                                        ),
+                                        name,
+                                    );
+                                }
+                            }
+                            world_location.add(
+                                &entity.identifier,
+                                Vec2::new(
+                                    entity.px[0] as f32 + level.world_x as f32,
+                                    (entity.px[1] as f32 + level.world_y as f32) * -1.,
+                                ),
+                                Vec2::new(entity.width as f32, entity.height as f32),
+                            );
+                            world_location.set_tags(&entity.identifier, &entity.tags);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        spawn_grid_collisions(&grid_combiner, &mut commands);
+        ev_world_locations_spawn.send_default();
+        ldtk.state = LdtkState::Loaded;
+    }
+}
+
+/// Spawns static collision for cells of the 100 unit island grid.
+pub fn spawn_grid_collisions(grid_combiner: &GridCombiner, commands: &mut Commands) {
+    let rects = grid_combiner.combine();
+    for rect in rects.iter() {
+        let (mut pos, mut size) = rect.to_position_size();
+        pos *= 100.;
+        size *= 100.;
+        commands.spawn((
+            TransformBundle::default(),
+            Transform2::from_translation(pos).with_depth((DepthLayer::Front, 1.)),
+            Collision {
+                shape: CollisionShape::Rect { size },
+                flags: COLLISION_FLAG,
+            },
+        ));
+    }
+}
+
+fn ldtk_stream(
+    mut query: Query<(Entity, &mut Ldtk, &GlobalTransform)>,
+    camera_query: Query<&GlobalTransform, With<Camera>>,
+    ldtk_assets: Res<Assets<LdtkAsset>>,
+    registry: Res<LdtkEntityRegistry>,
+    mut ldtk_entities: ResMut<LdtkEntities>,
+    entity_query: Query<(), With<LdtkEntity>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut commands: Commands,
+) {
+    // streaming out forgets an iid before despawning it, so anything missing was despawned by the
+    // game
+    let LdtkEntities { iids, removed } = ldtk_entities.as_mut();
+    iids.retain(|iid, entity| {
+        let exists = entity_query.contains(*entity);
+        if !exists {
+            removed.insert(iid.clone());
+        }
+        exists
+    });
+    let camera_position = camera_query
+        .get_single()
+        .ok()
+        .map(|transform| transform.translation().truncate());
+    for (map_entity, mut ldtk, transform) in query.iter_mut() {
+        if !ldtk.state.is_loaded() {
+            continue;
+        }
+        let Some(ldtk_asset) = ldtk_assets.get(&ldtk.asset) else { continue };
+        let ldtk = &mut *ldtk;
+        let offset = transform.translation().truncate();
+        let levels = ldtk_levels(&ldtk_asset.map, ldtk.world.as_deref());
+        for (level, ldtk_level) in levels.iter().zip(ldtk.levels.iter_mut()) {
+            let spawned = ldtk_level.entity.is_some();
+            let visible = match (ldtk.stream_distance, camera_position) {
+                (Some(stream_distance), Some(camera_position)) => {
+                    let closest =
+                        camera_position.clamp(ldtk_level.min + offset, ldtk_level.max + offset);
+                    let distance = closest.distance(camera_position);
+                    if spawned {
+                        distance <= stream_distance * LDTK_STREAM_UNLOAD_FACTOR
+                    } else {
+                        distance <= stream_distance
+                    }
+                }
+                (Some(_), None) => spawned,
+                (None, _) => true,
+            };
+            if visible && !spawned {
+                let level_entity = ldtk_spawn_level(
+                    level,
+                    &ldtk.tilesets,
+                    &registry,
+                    &mut ldtk_entities,
+                    &mut ldtk_level.iids,
+                    &mut meshes,
+                    &mut commands,
+                );
+                commands.entity(map_entity).push_children(&[level_entity]);
+                ldtk_level.entity = Some(level_entity);
+            } else if !visible {
+                if let Some(level_entity) = ldtk_level.entity.take() {
+                    commands.entity(level_entity).despawn_recursive();
+                    for iid in ldtk_level.iids.drain(..) {
+                        ldtk_entities.iids.remove(&iid);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn ldtk_spawn_level(
+    level: &ldtk2::Level,
+    tilesets: &HashMap<i64, Tileset>,
+    registry: &LdtkEntityRegistry,
+    ldtk_entities: &mut LdtkEntities,
+    iids: &mut Vec<String>,
+    meshes: &mut Assets<Mesh>,
+    commands: &mut Commands,
+) -> Entity {
+    let level_entity = commands
+        .spawn((
+            Transform2Bundle {
+                transform2: Transform2::from_xy(level.world_x as f32, level.world_y as f32 * -1.0),
+                ..Default::default()
+            },
+            VisibilityBundle::default(),
+            LdtkFields::from_instances(&level.field_instances),
+        ))
+        .id();
+    let layers = level.layer_instances.as_deref().unwrap_or_default();
+    for (idx, layer) in layers.iter().enumerate().rev() {
+        let layer_entity = commands
+            .spawn((
+                Transform2Bundle {
+                    transform2: Transform2::from_xy(0.0, 0.0)
+                        .with_depth((DepthLayer::Environment, 0.5 - idx as f32 / 100.0)),
+                    ..Default::default()
+                },
+                VisibilityBundle::default(),
+            ))
+            .id();
+        commands.entity(level_entity).push_children(&[layer_entity]);
+        let tiles: &[ldtk2::TileInstance] = match layer.layer_instance_type.as_str() {
+            "Tiles" => &layer.grid_tiles,
+            "AutoLayer" | "IntGrid" => &layer.auto_layer_tiles,
+            _ => Default::default(),
+        };
+        // hidden layers still spawn their entities, just not their tiles
+        let tileset = layer
+            .tileset_def_uid
+            .filter(|_| layer.visible)
+            .and_then(|tileset_uid| tilesets.get(&tileset_uid));
+        if let Some(tileset) = tileset {
+            let tiles = tiles.iter().map(|tile| TilemapTile {
+                position: Vec2::new(tile.px[0] as f32, tile.px[1] as f32 * -1.0),
+                index: tile.t,
+                flip_x: tile.f & 1 != 0,
+                flip_y: tile.f & 2 != 0,
+            });
+            let chunk_entities = spawn_tile_chunks(tiles, tileset, meshes, commands);
+            commands.entity(layer_entity).push_children(&chunk_entities);
+        }
+        for entity in layer.entity_instances.iter() {
+            let Some(spawner) = registry.get(&entity.identifier) else { continue };
+            if ldtk_entities.is_removed(&entity.iid) {
+                continue;
+            }
+            let ldtk_entity = LdtkEntity {
+                identifier: entity.identifier.clone(),
+                iid: entity.iid.clone(),
+                size: Vec2::new(entity.width as f32, entity.height as f32),
+                tags: entity.tags.clone(),
+            };
+            let fields = LdtkFields::from_instances(&entity.field_instances);
+            let mut entity_commands = commands.spawn((
+                Transform2Bundle {
+                    transform2: Transform2::from_xy(
+                        entity.px[0] as f32,
+                        entity.px[1] as f32 * -1.0,
+                    ),
+                    ..Default::default()
+                },
+                VisibilityBundle::default(),
+            ));
+            spawner(&mut entity_commands, &ldtk_entity, &fields);
+            let spawned_entity = entity_commands.insert((ldtk_entity, fields)).id();
+            commands
+                .entity(layer_entity)
+                .push_children(&[spawned_entity]);
+            ldtk_entities
+                .iids
+                .insert(entity.iid.clone(), spawned_entity);
+            iids.push(entity.iid.clone());
+        }
+    }
+    level_entity
+}
@@ -0,0 +1,312 @@
+use crate::common::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+use bevy_egui::{egui, EguiContext};
+
+/// Locale every other locale falls back to, and is checked against for missing keys.
+pub const REFERENCE_LOCALE: &str = "en";
+/// Generated from the reference locale to catch hardcoded and clipped text.
+pub const PSEUDO_LOCALE: &str = "pseudo";
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Localization>().add_systems(
+            Update,
+            (
+                localization_asset_sync,
+                localization_settings_sync,
+                localized_text_update
+                    .after(localization_asset_sync)
+                    .after(localization_settings_sync),
+                localization_debug,
+            ),
+        );
+    }
+}
+
+struct LocaleTable {
+    name: String,
+    plural: LocalePlural,
+    strings: HashMap<String, LocaleString>,
+}
+
+#[derive(Resource)]
+pub struct Localization {
+    locale: String,
+    tables: HashMap<String, LocaleTable>,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self {
+            locale: REFERENCE_LOCALE.to_owned(),
+            tables: HashMap::default(),
+        }
+    }
+}
+
+impl Localization {
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    pub fn locale_name(&self) -> &str {
+        self.locale_name_of(&self.locale)
+    }
+
+    pub fn locale_name_of<'a>(&'a self, code: &'a str) -> &'a str {
+        if code == PSEUDO_LOCALE {
+            return "Pseudo";
+        }
+        self.tables
+            .get(code)
+            .map_or(code, |table| table.name.as_str())
+    }
+
+    /// Codes of every loaded locale, reference locale first and pseudo locale last.
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<&str> = self.tables.keys().map(String::as_str).collect();
+        locales.sort_by_key(|code| (*code != REFERENCE_LOCALE, *code));
+        locales.push(PSEUDO_LOCALE);
+        locales
+    }
+
+    pub fn next_locale(&self) -> String {
+        let locales = self.locales();
+        let index = locales
+            .iter()
+            .position(|code| *code == self.locale)
+            .map_or(0, |index| index + 1);
+        locales[index % locales.len()].to_owned()
+    }
+
+    pub fn text(&self, key: &str) -> String {
+        self.text_or(key, key)
+    }
+
+    pub fn text_or(&self, key: &str, fallback: &str) -> String {
+        match self.lookup(key) {
+            Some(LocaleString::Text(text)) => self.finish(text),
+            Some(LocaleString::Lines(lines)) => self.finish(&lines.join("\n")),
+            Some(LocaleString::Plural { other, .. }) => self.finish(other),
+            None => self.finish(fallback),
+        }
+    }
+
+    pub fn plural(&self, key: &str, count: u32) -> String {
+        let text = match self.lookup(key) {
+            Some(LocaleString::Plural { one, other }) => {
+                if self.plural_rule().is_one(count) {
+                    self.finish(one)
+                } else {
+                    self.finish(other)
+                }
+            }
+            _ => self.text(key),
+        };
+        text.replace("{count}", &count.to_string())
+    }
+
+    /// Keys the reference locale or `source_keys` define that the current locale doesn't.
+    /// `source_keys` are keys whose reference text lives outside the locale files, such as
+    /// dialogue lines.
+    pub fn missing_keys<'a>(&'a self, source_keys: &'a [String]) -> Vec<&'a str> {
+        if self.locale == REFERENCE_LOCALE || self.locale == PSEUDO_LOCALE {
+            return vec![];
+        }
+        let Some(reference) = self.tables.get(REFERENCE_LOCALE) else {
+            return vec![];
+        };
+        let table = self.tables.get(&self.locale);
+        let mut keys: Vec<&str> = reference
+            .strings
+            .keys()
+            .chain(source_keys.iter())
+            .filter(|key| table.map_or(true, |table| !table.strings.contains_key(*key)))
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    fn plural_rule(&self) -> LocalePlural {
+        let code = if self.locale == PSEUDO_LOCALE {
+            REFERENCE_LOCALE
+        } else {
+            self.locale.as_str()
+        };
+        self.tables
+            .get(code)
+            .map(|table| table.plural)
+            .unwrap_or_default()
+    }
+
+    fn lookup(&self, key: &str) -> Option<&LocaleString> {
+        self.tables
+            .get(&self.locale)
+            .and_then(|table| table.strings.get(key))
+            .or_else(|| {
+                self.tables
+                    .get(REFERENCE_LOCALE)
+                    .and_then(|table| table.strings.get(key))
+            })
+    }
+
+    fn finish(&self, text: &str) -> String {
+        if self.locale == PSEUDO_LOCALE {
+            pseudo_localize(text)
+        } else {
+            text.to_owned()
+        }
+    }
+}
+
+fn pseudo_localize(text: &str) -> String {
+    let mut result = String::from("[");
+    let mut placeholder = false;
+    let mut letters = 0;
+    for c in text.chars() {
+        if c == '{' {
+            placeholder = true;
+        }
+        if placeholder {
+            result.push(c);
+            if c == '}' {
+                placeholder = false;
+            }
+            continue;
+        }
+        if c.is_ascii_alphabetic() {
+            letters += 1;
+        }
+        result.push(match c {
+            'a' => 'á',
+            'c' => 'ç',
+            'e' => 'é',
+            'i' => 'í',
+            'n' => 'ñ',
+            'o' => 'ö',
+            'u' => 'ü',
+            'y' => 'ý',
+            'A' => 'Å',
+            'C' => 'Ç',
+            'E' => 'É',
+            'I' => 'Î',
+            'N' => 'Ñ',
+            'O' => 'Ö',
+            'U' => 'Û',
+            'Y' => 'Ý',
+            _ => c,
+        });
+    }
+    for _ in 0..(letters + 2) / 3 {
+        result.push('~');
+    }
+    result.push(']');
+    result
+}
+
+#[derive(Component)]
+pub struct LocalizedText {
+    pub key: String,
+    pub count: Option<u32>,
+}
+
+impl LocalizedText {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            count: None,
+        }
+    }
+
+    pub fn plural(key: impl Into<String>, count: u32) -> Self {
+        Self {
+            key: key.into(),
+            count: Some(count),
+        }
+    }
+}
+
+fn localization_asset_sync(
+    mut ev_asset: EventReader<AssetEvent<LocaleAsset>>,
+    mut localization: ResMut<Localization>,
+    locale_assets: Res<Assets<LocaleAsset>>,
+) {
+    for event in ev_asset.iter() {
+        let (AssetEvent::Created { handle } | AssetEvent::Modified { handle }) = event else {
+            continue;
+        };
+        let Some(asset) = locale_assets.get(handle) else { continue };
+        if asset.code == PSEUDO_LOCALE {
+            warn!("Locale code {} is reserved", PSEUDO_LOCALE);
+            continue;
+        }
+        localization.tables.insert(
+            asset.code.clone(),
+            LocaleTable {
+                name: asset.name.clone(),
+                plural: asset.plural,
+                strings: asset.strings.clone(),
+            },
+        );
+    }
+}
+
+fn localization_settings_sync(settings: Res<Settings>, mut localization: ResMut<Localization>) {
+    if settings.locale != localization.locale {
+        localization.locale = settings.locale.clone();
+    }
+}
+
+fn localized_text_update(
+    mut query: Query<(Ref<LocalizedText>, &mut Text)>,
+    localization: Res<Localization>,
+) {
+    for (localized_text, mut text) in query.iter_mut() {
+        if !localization.is_changed() && !localized_text.is_changed() {
+            continue;
+        }
+        text.sections[0].value = if let Some(count) = localized_text.count {
+            localization.plural(&localized_text.key, count)
+        } else {
+            localization.text(&localized_text.key)
+        };
+    }
+}
+
+fn localization_debug(
+    mut egui_query: Query<&mut EguiContext>,
+    mut menu_bar: ResMut<MenuBar>,
+    mut settings: ResMut<Settings>,
+    localization: Res<Localization>,
+    dialogue: Res<Dialogue>,
+) {
+    menu_bar.item("Locale", |open| {
+        let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
+        egui::Window::new("Locale")
+            .open(open)
+            .show(egui_context.get_mut(), |ui| {
+                ui.horizontal(|ui| {
+                    for locale in localization.locales() {
+                        if ui
+                            .selectable_label(
+                                localization.locale() == locale,
+                                localization.locale_name_of(locale),
+                            )
+                            .clicked()
+                        {
+                            settings.locale = locale.to_owned();
+                        }
+                    }
+                });
+                let missing = localization.missing_keys(dialogue.localization_keys());
+                ui.label(format!("Missing keys: {}", missing.len()));
+                for key in missing {
+                    ui.label(key);
+                }
+            });
+    });
+}
@@ -37,6 +37,12 @@ impl Plugin for CommonPlugin {
             force_camera_ratio::ForceRatioPlugin,
             wasm::WasmPlugin,
             volume_control::VolumeControlPlugin,
+            localization::LocalizationPlugin,
+            input_map::InputMapPlugin,
+            settings::SettingsPlugin,
+            nav_grid::NavGridPlugin,
+            rng::RngPlugin,
+            audio_debug::AudioDebugPlugin,
         ))
         .add_global_state::<app_state::AppState>()
         .init_resource::<asset_library::AssetLibrary>()
@@ -58,6 +64,7 @@ fn safe_to_state_change() {}
 pub mod app_state;
 pub mod asset_library;
 pub mod assets;
+pub mod audio_debug;
 pub mod clickable;
 pub mod collision;
 pub mod cutscene;
@@ -68,15 +75,22 @@ pub mod entity_debug;
 pub mod facing;
 pub mod follow_camera;
 pub mod force_camera_ratio;
+pub mod input_map;
 pub mod label;
 pub mod ldtk;
+pub mod localization;
 pub mod map_builder;
 pub mod math;
 pub mod menu_bar;
 pub mod mouse;
+pub mod nav_grid;
 pub mod prelude;
+pub mod rng;
 pub mod screen_fade;
+pub mod settings;
 pub mod sound_effects;
+pub mod storage;
+pub mod tilemap;
 pub mod time_to_live;
 pub mod timed_chance;
 pub mod transform2;
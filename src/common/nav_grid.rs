@@ -0,0 +1,179 @@
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+pub const NAV_GRID_CELL_SIZE: f32 = 100.;
+
+/// Cells expanded before giving up, enough to route around the largest islands.
+const NAV_GRID_SEARCH_LIMIT: usize = 4000;
+const NAV_GRID_STRAIGHT_COST: u32 = 10;
+const NAV_GRID_DIAGONAL_COST: u32 = 14;
+const NAV_GRID_NEIGHBORS: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+pub struct NavGridPlugin;
+
+impl Plugin for NavGridPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavGrid>();
+    }
+}
+
+/// Blocked cells of the world, filled from the same IntGrid tiles as the island colliders.
+/// Cell `(x, y)` covers the tile centered at `(x, y) * NAV_GRID_CELL_SIZE`.
+#[derive(Default, Resource)]
+pub struct NavGrid {
+    blocked: HashSet<IVec2>,
+}
+
+impl NavGrid {
+    pub fn reset(&mut self) {
+        self.blocked.clear();
+    }
+
+    pub fn block(&mut self, cell: IVec2) {
+        self.blocked.insert(cell);
+    }
+
+    pub fn is_blocked(&self, cell: IVec2) -> bool {
+        self.blocked.contains(&cell)
+    }
+
+    pub fn world_to_cell(position: Vec2) -> IVec2 {
+        (position / NAV_GRID_CELL_SIZE).round().as_ivec2()
+    }
+
+    pub fn cell_to_world(cell: IVec2) -> Vec2 {
+        cell.as_vec2() * NAV_GRID_CELL_SIZE
+    }
+
+    /// Whether something `radius` wide can travel in a straight line without touching a blocked
+    /// cell.
+    pub fn is_clear(&self, from: Vec2, to: Vec2, radius: f32) -> bool {
+        let difference = to - from;
+        let side = difference.normalize_or_zero().perp() * radius;
+        let steps = (difference.length() / (NAV_GRID_CELL_SIZE * 0.25))
+            .ceil()
+            .max(1.) as usize;
+        for step in 0..=steps {
+            let point = from + difference * (step as f32 / steps as f32);
+            for offset in [Vec2::ZERO, side, -side] {
+                if self.is_blocked(Self::world_to_cell(point + offset)) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// A* over the grid followed by string pulling. The returned waypoints exclude `from` and
+    /// always end at `to`. Returns `None` when `to` is blocked or can't be reached.
+    pub fn find_path(&self, from: Vec2, to: Vec2, radius: f32) -> Option<Vec<Vec2>> {
+        if self.is_clear(from, to, radius) {
+            return Some(vec![to]);
+        }
+        let start = Self::world_to_cell(from);
+        let goal = Self::world_to_cell(to);
+        if self.is_blocked(goal) {
+            return None;
+        }
+        let mut open = BinaryHeap::new();
+        let mut came_from = HashMap::<IVec2, IVec2>::default();
+        let mut costs = HashMap::<IVec2, u32>::default();
+        costs.insert(start, 0);
+        open.push(Reverse((heuristic(start, goal), 0, start.x, start.y)));
+        let mut searched = 0;
+        while let Some(Reverse((_, cost, x, y))) = open.pop() {
+            let cell = IVec2::new(x, y);
+            // a cheaper way to this cell was found after this entry was pushed
+            if cost > costs[&cell] {
+                continue;
+            }
+            if cell == goal {
+                let mut cells = vec![cell];
+                while let Some(previous) = came_from.get(cells.last().unwrap()) {
+                    cells.push(*previous);
+                }
+                cells.pop();
+                cells.reverse();
+                let mut points: Vec<Vec2> = cells.into_iter().map(Self::cell_to_world).collect();
+                if let Some(last) = points.last_mut() {
+                    *last = to;
+                }
+                return Some(self.smooth_path(from, points, radius));
+            }
+            searched += 1;
+            if searched > NAV_GRID_SEARCH_LIMIT {
+                return None;
+            }
+            for (dx, dy) in NAV_GRID_NEIGHBORS {
+                let neighbor = cell + IVec2::new(dx, dy);
+                if self.is_blocked(neighbor) {
+                    continue;
+                }
+                let diagonal = dx != 0 && dy != 0;
+                if diagonal
+                    && (self.is_blocked(cell + IVec2::new(dx, 0))
+                        || self.is_blocked(cell + IVec2::new(0, dy)))
+                {
+                    continue;
+                }
+                let neighbor_cost = cost
+                    + if diagonal {
+                        NAV_GRID_DIAGONAL_COST
+                    } else {
+                        NAV_GRID_STRAIGHT_COST
+                    };
+                if costs
+                    .get(&neighbor)
+                    .map_or(true, |cost| neighbor_cost < *cost)
+                {
+                    costs.insert(neighbor, neighbor_cost);
+                    came_from.insert(neighbor, cell);
+                    open.push(Reverse((
+                        neighbor_cost + heuristic(neighbor, goal),
+                        neighbor_cost,
+                        neighbor.x,
+                        neighbor.y,
+                    )));
+                }
+            }
+        }
+        None
+    }
+
+    fn smooth_path(&self, from: Vec2, points: Vec<Vec2>, radius: f32) -> Vec<Vec2> {
+        let mut smoothed = vec![];
+        let mut current = from;
+        let mut index = 0;
+        while index < points.len() {
+            let mut furthest = index;
+            while furthest + 1 < points.len()
+                && self.is_clear(current, points[furthest + 1], radius)
+            {
+                furthest += 1;
+            }
+            current = points[furthest];
+            smoothed.push(current);
+            index = furthest + 1;
+        }
+        smoothed
+    }
+}
+
+fn heuristic(from: IVec2, to: IVec2) -> u32 {
+    let difference = (to - from).abs();
+    let straight = difference.x.max(difference.y) - difference.x.min(difference.y);
+    let diagonal = difference.x.min(difference.y);
+    straight as u32 * NAV_GRID_STRAIGHT_COST + diagonal as u32 * NAV_GRID_DIAGONAL_COST
+}
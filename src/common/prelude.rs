@@ -1,31 +1,56 @@
 pub use super::{
     app_state::AppState,
     asset_library::AssetLibrary,
-    assets::ldtk::LdtkAsset,
+    assets::{
+        dialogue::{
+            DialogueAsset, DialogueBranch, DialogueCondition, DialogueEffect, DialogueNode,
+            DialogueOption,
+        },
+        enemy_archetypes::{
+            EnemyArchetype, EnemyArchetypesAsset, EnemyAttack, EnemyBehavior, EnemySteering,
+        },
+        enemy_spawns::{EnemySpawnChance, EnemySpawnLevel, EnemySpawnsAsset},
+        ldtk::LdtkAsset,
+        locale::{LocaleAsset, LocaleIndexAsset, LocalePlural, LocaleString},
+    },
     clickable::Clickable,
     collision::{
-        shape::CollisionShape, Collision, CollisionFilter, CollisionQuery, COLLISION_FLAG,
+        shape::CollisionShape, Collision, CollisionFilter, CollisionHit, CollisionQuery,
+        CollisionQueryEntry, COLLISION_FLAG,
     },
     cutscene::{
-        AddAppCutscene, Cutscene, CutsceneBuilder, CutsceneContinueEvent, CutsceneSkipEvent,
-        CutsceneStartEvent, Cutscenes,
+        AddAppCutscene, Cutscene, CutsceneBuilder, CutsceneCancelEvent, CutsceneCompleteEvent,
+        CutsceneContinueEvent, CutsceneSkipEvent, CutsceneStartEvent, CutsceneStartParallelEvent,
+        CutsceneSystem, Cutscenes,
     },
     depth_layers::*,
-    dialogue::{Dialogue, DialogueInitEvent, DialoguePortrait},
+    dialogue::{Dialogue, DialogueContext, DialogueInitEvent, DialoguePortrait, DialogueSystem},
     easing::*,
     facing::Facing,
     follow_camera::FollowCamera,
+    input_map::{Action, Actions, Binding, InputMap},
     label::Label,
-    ldtk::{Ldtk, LdtkSpawnEvent},
+    ldtk::{
+        AddAppLdtkEntity, Ldtk, LdtkEntities, LdtkEntity, LdtkEntityRef, LdtkEntityRegistry,
+        LdtkEntitySpawner, LdtkFieldValue, LdtkFields, LdtkSpawnEvent,
+    },
+    localization::{Localization, LocalizedText},
     map_builder::MapBuilder,
     math::{Lerp, Rect},
     menu_bar::MenuBar,
     mouse::Mouse,
+    nav_grid::{NavGrid, NAV_GRID_CELL_SIZE},
+    rng::{GameRng, RngStream},
     screen_fade::ScreenFade,
+    settings::{CameraRatio, Settings, WorldSource},
     time_to_live::TimeToLive,
     timed_chance::TimedChance,
     transform2::{DepthLayer, Transform2, Transform2Bundle},
-    world_locations::{WorldLocationRect, WorldLocations, WorldLocationsSpawnEvent},
+    world_locations::{
+        AddAppWorldLocation, WorldLocationCount, WorldLocationError, WorldLocationRect,
+        WorldLocations, WorldLocationsSpawnEvent, WORLD_LOCATION_TAG_ARENA,
+        WORLD_LOCATION_TAG_THREAT_LEVEL, WORLD_LOCATION_TAG_TOWN,
+    },
     y_depth::YDepth,
     CommonPlugin, CommonSystem,
 };
@@ -0,0 +1,131 @@
+use crate::common::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+use bevy_egui::{egui, EguiContext};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+pub struct RngPlugin;
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameRng>()
+            .add_systems(Update, rng_debug);
+    }
+}
+
+/// Independent sequences of random numbers. Drawing from one stream never changes what another
+/// stream returns, so frame-dependent effects can't change where enemies spawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RngStream {
+    World,
+    Spawns,
+    Loot,
+    Attacks,
+    Ai,
+    Fx,
+}
+
+impl RngStream {
+    pub fn all() -> &'static [RngStream] {
+        &[
+            Self::World,
+            Self::Spawns,
+            Self::Loot,
+            Self::Attacks,
+            Self::Ai,
+            Self::Fx,
+        ]
+    }
+}
+
+/// Randomness for a run. Every stream is derived from a single seed, so a run can be replayed
+/// from its seed.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    next_seed: Option<u64>,
+    streams: HashMap<RngStream, StdRng>,
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            next_seed: None,
+            streams: HashMap::new(),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restarts every stream from `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.streams.clear();
+    }
+
+    /// Seed for a new run: the one picked in the debug window, or a random one.
+    pub fn new_run_seed(&mut self) -> u64 {
+        self.next_seed.take().unwrap_or_else(rand::random)
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut StdRng {
+        let seed = self.seed;
+        self.streams
+            .entry(stream)
+            .or_insert_with(|| StdRng::seed_from_u64(stream_seed(seed, stream)))
+    }
+
+    pub fn f32(&mut self, stream: RngStream) -> f32 {
+        self.stream(stream).gen()
+    }
+
+    pub fn bool(&mut self, stream: RngStream) -> bool {
+        self.stream(stream).gen()
+    }
+
+    /// Unit vector in a random direction.
+    pub fn direction(&mut self, stream: RngStream) -> Vec2 {
+        Vec2::from_angle(self.f32(stream) * std::f32::consts::TAU)
+    }
+
+    pub fn timed_chance(&mut self, stream: RngStream) -> TimedChance {
+        TimedChance::from_rng(self.stream(stream))
+    }
+}
+
+/// SplitMix64 of the run seed and stream, so nearby seeds still give unrelated streams.
+fn stream_seed(seed: u64, stream: RngStream) -> u64 {
+    let mut z = seed ^ (stream as u64 + 1).wrapping_mul(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+fn rng_debug(
+    mut egui_query: Query<&mut EguiContext>,
+    mut menu_bar: ResMut<MenuBar>,
+    mut game_rng: ResMut<GameRng>,
+    mut next_seed: Local<String>,
+) {
+    menu_bar.item("Rng", |open| {
+        let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
+        egui::Window::new("Rng")
+            .open(open)
+            .show(egui_context.get_mut(), |ui| {
+                ui.label(format!("Seed: {}", game_rng.seed()));
+                ui.horizontal(|ui| {
+                    ui.label("Next Run Seed");
+                    ui.text_edit_singleline(&mut *next_seed);
+                });
+                game_rng.next_seed = next_seed.trim().parse().ok();
+            });
+    });
+}
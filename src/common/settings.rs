@@ -0,0 +1,259 @@
+use crate::common::{
+    localization::REFERENCE_LOCALE,
+    prelude::*,
+    storage::{storage_read, storage_remove, storage_write},
+};
+use audio_plus::prelude::*;
+use bevy::{prelude::*, window::WindowMode};
+use bevy_egui::{egui, EguiContext};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+
+const SETTINGS_SAVE_DELAY: f32 = 0.5;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load())
+            .add_systems(Startup, settings_apply)
+            .add_systems(Update, (settings_apply, settings_save, settings_debug));
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CameraRatio {
+    #[default]
+    Letterbox,
+    Stretch,
+}
+
+impl CameraRatio {
+    pub fn all() -> &'static [CameraRatio] {
+        &[Self::Letterbox, Self::Stretch]
+    }
+
+    pub fn name_key(&self) -> &'static str {
+        match *self {
+            Self::Letterbox => "settings.camera_ratio.letterbox",
+            Self::Stretch => "settings.camera_ratio.stretch",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let all = Self::all();
+        let index = all.iter().position(|ratio| ratio == self).unwrap_or(0);
+        all[(index + 1) % all.len()]
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorldSource {
+    #[default]
+    Handmade,
+    Archipelago,
+}
+
+impl WorldSource {
+    pub fn all() -> &'static [WorldSource] {
+        &[Self::Handmade, Self::Archipelago]
+    }
+
+    pub fn name_key(&self) -> &'static str {
+        match *self {
+            Self::Handmade => "settings.world_source.handmade",
+            Self::Archipelago => "settings.world_source.archipelago",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let all = Self::all();
+        let index = all.iter().position(|source| source == self).unwrap_or(0);
+        all[(index + 1) % all.len()]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Resource, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub master_volume: f32,
+    /// Keyed by bus name. Buses without an entry play at full volume.
+    pub bus_volumes: BTreeMap<String, f32>,
+    pub fullscreen: bool,
+    pub camera_ratio: CameraRatio,
+    pub world_source: WorldSource,
+    /// Seed for new runs. `None` picks a new seed for every run.
+    pub seed: Option<u64>,
+    /// Code of a loaded locale file, or the pseudo locale.
+    pub locale: String,
+    /// Older settings stored these instead of `bus_volumes`. They are moved over on load.
+    #[serde(skip_serializing, deserialize_with = "deserialize_legacy_volume")]
+    music_volume: Option<f32>,
+    #[serde(skip_serializing, deserialize_with = "deserialize_legacy_volume")]
+    sfx_volume: Option<f32>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.,
+            bus_volumes: BTreeMap::new(),
+            fullscreen: false,
+            camera_ratio: CameraRatio::default(),
+            world_source: WorldSource::default(),
+            seed: None,
+            locale: REFERENCE_LOCALE.to_owned(),
+            music_volume: None,
+            sfx_volume: None,
+        }
+    }
+}
+
+impl Settings {
+    fn load() -> Self {
+        let mut settings = storage_read("settings")
+            .and_then(|data| ron::from_str::<Settings>(&data).ok())
+            .unwrap_or_default();
+        let legacy_volumes = [
+            (AudioPlusMixerChannel::Music, settings.music_volume.take()),
+            (AudioPlusMixerChannel::Sfx, settings.sfx_volume.take()),
+        ];
+        for (bus, volume) in legacy_volumes {
+            if let Some(volume) = volume {
+                settings
+                    .bus_volumes
+                    .entry(bus.name().to_owned())
+                    .or_insert(volume);
+            }
+        }
+        settings.master_volume = settings.master_volume.clamp(0., 1.);
+        for volume in settings.bus_volumes.values_mut() {
+            *volume = volume.clamp(0., 1.);
+        }
+        // the locale used to be stored on its own
+        if let Some(locale) = storage_read("locale") {
+            settings.locale = locale.trim().to_owned();
+            if settings.save() {
+                storage_remove("locale");
+            }
+        }
+        settings
+    }
+
+    pub fn bus_volume(&self, bus: AudioPlusMixerChannel) -> f32 {
+        self.bus_volumes.get(bus.name()).copied().unwrap_or(1.)
+    }
+
+    pub fn set_bus_volume(&mut self, bus: AudioPlusMixerChannel, volume: f32) {
+        self.bus_volumes.insert(bus.name().to_owned(), volume);
+    }
+
+    fn save(&self) -> bool {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_or(false, |data| storage_write("settings", &data))
+    }
+}
+
+fn deserialize_legacy_volume<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<f32>, D::Error> {
+    f32::deserialize(deserializer).map(Some)
+}
+
+fn settings_apply(
+    settings: Res<Settings>,
+    mut mixer: ResMut<AudioPlusMixer>,
+    mut window_query: Query<&mut Window>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    mixer.set_master_volume(settings.master_volume);
+    let buses = mixer.buses().collect::<Vec<_>>();
+    for bus in buses {
+        mixer.set_volume(bus, settings.bus_volume(bus));
+    }
+    if let Ok(mut window) = window_query.get_single_mut() {
+        let mode = if settings.fullscreen {
+            WindowMode::BorderlessFullscreen
+        } else {
+            WindowMode::Windowed
+        };
+        if window.mode != mode {
+            window.mode = mode;
+        }
+    }
+}
+
+fn settings_save(settings: Res<Settings>, mut timer: Local<Option<f32>>, time: Res<Time>) {
+    if settings.is_changed() && !settings.is_added() {
+        *timer = Some(SETTINGS_SAVE_DELAY);
+    }
+    if let Some(seconds) = timer.as_mut() {
+        *seconds -= time.delta_seconds();
+        if *seconds <= 0. {
+            if !settings.save() {
+                warn!("Failed to save settings");
+            }
+            *timer = None;
+        }
+    }
+}
+
+fn settings_debug(
+    mut egui_query: Query<&mut EguiContext>,
+    mut menu_bar: ResMut<MenuBar>,
+    mut settings: ResMut<Settings>,
+    mixer: Res<AudioPlusMixer>,
+) {
+    menu_bar.item("Settings", |open| {
+        let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
+        let mut edited = settings.clone();
+        egui::Window::new("Settings")
+            .open(open)
+            .show(egui_context.get_mut(), |ui| {
+                ui.add(egui::Slider::new(&mut edited.master_volume, 0. ..=1.).text("Master"));
+                for bus in mixer.buses() {
+                    let mut volume = edited.bus_volume(bus);
+                    ui.horizontal(|ui| {
+                        ui.add_space(mixer.get_depth(bus) as f32 * 16.);
+                        ui.add(egui::Slider::new(&mut volume, 0. ..=1.).text(bus.name()));
+                    });
+                    if volume != edited.bus_volume(bus) {
+                        edited.set_bus_volume(bus, volume);
+                    }
+                }
+                ui.checkbox(&mut edited.fullscreen, "Fullscreen");
+                ui.horizontal(|ui| {
+                    for ratio in CameraRatio::all() {
+                        ui.selectable_value(
+                            &mut edited.camera_ratio,
+                            *ratio,
+                            format!("{:?}", ratio),
+                        );
+                    }
+                });
+                ui.horizontal(|ui| {
+                    for source in WorldSource::all() {
+                        ui.selectable_value(
+                            &mut edited.world_source,
+                            *source,
+                            format!("{:?}", source),
+                        );
+                    }
+                });
+                ui.horizontal(|ui| {
+                    let mut fixed_seed = edited.seed.is_some();
+                    ui.checkbox(&mut fixed_seed, "Fixed Seed");
+                    if fixed_seed {
+                        ui.add(egui::DragValue::new(edited.seed.get_or_insert(0)));
+                    } else {
+                        edited.seed = None;
+                    }
+                });
+            });
+        if edited != *settings {
+            *settings = edited;
+        }
+    });
+}
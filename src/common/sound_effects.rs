@@ -1,6 +1,11 @@
 pub use crate::common::prelude::*;
 pub use audio_plus::prelude::*;
 
+pub const AUDIO_BUS_VOICE: AudioPlusMixerChannel = AudioPlusMixerChannel::Bus("Voice");
+pub const AUDIO_BUS_AMBIENT: AudioPlusMixerChannel = AudioPlusMixerChannel::Bus("Ambient");
+pub const AUDIO_BUS_UI: AudioPlusMixerChannel = AudioPlusMixerChannel::Bus("UI");
+pub const AUDIO_BUS_STINGERS: AudioPlusMixerChannel = AudioPlusMixerChannel::Bus("Stingers");
+
 #[derive(Default)]
 pub struct SoundEffects {
     // placeholder
@@ -36,6 +41,8 @@ pub struct SoundEffects {
     // overworld
     pub sfx_overworld_ambient: AudioPlusSoundEffect,
     pub sfx_overworld_music: AudioPlusSoundEffect,
+    pub sfx_overworld_music_danger: AudioPlusSoundEffect,
+    pub sfx_overworld_music_boss: AudioPlusSoundEffect,
     pub sfx_overworld_town_enter: AudioPlusSoundEffect,
     pub sfx_overworld_town_exit: AudioPlusSoundEffect,
     pub sfx_overworld_dash: AudioPlusSoundEffect,
@@ -72,21 +79,26 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
         sfx_placeholder_music: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_music_placeholder.clone()],
             volume: 1.0,
+            channel: AudioPlusMixerChannel::Music,
+            priority: 100,
             ..Default::default()
         },
         sfx_placeholder_sound: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_placeholder.clone()],
             volume: 1.0,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_dialogue_start: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_placeholder.clone()],
             volume: 0.0,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_dialogue_proceed: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_sfx_button_click.clone()],
             volume: 1.0,
+            channel: AUDIO_BUS_UI,
             ..Default::default()
         },
         sfx_dialogue_repeat: AudioPlusSoundEffect {
@@ -104,6 +116,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             volume: 0.6,
             pitch_variation: 0.2,
             chance: 0.85,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_menu_ambient: AudioPlusSoundEffect {
@@ -111,87 +124,106 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             volume: 0.6,
             fade_in: 1.,
             fade_out: 1.,
+            channel: AUDIO_BUS_AMBIENT,
+            priority: 100,
             ..Default::default()
         },
         sfx_menu_music: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_music.clone()],
             volume: 0.65,
             fade_out: 1.7,
+            channel: AudioPlusMixerChannel::Music,
+            priority: 100,
             ..Default::default()
         },
         sfx_menu_button_hover: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_sfx_button_hover.clone()],
             volume: 1.,
+            channel: AUDIO_BUS_UI,
             ..Default::default()
         },
         sfx_menu_button_click: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_sfx_button_click.clone()],
             volume: 1.,
+            channel: AUDIO_BUS_UI,
             ..Default::default()
         },
         sfx_menu_button_click_confirm: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_sfx_play.clone()],
             volume: 1.,
+            channel: AUDIO_BUS_UI,
             ..Default::default()
         },
         sfx_cutscene_intro_music: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_music.clone()],
             volume: 0.1,
             fade_out: 1.0,
+            channel: AudioPlusMixerChannel::Music,
+            priority: 100,
             ..Default::default()
         },
         sfx_cutscene_intro1: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.cutscene_voice_intro1.clone()],
             volume: 0.7,
             fade_out: 0.5,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_cutscene_intro2: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.cutscene_voice_intro2.clone()],
             volume: 0.7,
             fade_out: 0.5,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_cutscene_intro3: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.cutscene_voice_intro3.clone()],
             volume: 0.7,
             fade_out: 0.5,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_cutscene_intro4: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.cutscene_voice_intro4.clone()],
             volume: 0.7,
             fade_out: 0.5,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_cutscene_intro5: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.cutscene_voice_intro5.clone()],
             volume: 0.7,
             fade_out: 0.5,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_cutscene_outro_music: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_music.clone()],
             volume: 0.1,
             fade_out: 1.0,
+            channel: AudioPlusMixerChannel::Music,
+            priority: 100,
             ..Default::default()
         },
         sfx_cutscene_outro1: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.cutscene_voice_outro1.clone()],
             volume: 0.7,
             fade_out: 0.5,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_cutscene_outro2: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.cutscene_voice_outro2.clone()],
             volume: 0.7,
             fade_out: 0.5,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_cutscene_outro3: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.cutscene_voice_outro3.clone()],
             volume: 0.7,
             fade_out: 0.5,
+            channel: AUDIO_BUS_VOICE,
             ..Default::default()
         },
         sfx_overworld_ambient: AudioPlusSoundEffect {
@@ -199,16 +231,20 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             volume: 0.1,
             fade_in: 1.,
             fade_out: 1.,
+            channel: AUDIO_BUS_AMBIENT,
+            priority: 100,
             ..Default::default()
         },
         sfx_overworld_town_enter: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_town_enter.clone()],
             volume: 0.8,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_town_exit: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_town_leave.clone()],
             volume: 0.8,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_dash: AudioPlusSoundEffect {
@@ -218,6 +254,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
                 asset_library.audio_sfx_dash_03.clone(),
             ],
             volume: 0.1,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_experience: AudioPlusSoundEffect {
@@ -227,6 +264,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
                 asset_library.audio_sfx_xp_03.clone(),
             ],
             volume: 0.45,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_player_damage: AudioPlusSoundEffect {
@@ -236,11 +274,15 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
                 asset_library.audio_sfx_player_damage_03.clone(),
             ],
             volume: 0.6,
+            channel: AudioPlusMixerChannel::Sfx,
+            priority: 10,
             ..Default::default()
         },
         sfx_overworld_player_died: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_player_died.clone()],
             volume: 0.8,
+            channel: AUDIO_BUS_STINGERS,
+            priority: 10,
             ..Default::default()
         },
         sfx_overworld_enemy_damage: AudioPlusSoundEffect {
@@ -251,21 +293,26 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             ],
             positional: true,
             volume: 0.6,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_map_open: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_map_open_01.clone()],
             volume: 0.5,
+            channel: AUDIO_BUS_UI,
             ..Default::default()
         },
         sfx_overworld_map_close: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_map_close_01.clone()],
             volume: 0.5,
+            channel: AUDIO_BUS_UI,
             ..Default::default()
         },
         sfx_overworld_level_up: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_level_up.clone()],
             volume: 0.5,
+            channel: AUDIO_BUS_STINGERS,
+            priority: 10,
             ..Default::default()
         },
         sfx_overworld_attack_forward_cannons: AudioPlusSoundEffect {
@@ -276,6 +323,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             ],
             volume: 0.4,
             positional: true,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_attack_shotgun_cannons: AudioPlusSoundEffect {
@@ -286,6 +334,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             ],
             volume: 0.3,
             positional: true,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_attack_shockwave: AudioPlusSoundEffect {
@@ -296,6 +345,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             ],
             volume: 0.3,
             positional: true,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_attack_bombs: AudioPlusSoundEffect {
@@ -306,6 +356,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             ],
             volume: 0.15,
             positional: true,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_attack_bomb_throw: AudioPlusSoundEffect {
@@ -316,6 +367,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             ],
             volume: 0.1,
             positional: true,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_attack_bomb_explode: AudioPlusSoundEffect {
@@ -326,6 +378,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             ],
             volume: 0.2,
             positional: true,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_attack_kraken: AudioPlusSoundEffect {
@@ -336,6 +389,7 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             ],
             volume: 0.2,
             positional: true,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_attack_tentacle: AudioPlusSoundEffect {
@@ -347,30 +401,38 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
             volume: 0.06,
             positional: true,
             pitch_variation: 0.1,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_overworld_music: AudioPlusSoundEffect::none(),
+        sfx_overworld_music_danger: AudioPlusSoundEffect::none(),
+        sfx_overworld_music_boss: AudioPlusSoundEffect::none(),
         sfx_town_ambient: AudioPlusSoundEffect::none(),
         sfx_town_music: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_music.clone()],
             volume: 0.2,
             fade_in: 0.2,
             fade_out: 1.0,
+            channel: AudioPlusMixerChannel::Music,
+            priority: 100,
             ..Default::default()
         },
         sfx_town_outside_hover: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_sfx_button_hover.clone()],
             volume: 1.,
+            channel: AUDIO_BUS_UI,
             ..Default::default()
         },
         sfx_town_outside_click: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.menu_sfx_button_click.clone()],
             volume: 1.,
+            channel: AUDIO_BUS_UI,
             ..Default::default()
         },
         sfx_town_rum_refill_jingle: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_town_rum_refill_jingle.clone()],
             volume: 1.0,
+            channel: AUDIO_BUS_STINGERS,
             ..Default::default()
         },
         sfx_town_rum_refill_clank: AudioPlusSoundEffect {
@@ -380,16 +442,19 @@ pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
                 asset_library.audio_sfx_town_rum_refill_clank_03.clone(),
             ],
             volume: 1.0,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
         sfx_town_upgrade: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_upgrade_01.clone()],
             volume: 1.0,
+            channel: AUDIO_BUS_STINGERS,
             ..Default::default()
         },
         sfx_audio_preview: AudioPlusSoundEffect {
             audio_sources: vec![asset_library.audio_sfx_preview.clone()],
             volume: 1.0,
+            channel: AudioPlusMixerChannel::Sfx,
             ..Default::default()
         },
     }
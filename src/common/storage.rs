@@ -0,0 +1,70 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+const STORAGE_NAME: &str = "shanty-quest";
+
+#[cfg(not(target_arch = "wasm32"))]
+fn storage_path(key: &str) -> PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    let path = base.join(STORAGE_NAME).join(key);
+    // keys without an extension of their own are ron files
+    if path.extension().is_some() {
+        path
+    } else {
+        path.with_extension("ron")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn storage_read(key: &str) -> Option<String> {
+    std::fs::read_to_string(storage_path(key)).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn storage_write(key: &str, value: &str) -> bool {
+    let path = storage_path(key);
+    if let Some(parent) = path.parent() {
+        if std::fs::create_dir_all(parent).is_err() {
+            return false;
+        }
+    }
+    std::fs::write(path, value).is_ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn storage_remove(key: &str) -> bool {
+    std::fs::remove_file(storage_path(key)).is_ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn storage_key(key: &str) -> String {
+    format!("{}/{}", STORAGE_NAME, key)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn storage_read(key: &str) -> Option<String> {
+    local_storage()?.get_item(&storage_key(key)).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn storage_write(key: &str, value: &str) -> bool {
+    local_storage()
+        .map(|storage| storage.set_item(&storage_key(key), value).is_ok())
+        .unwrap_or(false)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn storage_remove(key: &str) -> bool {
+    local_storage()
+        .map(|storage| storage.remove_item(&storage_key(key)).is_ok())
+        .unwrap_or(false)
+}
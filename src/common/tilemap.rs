@@ -0,0 +1,140 @@
+use crate::common::prelude::*;
+use bevy::{
+    prelude::*,
+    render::{mesh::Indices, primitives::Aabb, render_resource::PrimitiveTopology},
+    sprite::MaterialMesh2dBundle,
+};
+use std::collections::HashMap;
+
+/// Tiles along each side of a chunk. Each chunk is a single mesh, culled as a whole when it is
+/// off screen.
+const TILEMAP_CHUNK_TILES: i64 = 32;
+
+/// Grid of square tiles in a texture, numbered left to right then top to bottom.
+pub struct Tileset {
+    material: Handle<ColorMaterial>,
+    size: Vec2,
+    tile_size: i64,
+    columns: i64,
+}
+
+pub struct TilemapTile {
+    /// Center of the tile.
+    pub position: Vec2,
+    pub index: i64,
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+
+impl Tileset {
+    pub fn new(material: Handle<ColorMaterial>, size: Vec2, tile_size: i64) -> Self {
+        Self {
+            material,
+            size,
+            tile_size,
+            columns: size.x as i64 / tile_size,
+        }
+    }
+
+    fn uv_rect(&self, index: i64) -> (Vec2, Vec2) {
+        let min = Vec2::new(
+            (index % self.columns * self.tile_size) as f32,
+            (index / self.columns * self.tile_size) as f32,
+        );
+        (min / self.size, (min + self.tile_size as f32) / self.size)
+    }
+}
+
+#[derive(Default)]
+struct TilemapChunk {
+    positions: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+    min: Vec2,
+    max: Vec2,
+}
+
+impl TilemapChunk {
+    fn add_tile(&mut self, center: Vec2, half_size: f32, uv_min: Vec2, uv_max: Vec2) {
+        let index = self.positions.len() as u32;
+        let (min, max) = (center - half_size, center + half_size);
+        self.positions.extend([
+            [min.x, min.y, 0.],
+            [max.x, min.y, 0.],
+            [max.x, max.y, 0.],
+            [min.x, max.y, 0.],
+        ]);
+        self.uvs.extend([
+            [uv_min.x, uv_max.y],
+            [uv_max.x, uv_max.y],
+            [uv_max.x, uv_min.y],
+            [uv_min.x, uv_min.y],
+        ]);
+        self.indices
+            .extend([index, index + 1, index + 2, index, index + 2, index + 3]);
+        if index == 0 {
+            (self.min, self.max) = (min, max);
+        } else {
+            self.min = self.min.min(min);
+            self.max = self.max.max(max);
+        }
+    }
+
+    fn into_mesh(self) -> Mesh {
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_NORMAL,
+            vec![[0., 0., 1.]; self.positions.len()],
+        );
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs);
+        mesh.set_indices(Some(Indices::U32(self.indices)));
+        mesh
+    }
+}
+
+/// Bakes tiles into chunk meshes, drawn the same as one sprite per tile. Tiles overlapping in a
+/// chunk are drawn in order.
+pub fn spawn_tile_chunks(
+    tiles: impl IntoIterator<Item = TilemapTile>,
+    tileset: &Tileset,
+    meshes: &mut Assets<Mesh>,
+    commands: &mut Commands,
+) -> Vec<Entity> {
+    let chunk_size = (TILEMAP_CHUNK_TILES * tileset.tile_size) as f32;
+    let half_size = tileset.tile_size as f32 * 0.5;
+    let mut chunks: HashMap<IVec2, TilemapChunk> = HashMap::new();
+    for tile in tiles {
+        let chunk_position = (tile.position / chunk_size).floor().as_ivec2();
+        let center = tile.position - chunk_position.as_vec2() * chunk_size;
+        let (mut uv_min, mut uv_max) = tileset.uv_rect(tile.index);
+        if tile.flip_x {
+            std::mem::swap(&mut uv_min.x, &mut uv_max.x);
+        }
+        if tile.flip_y {
+            std::mem::swap(&mut uv_min.y, &mut uv_max.y);
+        }
+        chunks
+            .entry(chunk_position)
+            .or_default()
+            .add_tile(center, half_size, uv_min, uv_max);
+    }
+    chunks
+        .into_iter()
+        .map(|(chunk_position, chunk)| {
+            let aabb = Aabb::from_min_max(chunk.min.extend(0.), chunk.max.extend(0.));
+            let origin = chunk_position.as_vec2() * chunk_size;
+            commands
+                .spawn((
+                    MaterialMesh2dBundle {
+                        mesh: meshes.add(chunk.into_mesh()).into(),
+                        material: tileset.material.clone(),
+                        ..Default::default()
+                    },
+                    aabb,
+                    Transform2::from_translation(origin),
+                ))
+                .id()
+        })
+        .collect()
+}
@@ -1,17 +1,18 @@
 use rand::prelude::*;
 use rand::{rngs::StdRng, SeedableRng};
 
-#[derive(Default)]
+/// Seeded from a [`GameRng`](crate::common::rng::GameRng) stream with
+/// [`GameRng::timed_chance`](crate::common::rng::GameRng::timed_chance).
 pub struct TimedChance {
     time: f32,
     seed: [u8; 32],
 }
 
 impl TimedChance {
-    pub fn new() -> Self {
+    pub fn from_rng(rng: &mut impl Rng) -> Self {
         Self {
             time: 0.,
-            seed: Default::default(),
+            seed: rng.gen(),
         }
     }
 
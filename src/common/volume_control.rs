@@ -1,4 +1,7 @@
-use crate::common::prelude::*;
+use crate::common::{
+    prelude::*,
+    sound_effects::{AUDIO_BUS_AMBIENT, AUDIO_BUS_STINGERS, AUDIO_BUS_UI, AUDIO_BUS_VOICE},
+};
 use audio_plus::prelude::*;
 use bevy::prelude::*;
 
@@ -6,27 +9,48 @@ pub struct VolumeControlPlugin;
 
 impl Plugin for VolumeControlPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, volume_control);
+        app.add_audio_bus(AUDIO_BUS_STINGERS, AudioPlusMixerChannel::Music)
+            .add_audio_bus(AUDIO_BUS_AMBIENT, AudioPlusMixerChannel::Sfx)
+            .add_audio_bus(AUDIO_BUS_UI, AudioPlusMixerChannel::Sfx)
+            .add_audio_bus(AUDIO_BUS_VOICE, AudioPlusMixerChannel::None)
+            .add_audio_ducking(
+                AudioPlusMixerChannel::Music,
+                AUDIO_BUS_VOICE,
+                AudioPlusDucking {
+                    volume: 0.4,
+                    attack: 0.3,
+                    release: 1.2,
+                },
+            )
+            .add_audio_ducking(
+                AUDIO_BUS_AMBIENT,
+                AUDIO_BUS_VOICE,
+                AudioPlusDucking {
+                    volume: 0.7,
+                    ..Default::default()
+                },
+            )
+            .add_systems(Update, volume_control);
     }
 }
 
 fn volume_control(
-    mut audio_mixer: ResMut<AudioPlusMixer>,
+    mut settings: ResMut<Settings>,
     input: Res<Input<KeyCode>>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
 ) {
-    let volume = audio_mixer.get_master_volume();
+    let volume = settings.master_volume;
     let mut play_sample = false;
     if input.just_pressed(KeyCode::P) {
-        audio_mixer.set_master_volume(volume + 0.1);
-        if audio_mixer.get_master_volume() != volume {
+        settings.master_volume = (volume + 0.1).min(1.);
+        if settings.master_volume != volume {
             play_sample = true;
         }
     }
     if input.just_pressed(KeyCode::O) {
-        audio_mixer.set_master_volume((volume - 0.1).max(0.1));
-        if audio_mixer.get_master_volume() != volume {
+        settings.master_volume = (volume - 0.1).max(0.1);
+        if settings.master_volume != volume {
             play_sample = true;
         }
     }
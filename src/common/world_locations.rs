@@ -1,11 +1,19 @@
+use crate::common::prelude::*;
 use bevy::{prelude::*, utils::HashMap};
+use bevy_egui::{egui, EguiContext};
+use std::fmt;
+
+pub const WORLD_LOCATION_TAG_TOWN: &str = "Town";
+pub const WORLD_LOCATION_TAG_ARENA: &str = "Arena";
+pub const WORLD_LOCATION_TAG_THREAT_LEVEL: &str = "ThreatLevel";
 
 pub struct WorldLocationsPlugin;
 
 impl Plugin for WorldLocationsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<WorldLocations>()
-            .add_event::<WorldLocationsSpawnEvent>();
+            .add_event::<WorldLocationsSpawnEvent>()
+            .add_systems(Update, (world_locations_validate, world_locations_debug));
     }
 }
 
@@ -15,6 +23,8 @@ pub struct WorldLocationsSpawnEvent;
 #[derive(Default, Resource)]
 pub struct WorldLocations {
     positions: HashMap<String, Vec<WorldLocationRect>>,
+    tags: HashMap<String, Vec<String>>,
+    expected: HashMap<String, WorldLocationCount>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -23,9 +33,61 @@ pub struct WorldLocationRect {
     pub size: Vec2,
 }
 
+/// How many times a location must appear in the level, checked when the level loads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorldLocationCount {
+    Single,
+    Optional,
+    Multiple,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WorldLocationError {
+    Missing(String),
+    Duplicate(String, usize),
+}
+
+impl fmt::Display for WorldLocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorldLocationError::Missing(name) => write!(f, "missing world location: {}", name),
+            WorldLocationError::Duplicate(name, count) => {
+                write!(f, "expected one world location {}, found {}", name, count)
+            }
+        }
+    }
+}
+
+impl WorldLocationError {
+    pub fn name(&self) -> &str {
+        match self {
+            WorldLocationError::Missing(name) => name,
+            WorldLocationError::Duplicate(name, _) => name,
+        }
+    }
+}
+
+impl std::error::Error for WorldLocationError {}
+
+pub trait AddAppWorldLocation {
+    fn add_world_location(&mut self, name: &str, count: WorldLocationCount) -> &mut Self;
+}
+
+impl AddAppWorldLocation for App {
+    fn add_world_location(&mut self, name: &str, count: WorldLocationCount) -> &mut Self {
+        self.init_resource::<WorldLocations>();
+        self.world
+            .resource_mut::<WorldLocations>()
+            .require(name, count);
+        self
+    }
+}
+
 impl WorldLocations {
+    /// Removes every location, but keeps what is expected of the next level.
     pub fn clear(&mut self) {
         self.positions = HashMap::new();
+        self.tags = HashMap::new();
     }
 
     pub fn add(&mut self, name: &str, position: Vec2, size: Vec2) {
@@ -42,38 +104,160 @@ impl WorldLocations {
         }
     }
 
-    pub fn get_single_position(&self, name: &str) -> Vec2 {
-        if let Some(vec) = self.positions.get(name) {
-            vec[0].position
-        } else {
-            Vec2::ZERO
-        }
+    /// Tags belong to a name, like the entity definitions in LDtk.
+    pub fn set_tags(&mut self, name: &str, tags: &[String]) {
+        self.tags.insert(String::from(name), tags.to_vec());
     }
 
-    pub fn get_single_rect(&self, name: &str) -> WorldLocationRect {
-        if let Some(vec) = self.positions.get(name) {
-            vec[0]
-        } else {
-            WorldLocationRect {
-                position: Vec2::ZERO,
-                size: Vec2::ONE,
-            }
-        }
+    pub fn has_tag(&self, name: &str, tag: &str) -> bool {
+        self.tags
+            .get(name)
+            .map_or(false, |tags| tags.iter().any(|i| i == tag))
     }
 
-    pub fn get_multiple_positions(&self, name: &str) -> Vec<Vec2> {
-        if let Some(vec) = self.positions.get(name) {
-            vec.iter().map(|i| i.position).collect()
-        } else {
-            vec![]
+    pub fn require(&mut self, name: &str, count: WorldLocationCount) {
+        self.expected.insert(String::from(name), count);
+    }
+
+    /// Problems with the expected locations, sorted by name.
+    pub fn validate(&self) -> Vec<WorldLocationError> {
+        let mut errors: Vec<WorldLocationError> = self
+            .expected
+            .iter()
+            .filter_map(|(name, count)| {
+                let found = self.multiple(name).len();
+                match count {
+                    WorldLocationCount::Single | WorldLocationCount::Multiple if found == 0 => {
+                        Some(WorldLocationError::Missing(name.clone()))
+                    }
+                    WorldLocationCount::Single | WorldLocationCount::Optional if found > 1 => {
+                        Some(WorldLocationError::Duplicate(name.clone(), found))
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+        errors.sort_by(|a, b| a.name().cmp(b.name()));
+        errors
+    }
+
+    pub fn single(&self, name: &str) -> Result<WorldLocationRect, WorldLocationError> {
+        match self.multiple(name) {
+            [] => Err(WorldLocationError::Missing(String::from(name))),
+            [rect] => Ok(*rect),
+            rects => Err(WorldLocationError::Duplicate(
+                String::from(name),
+                rects.len(),
+            )),
         }
     }
 
-    pub fn get_multiple_rect(&self, name: &str) -> Vec<WorldLocationRect> {
-        if let Some(vec) = self.positions.get(name) {
-            vec.clone()
-        } else {
-            vec![]
+    pub fn single_position(&self, name: &str) -> Result<Vec2, WorldLocationError> {
+        self.single(name).map(|rect| rect.position)
+    }
+
+    pub fn multiple(&self, name: &str) -> &[WorldLocationRect] {
+        self.positions.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn nearest(&self, name: &str, position: Vec2) -> Option<WorldLocationRect> {
+        self.multiple(name).iter().copied().min_by(|a, b| {
+            a.position
+                .distance_squared(position)
+                .total_cmp(&b.position.distance_squared(position))
+        })
+    }
+
+    pub fn nearest_with_tag(&self, tag: &str, position: Vec2) -> Option<(&str, WorldLocationRect)> {
+        self.iter()
+            .filter(|(name, _)| self.has_tag(name, tag))
+            .min_by(|(_, a), (_, b)| {
+                a.position
+                    .distance_squared(position)
+                    .total_cmp(&b.position.distance_squared(position))
+            })
+    }
+
+    /// Locations centered inside the rect between `min` and `max`.
+    pub fn inside_rect(
+        &self,
+        min: Vec2,
+        max: Vec2,
+    ) -> impl Iterator<Item = (&str, WorldLocationRect)> + '_ {
+        self.iter().filter(move |(_, rect)| {
+            rect.position.cmpge(min).all() && rect.position.cmple(max).all()
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, WorldLocationRect)> + '_ {
+        self.positions
+            .iter()
+            .flat_map(|(name, rects)| rects.iter().map(|rect| (name.as_str(), *rect)))
+    }
+}
+
+fn world_locations_validate(
+    mut ev_spawn: EventReader<WorldLocationsSpawnEvent>,
+    world_locations: Res<WorldLocations>,
+) {
+    for _ in ev_spawn.iter() {
+        for error in world_locations.validate() {
+            error!("{}", error);
         }
     }
 }
+
+fn world_locations_debug(
+    mut egui_query: Query<&mut EguiContext>,
+    mut menu_bar: ResMut<MenuBar>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    world_locations: Res<WorldLocations>,
+) {
+    menu_bar.item("World Locations", |open| {
+        if !*open {
+            return;
+        }
+        let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
+        let Some((camera, camera_transform)) = camera_query.iter().next() else { return };
+        let egui_context = egui_context.get_mut();
+        let painter = egui_context.layer_painter(egui::LayerId::new(
+            egui::Order::Background,
+            egui::Id::new("world_locations"),
+        ));
+        let to_screen = |position: Vec2| {
+            camera
+                .world_to_viewport(camera_transform, position.extend(0.))
+                .map(|position| egui::pos2(position.x, position.y))
+        };
+        for (name, rect) in world_locations.iter() {
+            let corners = (
+                to_screen(rect.position + rect.size * Vec2::new(-0.5, 0.5)),
+                to_screen(rect.position + rect.size * Vec2::new(0.5, -0.5)),
+            );
+            let (Some(min), Some(max)) = corners else { continue };
+            painter.rect_stroke(
+                egui::Rect::from_min_max(min, max),
+                0.,
+                egui::Stroke::new(1., egui::Color32::YELLOW),
+            );
+            painter.text(
+                min,
+                egui::Align2::LEFT_TOP,
+                name,
+                egui::FontId::monospace(12.),
+                egui::Color32::YELLOW,
+            );
+        }
+        egui::Window::new("World Locations")
+            .open(open)
+            .show(egui_context, |ui| {
+                let errors = world_locations.validate();
+                if errors.is_empty() {
+                    ui.label("All expected locations found");
+                }
+                for error in errors {
+                    ui.colored_label(egui::Color32::RED, error.to_string());
+                }
+            });
+    });
+}
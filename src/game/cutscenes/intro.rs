@@ -102,14 +102,13 @@ fn init(
 
 fn skip(
     mut cutscene_state: ResMut<IntroCutsceneState>,
-    input: Res<Input<KeyCode>>,
-    mouse: Res<Input<MouseButton>>,
+    actions: Res<Actions>,
     mut screen_fade: ResMut<ScreenFade>,
     mut ev_cutscene_skip: EventWriter<CutsceneSkipEvent<IntroCutscene>>,
     mut query: Query<&mut AudioPlusSource>,
     mut text_query: Query<&mut Text, With<CutsceneText>>,
 ) {
-    if input.just_pressed(KeyCode::Space) || mouse.just_pressed(MouseButton::Left) {
+    if actions.just_pressed(Action::Advance) {
         if !cutscene_state.proceed {
             if let Ok(mut text) = text_query.get_single_mut() {
                 text.sections[0].value = "".to_owned();
@@ -89,14 +89,13 @@ fn init(
 
 fn skip(
     mut cutscene_state: ResMut<OutroCutsceneState>,
-    input: Res<Input<KeyCode>>,
-    mouse: Res<Input<MouseButton>>,
+    actions: Res<Actions>,
     mut screen_fade: ResMut<ScreenFade>,
     mut ev_cutscene_skip: EventWriter<CutsceneSkipEvent<OutroCutscene>>,
     mut query: Query<&mut AudioPlusSource>,
     mut text_query: Query<&mut Text, With<CutsceneText>>,
 ) {
-    if input.just_pressed(KeyCode::Space) || mouse.just_pressed(MouseButton::Left) {
+    if actions.just_pressed(Action::Advance) {
         if !cutscene_state.proceed {
             if let Ok(mut text) = text_query.get_single_mut() {
                 text.sections[0].value = "".to_owned();
@@ -262,6 +261,9 @@ fn cleanup(
     mut game_state: ResMut<GameState>,
     world_locations: Res<WorldLocations>,
 ) {
-    game_state.town = TownData::build("Republic of Roll", world_locations.as_ref());
+    match TownData::build("Republic of Roll", world_locations.as_ref()) {
+        Ok(town) => game_state.town = town,
+        Err(error) => error!("{}", error),
+    }
     app_state.set(AppState::TownOutside);
 }
@@ -1,7 +1,8 @@
 use crate::common::prelude::*;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum BandMember {
     Guitar,
     Drums,
@@ -36,6 +37,16 @@ impl BandMember {
         }
     }
 
+    pub fn id(&self) -> &'static str {
+        match *self {
+            Self::Guitar => "guitar",
+            Self::Drums => "drums",
+            Self::Flute => "flute",
+            Self::Accordion => "accordion",
+            Self::Harmonica => "harmonica",
+        }
+    }
+
     pub fn selection_active_image(&self, asset_library: &AssetLibrary) -> Handle<Image> {
         match *self {
             Self::Guitar => asset_library
@@ -1,5 +1,6 @@
 use crate::common::prelude::*;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub const TOWN_NAMES: [&str; 15] = [
     "Portallica",
@@ -23,7 +24,11 @@ pub fn town_safe_name(input: &str) -> String {
     input.replace(" ", "_").replace("'", "_")
 }
 
-#[derive(Clone, Debug)]
+pub fn town_name_key(input: &str) -> String {
+    format!("town.{}", town_safe_name(input).to_lowercase())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TownData {
     pub name: String,
     pub position: Vec2,
@@ -41,13 +46,16 @@ impl Default for TownData {
 }
 
 impl TownData {
-    pub fn build(name: &str, world_locations: &WorldLocations) -> Self {
-        let town_name = town_safe_name(name);
-        let position = world_locations.get_single_position(&town_name);
-        Self {
+    pub fn name_key(&self) -> String {
+        town_name_key(&self.name)
+    }
+
+    pub fn build(name: &str, world_locations: &WorldLocations) -> Result<Self, WorldLocationError> {
+        let position = world_locations.single_position(&town_safe_name(name))?;
+        Ok(Self {
             name: String::from(name),
             position,
             spawn_offset: Vec2::new(0., -300.),
-        }
+        })
     }
 }
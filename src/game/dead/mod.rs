@@ -65,14 +65,11 @@ fn town_init(
 
 fn town_update(
     mut state: ResMut<DeadState>,
-    keys: Res<Input<KeyCode>>,
-    mouse: Res<Input<MouseButton>>,
+    actions: Res<Actions>,
     mut app_state: ResMut<NextState<AppState>>,
     mut screen_fade: ResMut<ScreenFade>,
 ) {
-    if !state.can_leave
-        && (keys.just_pressed(KeyCode::Space) || mouse.just_pressed(MouseButton::Left))
-    {
+    if !state.can_leave && actions.just_pressed(Action::Advance) {
         state.can_leave = true;
         screen_fade.fade_out(0.5);
     }
@@ -0,0 +1,72 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+pub struct DialogueTreePlugin;
+
+impl Plugin for DialogueTreePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, dialogue_tree_resolve.before(DialogueSystem::Update));
+    }
+}
+
+impl DialogueContext for GameState {
+    fn check(&self, condition: &DialogueCondition) -> bool {
+        match condition {
+            DialogueCondition::Quest(name) => self.quests.active_quest.name() == name,
+            DialogueCondition::QuestStage(name, stage) => {
+                self.quests.active_quest.name() == name
+                    && self.quests.active_quest.stage_name() == Some(stage.as_str())
+            }
+            DialogueCondition::LevelAtLeast(level) => self.level >= *level,
+            DialogueCondition::BandMember(id) => {
+                self.band_members.iter().any(|member| member.id() == id)
+            }
+            DialogueCondition::Flag(flag) => self.dialogue_flags.contains(flag),
+            DialogueCondition::Counter(name, modulo, value) => {
+                let counter = self.dialogue_counters.get(name).copied().unwrap_or(0);
+                counter % (*modulo).max(1) == *value
+            }
+            DialogueCondition::Not(condition) => !self.check(condition),
+            DialogueCondition::All(conditions) => {
+                conditions.iter().all(|condition| self.check(condition))
+            }
+            DialogueCondition::Any(conditions) => {
+                conditions.iter().any(|condition| self.check(condition))
+            }
+        }
+    }
+
+    fn apply(&mut self, effect: &DialogueEffect) {
+        match effect {
+            DialogueEffect::SetFlag(flag) => {
+                self.dialogue_flags.insert(flag.clone());
+            }
+            DialogueEffect::ClearFlag(flag) => {
+                self.dialogue_flags.remove(flag);
+            }
+            DialogueEffect::IncrementCounter(name) => {
+                *self.dialogue_counters.entry(name.clone()).or_insert(0) += 1;
+            }
+            DialogueEffect::GrantExperience(amount) => {
+                if self.add_experience(*amount) {
+                    self.skill_points += 1;
+                }
+            }
+        }
+    }
+}
+
+fn dialogue_tree_resolve(
+    mut dialogue: ResMut<Dialogue>,
+    mut game_state: ResMut<GameState>,
+    mut ev_level_up: EventWriter<LevelUpSpawnEvent>,
+) {
+    if dialogue.visible() {
+        let level = game_state.level;
+        dialogue.resolve_logic(game_state.as_mut());
+        if game_state.level > level {
+            ev_level_up.send_default();
+        }
+    }
+}
@@ -10,16 +10,20 @@ impl Plugin for GamePlugin {
             town::TownPlugin,
             quests::QuestsPlugin,
             dead::DeadPlugin,
+            dialogue_tree::DialogueTreePlugin,
+            music::MusicPlugin,
         ));
     }
 }
 
-pub mod all_dialogue;
 pub mod cutscenes;
 pub mod data;
 pub mod dead;
+pub mod dialogue_tree;
+pub mod music;
 pub mod overworld;
 pub mod prelude;
 pub mod quests;
+pub mod save;
 pub mod state;
 pub mod town;
@@ -0,0 +1,131 @@
+use crate::{
+    common::prelude::*,
+    game::{prelude::*, state::GameState},
+};
+use audio_plus::{prelude::*, AudioPlusSystem};
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContext};
+
+const MUSIC_BPM: f32 = 120.;
+const MUSIC_BEATS_PER_BAR: u32 = 4;
+
+const MUSIC_LAYER_EXPLORE: &str = "explore";
+const MUSIC_LAYER_DANGER: &str = "danger";
+const MUSIC_LAYER_BOSS: &str = "boss";
+const MUSIC_LAYER_TOWN: &str = "town";
+
+const MUSIC_STATE_EXPLORE: &str = "explore";
+const MUSIC_STATE_DANGER: &str = "danger";
+const MUSIC_STATE_BOSS: &str = "boss";
+const MUSIC_STATE_TOWN: &str = "town";
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(AppState::Loading), music_setup)
+            .add_systems(
+                Update,
+                (
+                    music_update.before(AudioPlusSystem::UpdateMusic),
+                    music_persistent,
+                    music_debug,
+                ),
+            );
+    }
+}
+
+fn music_setup(mut director: ResMut<AudioPlusMusicDirector>, asset_library: Res<AssetLibrary>) {
+    let sound_effects = &asset_library.sound_effects;
+    director.clear();
+    director.set_tempo(AudioPlusTempoMap::new(MUSIC_BPM, MUSIC_BEATS_PER_BAR));
+    director.set_quantize(AudioPlusQuantize::Bar);
+    director.add_layer(
+        MUSIC_LAYER_EXPLORE,
+        sound_effects.sfx_overworld_music.clone(),
+    );
+    director.add_layer(
+        MUSIC_LAYER_DANGER,
+        sound_effects.sfx_overworld_music_danger.clone(),
+    );
+    director.add_layer(
+        MUSIC_LAYER_BOSS,
+        sound_effects.sfx_overworld_music_boss.clone(),
+    );
+    director.add_layer(MUSIC_LAYER_TOWN, sound_effects.sfx_town_music.clone());
+    director.add_state(MUSIC_STATE_EXPLORE, &[MUSIC_LAYER_EXPLORE]);
+    director.add_state(
+        MUSIC_STATE_DANGER,
+        &[MUSIC_LAYER_EXPLORE, MUSIC_LAYER_DANGER],
+    );
+    director.add_state(
+        MUSIC_STATE_BOSS,
+        &[MUSIC_LAYER_EXPLORE, MUSIC_LAYER_DANGER, MUSIC_LAYER_BOSS],
+    );
+    director.add_state(MUSIC_STATE_TOWN, &[MUSIC_LAYER_TOWN]);
+}
+
+fn music_update(
+    mut director: ResMut<AudioPlusMusicDirector>,
+    app_state: Res<State<AppState>>,
+    threat_level: Res<ThreatLevel>,
+    game_state: Res<GameState>,
+) {
+    let state = if app_state.get().is_town() {
+        Some(MUSIC_STATE_TOWN)
+    } else if *app_state.get() == AppState::Overworld {
+        if game_state.quests.fighting() {
+            Some(MUSIC_STATE_BOSS)
+        } else {
+            match *threat_level {
+                ThreatLevel::None | ThreatLevel::Easy | ThreatLevel::Medium => {
+                    Some(MUSIC_STATE_EXPLORE)
+                }
+                ThreatLevel::Hard | ThreatLevel::Midnight | ThreatLevel::Davy => {
+                    Some(MUSIC_STATE_DANGER)
+                }
+            }
+        }
+    } else {
+        None
+    };
+    director.set_state(state);
+}
+
+fn music_persistent(mut commands: Commands, query: Query<Entity, Added<AudioPlusMusicLayer>>) {
+    for entity in query.iter() {
+        commands.entity(entity).insert(Persistent);
+    }
+}
+
+fn music_debug(
+    mut egui_query: Query<&mut EguiContext>,
+    mut menu_bar: ResMut<MenuBar>,
+    director: Res<AudioPlusMusicDirector>,
+) {
+    menu_bar.item("Music", |open| {
+        let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
+        egui::Window::new("Music")
+            .open(open)
+            .show(egui_context.get_mut(), |ui| {
+                let (bar, beat) = director.tempo().bar_and_beat(director.time());
+                ui.label(format!("State: {:?}", director.state()));
+                ui.label(format!("Next State: {:?}", director.next_state()));
+                if let Some(transition_at) = director.transition_at() {
+                    ui.label(format!(
+                        "Transition In: {:.2}s",
+                        transition_at - director.time()
+                    ));
+                }
+                ui.label(format!("Bar: {} Beat: {}", bar + 1, beat + 1));
+                for layer in [
+                    MUSIC_LAYER_EXPLORE,
+                    MUSIC_LAYER_DANGER,
+                    MUSIC_LAYER_BOSS,
+                    MUSIC_LAYER_TOWN,
+                ] {
+                    ui.label(format!("{}: {:.2}", layer, director.layer_gain(layer)));
+                }
+            });
+    });
+}
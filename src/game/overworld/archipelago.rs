@@ -0,0 +1,527 @@
+use crate::common::{
+    ldtk::spawn_grid_collisions,
+    prelude::*,
+    tilemap::{spawn_tile_chunks, TilemapTile, Tileset},
+};
+use crate::game::data::town_data::{town_safe_name, TOWN_NAMES};
+use bevy::{prelude::*, utils::HashSet};
+use grid_combiner::{GridCombiner, GridPoint};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Cells of the generated world, including the land border around it.
+const ARCHIPELAGO_MIN: IVec2 = IVec2::new(-4, -126);
+const ARCHIPELAGO_MAX: IVec2 = IVec2::new(125, 5);
+const ARCHIPELAGO_ISLANDS: usize = 70;
+const ARCHIPELAGO_EXTRA_TOWNS: usize = 5;
+const ARCHIPELAGO_TOWN_SPACING: f32 = 1800.;
+const ARCHIPELAGO_PLACEMENT_ATTEMPTS: usize = 200;
+
+/// The start of the game plays out the same in every world, so the basin around it, Portallica
+/// and the Jagerossa arena are copied from the hand-made level.
+const ARCHIPELAGO_START: Vec2 = Vec2::new(800., -500.);
+const ARCHIPELAGO_START_BASIN: (IVec2, IVec2) = (IVec2::new(1, -17), IVec2::new(27, -1));
+const ARCHIPELAGO_START_LOCATIONS: [(&str, Vec2, Vec2); 10] = [
+    ("GameStart", Vec2::new(800., -500.), Vec2::new(100., 100.)),
+    ("Rubble", Vec2::new(1200., -700.), Vec2::new(100., 100.)),
+    ("Rubble", Vec2::new(1100., -800.), Vec2::new(100., 100.)),
+    (
+        "JagerossaArena",
+        Vec2::new(2050., -1050.),
+        Vec2::new(1400., 900.),
+    ),
+    (
+        "JagerossaTrigger",
+        Vec2::new(1438., -1048.),
+        Vec2::new(416., 100.),
+    ),
+    (
+        "JagerossaTrigger",
+        Vec2::new(1696., -898.),
+        Vec2::new(100., 304.),
+    ),
+    (
+        "JagerossaMoveTo",
+        Vec2::new(2050., -800.),
+        Vec2::new(100., 100.),
+    ),
+    (
+        "JagerossaSpawn",
+        Vec2::new(2500., -850.),
+        Vec2::new(100., 100.),
+    ),
+    (
+        "DangerousSeasTrigger",
+        Vec2::new(1646., -1704.),
+        Vec2::new(1600., 100.),
+    ),
+    (
+        "DangerousSeasTrigger",
+        Vec2::new(2496., -998.),
+        Vec2::new(100., 1504.),
+    ),
+];
+const ARCHIPELAGO_START_TOWN: (&str, Vec2) = ("Portallica", Vec2::new(1900., -600.));
+
+struct ArchipelagoQuest {
+    name: &'static str,
+    town: &'static str,
+    distance: (f32, f32),
+    size: (Vec2, Vec2),
+    /// Where the arena is in the hand-made level, used if no random spot fits.
+    fallback: Vec2,
+}
+
+const ARCHIPELAGO_QUESTS: [ArchipelagoQuest; 3] = [
+    ArchipelagoQuest {
+        name: "Ringo",
+        town: "Isla de Dio",
+        distance: (5000., 8000.),
+        size: (Vec2::new(1600., 1100.), Vec2::new(2200., 1400.)),
+        fallback: Vec2::new(7800., -1300.),
+    },
+    ArchipelagoQuest {
+        name: "Plank",
+        town: "Iron Maiden's Cove",
+        distance: (8000., 10500.),
+        size: (Vec2::new(1800., 1200.), Vec2::new(2400., 1500.)),
+        fallback: Vec2::new(7000., -7100.),
+    },
+    ArchipelagoQuest {
+        name: "Davy",
+        town: "Republic of Roll",
+        distance: (10000., 12500.),
+        size: (Vec2::new(2800., 1500.), Vec2::new(3400., 1800.)),
+        fallback: Vec2::new(2300., -11200.),
+    },
+];
+
+/// Threat levels by distance from the start, outside of the start region.
+const ARCHIPELAGO_THREAT_LEVELS: [(&str, f32); 4] = [
+    ("ThreatLevelEasy", 5500.),
+    ("ThreatLevelMedium", 8000.),
+    ("ThreatLevelHard", 10500.),
+    ("ThreatLevelMidnight", f32::INFINITY),
+];
+const ARCHIPELAGO_THREAT_LEVEL_SIZE: f32 = 2000.;
+const ARCHIPELAGO_THREAT_LEVEL_SAFE_DISTANCE: f32 = 3000.;
+
+pub struct ArchipelagoPlugin;
+
+impl Plugin for ArchipelagoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ArchipelagoSpawnEvent>()
+            .add_systems(Update, archipelago_spawn);
+    }
+}
+
+/// Generates a world from the seed and spawns it in place of the hand-made level.
+#[derive(Event, Clone, Copy)]
+pub struct ArchipelagoSpawnEvent {
+    pub seed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArchipelagoLocation {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub center: Vec2,
+    pub size: Vec2,
+}
+
+/// A generated world. The same seed always generates the same world.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Archipelago {
+    /// Land cells, where cell `(x, y)` is the tile centered at `(x, y) * 100`.
+    pub land: HashSet<IVec2>,
+    pub locations: Vec<ArchipelagoLocation>,
+    pub towns: Vec<(&'static str, Vec2)>,
+}
+
+impl Archipelago {
+    pub fn generate(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut archipelago = Self::default();
+        let mut water = vec![ARCHIPELAGO_START_BASIN];
+        let mut arenas = vec![];
+
+        for (name, center, size) in ARCHIPELAGO_START_LOCATIONS {
+            let tags: &[&str] = if name == "JagerossaArena" {
+                arenas.push((center, size));
+                &[WORLD_LOCATION_TAG_ARENA]
+            } else {
+                &[]
+            };
+            archipelago.add_location(name, tags, center, size);
+        }
+        archipelago.add_town(
+            ARCHIPELAGO_START_TOWN.0,
+            ARCHIPELAGO_START_TOWN.1,
+            &mut water,
+        );
+
+        for quest in ARCHIPELAGO_QUESTS.iter() {
+            let (center, size) = (0..ARCHIPELAGO_PLACEMENT_ATTEMPTS)
+                .map(|_| {
+                    let angle = rng.gen_range(-std::f32::consts::FRAC_PI_2..0.);
+                    let distance = rng.gen_range(quest.distance.0..quest.distance.1);
+                    let size = Vec2::new(
+                        rng.gen_range(quest.size.0.x..=quest.size.1.x),
+                        rng.gen_range(quest.size.0.y..=quest.size.1.y),
+                    );
+                    let center = ARCHIPELAGO_START + Vec2::from_angle(angle) * distance;
+                    ((center / 100.).round() * 100., (size / 100.).round() * 100.)
+                })
+                .find(|(center, size)| {
+                    let town = *center + Vec2::new(0., size.y * 0.5 + 400.);
+                    let (min, max) = (*center - *size * 0.5, *center + *size * 0.5);
+                    min.x > 600.
+                        && max.x < 11400.
+                        && min.y > -11800.
+                        && town.y < -400.
+                        && !archipelago.near_start(*center, *size, 1000.)
+                        && !arenas.iter().any(|(other_center, other_size)| {
+                            rect_overlaps(
+                                *center,
+                                *size + 1000.,
+                                *other_center,
+                                *other_size + 1000.,
+                            )
+                        })
+                })
+                .unwrap_or((quest.fallback, (quest.size.0 + quest.size.1) * 0.5));
+            arenas.push((center, size));
+            archipelago.add_location(
+                &format!("{}Arena", quest.name),
+                &[WORLD_LOCATION_TAG_ARENA],
+                center,
+                size,
+            );
+            archipelago.add_location(
+                &format!("{}Trigger", quest.name),
+                &[],
+                center + Vec2::new(size.x * -0.5 + 100., 0.),
+                Vec2::new(100., size.y * 0.6),
+            );
+            archipelago.add_location(
+                &format!("{}MoveTo", quest.name),
+                &[],
+                center + Vec2::new(size.x * 0.1, size.y * 0.2),
+                Vec2::new(100., 100.),
+            );
+            archipelago.add_location(
+                &format!("{}Spawn", quest.name),
+                &[],
+                center + Vec2::new(size.x * 0.5 - 200., size.y * 0.2),
+                Vec2::new(100., 100.),
+            );
+            archipelago.add_town(
+                quest.town,
+                center + Vec2::new(0., size.y * 0.5 + 400.),
+                &mut water,
+            );
+        }
+
+        let mut extra_towns: Vec<&str> = TOWN_NAMES
+            .iter()
+            .copied()
+            .filter(|name| {
+                *name != ARCHIPELAGO_START_TOWN.0
+                    && !ARCHIPELAGO_QUESTS.iter().any(|quest| quest.town == *name)
+            })
+            .collect();
+        extra_towns.shuffle(&mut rng);
+        for name in extra_towns.into_iter().take(ARCHIPELAGO_EXTRA_TOWNS) {
+            let position = (0..ARCHIPELAGO_PLACEMENT_ATTEMPTS)
+                .map(|_| {
+                    Vec2::new(
+                        rng.gen_range(6..114) as f32 * 100.,
+                        rng.gen_range(-114..-6) as f32 * 100.,
+                    )
+                })
+                .find(|position| {
+                    !archipelago.near_start(*position, Vec2::ZERO, 1000.)
+                        && archipelago
+                            .towns
+                            .iter()
+                            .all(|(_, other)| position.distance(*other) > ARCHIPELAGO_TOWN_SPACING)
+                        && !arenas.iter().any(|(center, size)| {
+                            rect_overlaps(*position, Vec2::ZERO, *center, *size + 1200.)
+                        })
+                });
+            if let Some(position) = position {
+                archipelago.add_town(name, position, &mut water);
+            }
+        }
+
+        archipelago.add_border(&mut rng);
+        for _ in 0..ARCHIPELAGO_ISLANDS {
+            let center = IVec2::new(
+                rng.gen_range(ARCHIPELAGO_MIN.x..ARCHIPELAGO_MAX.x),
+                rng.gen_range(ARCHIPELAGO_MIN.y..ARCHIPELAGO_MAX.y),
+            );
+            archipelago.add_island(center, &mut rng);
+        }
+        let towns = archipelago.towns.clone();
+        for (_, position) in towns.iter() {
+            let cell = (*position / 100.).round().as_ivec2();
+            archipelago.add_island(cell + IVec2::new(0, 3), &mut rng);
+        }
+
+        for (center, size) in arenas.iter() {
+            let (min, max) = (*center - *size * 0.5, *center + *size * 0.5);
+            water.push((
+                (min / 100.).floor().as_ivec2() - 1,
+                (max / 100.).ceil().as_ivec2() + 1,
+            ));
+        }
+        for (min, max) in water.iter() {
+            archipelago
+                .land
+                .retain(|cell| cell.cmplt(*min).any() || cell.cmpgt(*max).any());
+        }
+        for (_, position) in towns.iter() {
+            let cell = (*position / 100.).round().as_ivec2();
+            for x in -1..=1 {
+                for y in 0..=1 {
+                    archipelago.land.insert(cell + IVec2::new(x, y));
+                }
+            }
+        }
+        archipelago.remove_thin_land();
+
+        archipelago.add_threat_levels();
+
+        archipelago
+    }
+
+    /// Tile index in the island tileset for a land cell, and whether it is flipped horizontally.
+    pub fn island_tile(&self, cell: IVec2) -> (i64, bool) {
+        let land = |x: i32, y: i32| self.land.contains(&(cell + IVec2::new(x, y)));
+        let (top, bottom, left, right) = (land(0, 1), land(0, -1), land(-1, 0), land(1, 0));
+        if !top {
+            if !left {
+                (0, false)
+            } else if !right {
+                (2, false)
+            } else {
+                (1, false)
+            }
+        } else if !bottom {
+            if !left {
+                (18, false)
+            } else if !right {
+                (20, false)
+            } else {
+                (19, false)
+            }
+        } else if !left {
+            (9, false)
+        } else if !right {
+            (11, false)
+        } else if !land(1, 1) {
+            (24, false)
+        } else if !land(-1, 1) {
+            (24, true)
+        } else if !land(1, -1) {
+            (6, false)
+        } else if !land(-1, -1) {
+            (6, true)
+        } else {
+            (10, false)
+        }
+    }
+
+    fn add_location(&mut self, name: &str, tags: &[&str], center: Vec2, size: Vec2) {
+        self.locations.push(ArchipelagoLocation {
+            name: String::from(name),
+            tags: tags.iter().map(|tag| String::from(*tag)).collect(),
+            center,
+            size,
+        });
+    }
+
+    /// Towns sit on the south coast of an island, with open water below where the player leaves
+    /// town.
+    fn add_town(&mut self, name: &'static str, position: Vec2, water: &mut Vec<(IVec2, IVec2)>) {
+        let cell = (position / 100.).round().as_ivec2();
+        let position = cell.as_vec2() * 100.;
+        self.add_location(
+            &town_safe_name(name),
+            &[WORLD_LOCATION_TAG_TOWN],
+            position,
+            Vec2::new(100., 100.),
+        );
+        self.towns.push((name, position));
+        water.push((cell + IVec2::new(-2, -4), cell + IVec2::new(2, -1)));
+    }
+
+    fn near_start(&self, center: Vec2, size: Vec2, padding: f32) -> bool {
+        let (min, max) = ARCHIPELAGO_START_BASIN;
+        let (min, max) = (min.as_vec2() * 100., max.as_vec2() * 100.);
+        rect_overlaps(
+            center,
+            size + padding,
+            (min + max) * 0.5,
+            max - min + padding,
+        )
+    }
+
+    fn add_border(&mut self, rng: &mut StdRng) {
+        let thickness =
+            |rng: &mut StdRng, current: i32| (current + rng.gen_range(-1..=1)).clamp(3, 8);
+        let (mut top, mut bottom) = (5, 5);
+        for x in ARCHIPELAGO_MIN.x..=ARCHIPELAGO_MAX.x {
+            top = thickness(rng, top);
+            bottom = thickness(rng, bottom);
+            for y in 0..top {
+                self.land.insert(IVec2::new(x, ARCHIPELAGO_MAX.y - y));
+            }
+            for y in 0..bottom {
+                self.land.insert(IVec2::new(x, ARCHIPELAGO_MIN.y + y));
+            }
+        }
+        let (mut left, mut right) = (5, 5);
+        for y in ARCHIPELAGO_MIN.y..=ARCHIPELAGO_MAX.y {
+            left = thickness(rng, left);
+            right = thickness(rng, right);
+            for x in 0..left {
+                self.land.insert(IVec2::new(ARCHIPELAGO_MIN.x + x, y));
+            }
+            for x in 0..right {
+                self.land.insert(IVec2::new(ARCHIPELAGO_MAX.x - x, y));
+            }
+        }
+    }
+
+    /// Adds a blob of a few overlapping ellipses.
+    fn add_island(&mut self, center: IVec2, rng: &mut StdRng) {
+        for _ in 0..rng.gen_range(1..=3) {
+            let offset = IVec2::new(rng.gen_range(-3..=3), rng.gen_range(-3..=3));
+            let radius = Vec2::new(rng.gen_range(2.0..6.5), rng.gen_range(2.0..5.5));
+            let extent = radius.ceil().as_ivec2();
+            for x in -extent.x..=extent.x {
+                for y in -extent.y..=extent.y {
+                    if (Vec2::new(x as f32, y as f32) / radius).length_squared() <= 1. {
+                        self.land.insert(center + offset + IVec2::new(x, y));
+                    }
+                }
+            }
+        }
+    }
+
+    /// The island tileset has no tiles for land one cell wide, so those cells are removed.
+    fn remove_thin_land(&mut self) {
+        loop {
+            let thin: Vec<IVec2> = self
+                .land
+                .iter()
+                .copied()
+                .filter(|cell| {
+                    let land = |x: i32, y: i32| self.land.contains(&(*cell + IVec2::new(x, y)));
+                    (!land(-1, 0) && !land(1, 0)) || (!land(0, -1) && !land(0, 1))
+                })
+                .collect();
+            if thin.is_empty() {
+                break;
+            }
+            for cell in thin {
+                self.land.remove(&cell);
+            }
+        }
+    }
+
+    fn add_threat_levels(&mut self) {
+        let size = Vec2::splat(ARCHIPELAGO_THREAT_LEVEL_SIZE);
+        let origin = Vec2::new(ARCHIPELAGO_MIN.x as f32, ARCHIPELAGO_MAX.y as f32) * 100.
+            + Vec2::new(size.x, -size.y) * 0.5;
+        let count = ((ARCHIPELAGO_MAX - ARCHIPELAGO_MIN).as_vec2() * 100. / size)
+            .ceil()
+            .as_ivec2();
+        for x in 0..count.x {
+            for y in 0..count.y {
+                let center = origin + Vec2::new(x as f32, -y as f32) * size;
+                let distance = center.distance(ARCHIPELAGO_START);
+                if distance < ARCHIPELAGO_THREAT_LEVEL_SAFE_DISTANCE {
+                    continue;
+                }
+                let Some((name, _)) = ARCHIPELAGO_THREAT_LEVELS
+                    .iter()
+                    .find(|(_, max_distance)| distance < *max_distance)
+                else {
+                    continue;
+                };
+                self.add_location(name, &[WORLD_LOCATION_TAG_THREAT_LEVEL], center, size);
+            }
+        }
+    }
+}
+
+fn rect_overlaps(a_center: Vec2, a_size: Vec2, b_center: Vec2, b_size: Vec2) -> bool {
+    let distance = (a_center - b_center).abs();
+    distance.x < (a_size.x + b_size.x) * 0.5 && distance.y < (a_size.y + b_size.y) * 0.5
+}
+
+fn archipelago_spawn(
+    mut ev_spawn: EventReader<ArchipelagoSpawnEvent>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut color_materials: ResMut<Assets<ColorMaterial>>,
+    asset_library: Res<AssetLibrary>,
+    mut ev_world_locations_spawn: EventWriter<WorldLocationsSpawnEvent>,
+    mut world_locations: ResMut<WorldLocations>,
+    mut map_builder: ResMut<MapBuilder>,
+    mut nav_grid: ResMut<NavGrid>,
+) {
+    for event in ev_spawn.iter() {
+        let archipelago = Archipelago::generate(event.seed);
+        let mut grid_combiner = GridCombiner::new();
+        world_locations.clear();
+        map_builder.reset();
+        nav_grid.reset();
+        for cell in archipelago.land.iter() {
+            grid_combiner.add_point(GridPoint::new(cell.x as i64, cell.y as i64));
+            nav_grid.block(*cell);
+            map_builder.add_tile(NavGrid::cell_to_world(*cell));
+        }
+        spawn_grid_collisions(&grid_combiner, &mut commands);
+        for location in archipelago.locations.iter() {
+            world_locations.add(
+                &location.name,
+                location.center
+                    + Vec2::new(location.size.x * -0.5 + 50., location.size.y * 0.5 - 50.),
+                location.size,
+            );
+            world_locations.set_tags(&location.name, &location.tags);
+        }
+        for (name, position) in archipelago.towns.iter() {
+            map_builder.add_label(*position, name);
+        }
+        let tileset = Tileset::new(
+            color_materials.add(ColorMaterial::from(
+                asset_library.levelisland_tilemap.clone(),
+            )),
+            Vec2::new(900., 600.),
+            100,
+        );
+        let tiles = archipelago.land.iter().map(|cell| {
+            let (index, flip_x) = archipelago.island_tile(*cell);
+            TilemapTile {
+                position: NavGrid::cell_to_world(*cell),
+                index,
+                flip_x,
+                flip_y: false,
+            }
+        });
+        let chunk_entities = spawn_tile_chunks(tiles, &tileset, &mut meshes, &mut commands);
+        commands
+            .spawn((
+                Transform2Bundle {
+                    transform2: Transform2::new().with_depth((DepthLayer::Environment, 0.45)),
+                    ..Default::default()
+                },
+                VisibilityBundle::default(),
+            ))
+            .push_children(&chunk_entities);
+        ev_world_locations_spawn.send_default();
+    }
+}
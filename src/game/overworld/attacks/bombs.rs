@@ -67,6 +67,7 @@ fn bombs_fire(
     mut query: Query<(Entity, &mut Bombs, &Boat, &GlobalTransform)>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     for (boat_entity, mut bombs, boat, global_transform) in query.iter_mut() {
         if bombs.shoot {
@@ -88,13 +89,13 @@ fn bombs_fire(
                 TimeToLive { seconds: 3. },
             ));
             for _ in 0..stats.spawn_amount {
-                let time_to_live = 1.5 + rand::random::<f32>() * 0.35;
-                let throw_direction =
-                    Vec2::from_angle(rand::random::<f32>() * std::f32::consts::TAU);
+                let time_to_live = 1.5 + game_rng.f32(RngStream::Attacks) * 0.35;
+                let throw_direction = game_rng.direction(RngStream::Attacks);
                 let position = global_transform.translation().truncate() + throw_direction * 100.;
                 let velocity = throw_direction
                     * (stats.velocity_min
-                        + rand::random::<f32>() * (stats.velocity_max - stats.velocity_min))
+                        + game_rng.f32(RngStream::Attacks)
+                            * (stats.velocity_max - stats.velocity_min))
                     + boat.movement.clamp(Vec2::NEG_ONE, Vec2::ONE) * 150.;
                 commands.spawn((
                     SpriteSheetBundle {
@@ -71,6 +71,7 @@ fn kraken_fire(
     mut query: Query<(Entity, &mut Kraken, &GlobalTransform)>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     for (boat_entity, mut kraken, global_transform) in query.iter_mut() {
         if kraken.shoot {
@@ -101,10 +102,11 @@ fn kraken_fire(
                         stats.far_tentacle_distance_max,
                     )
                 };
-                let forward = Vec2::from_angle(rand::random::<f32>() * std::f32::consts::TAU);
+                let forward = game_rng.direction(RngStream::Attacks);
                 let position = global_transform.translation().truncate()
                     + forward
-                        * (distance_min + rand::random::<f32>() * (distance_max - distance_min));
+                        * (distance_min
+                            + game_rng.f32(RngStream::Attacks) * (distance_max - distance_min));
                 let (scale, _, _) = global_transform.to_scale_rotation_translation();
                 let submerge_time = if close_tentacle { 0. } else { 1.0 };
                 commands.spawn((
@@ -137,6 +139,7 @@ fn tentacle_update(
     time: Res<Time>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     for (entity, mut tentacle, global_transform) in query.iter_mut() {
         tentacle.submerge_time -= time.delta_seconds();
@@ -145,7 +148,7 @@ fn tentacle_update(
             commands.entity(entity).despawn();
         }
         if tentacle.submerge_time <= 0. && !tentacle.spawned_hurtbox {
-            if rand::random() {
+            if game_rng.bool(RngStream::Fx) {
                 commands.spawn((
                     TransformBundle::default(),
                     Transform2::from_translation(global_transform.translation().truncate()),
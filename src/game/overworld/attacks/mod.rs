@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub struct AttacksPlugin;
 
@@ -15,7 +16,7 @@ impl Plugin for AttacksPlugin {
     }
 }
 
-#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub struct Attacks {
     pub forward_cannons: u32,
     pub shotgun_cannons: u32,
@@ -113,8 +113,8 @@ fn shockwave_fire(
                             ..Default::default()
                         },
                         Hurtbox {
-                            shape: CollisionShape::Rect {
-                                size: Vec2::new(400., 400.) * stats.scale,
+                            shape: CollisionShape::Circle {
+                                radius: 200. * stats.scale,
                             },
                             for_entity: Some(entity),
                             auto_despawn: false,
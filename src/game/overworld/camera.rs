@@ -105,6 +105,7 @@ fn overworld_camera_update(
     global_transform_query: Query<&GlobalTransform>,
     time: Res<Time>,
     input: Res<Input<KeyCode>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let player_position = if let Ok(player_entity) = player_query.get_single() {
         if let Ok(player_transform) = transform_query.get(player_entity) {
@@ -162,8 +163,8 @@ fn overworld_camera_update(
         );
         position = position.clamp(WORLD_LIMITS.0, WORLD_LIMITS.1);
         position += Vec2::new(
-            rand::random::<f32>() * 2. - 1.,
-            rand::random::<f32>() * 2. - 1.,
+            game_rng.f32(RngStream::Fx) * 2. - 1.,
+            game_rng.f32(RngStream::Fx) * 2. - 1.,
         ) * overworld_camera.screen_shake
             * 10.;
         overworld_camera.screen_shake *= 0.000001_f32.powf(time.delta_seconds());
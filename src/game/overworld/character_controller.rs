@@ -55,6 +55,7 @@ fn character_controller_update(
             &GlobalTransform,
             Option<&Dash>,
             Option<&CharacterControllerDestination>,
+            Option<&NavigationPath>,
         )>,
         Query<(Entity, &GlobalTransform, &Collision)>,
     )>,
@@ -63,8 +64,8 @@ fn character_controller_update(
     mut commands: Commands,
     overworld_camera: Res<OverworldCamera>,
 ) {
+    collision_query.update(&queries.p1());
     for entity in query.iter() {
-        collision_query.update(&queries.p1());
         if let Ok((
             entity,
             mut character_controller,
@@ -73,6 +74,7 @@ fn character_controller_update(
             global_transform,
             dash,
             destination,
+            navigation_path,
         )) = queries.p0().get_mut(entity)
         {
             let collision_filters = CollisionFilter {
@@ -89,7 +91,13 @@ fn character_controller_update(
             let mut velocity = character_controller.movement;
             character_controller.force_facing = None;
             if let Some(destination) = destination {
-                velocity = (destination.target - global_transform.translation().truncate()) / 200.;
+                let position = global_transform.translation().truncate();
+                let waypoint = navigation_path.and_then(|path| path.waypoint());
+                velocity = if let Some(waypoint) = waypoint {
+                    (waypoint - position).normalize_or_zero()
+                } else {
+                    (destination.target - position) / 200.
+                };
                 if let Some(facing) = Facing::from_vec(velocity) {
                     character_controller.force_facing = Some(facing);
                 }
@@ -23,7 +23,5 @@ impl Cutscene for DangerousSeasCutscene {
 }
 
 fn init1(mut dialogue: ResMut<Dialogue>) {
-    for (p, t) in DANGEROUS_SEAS.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("dangerous_seas");
 }
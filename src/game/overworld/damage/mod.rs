@@ -71,11 +71,15 @@ fn damage_check(
         return;
     }
     for (hurtbox_entity, hurtbox) in hurtbox_query.iter() {
-        let hurtbox_translation = if let Ok(transform) = transform_query.get(hurtbox_entity) {
-            transform.translation().truncate()
-        } else {
-            continue;
-        };
+        let (hurtbox_translation, hurtbox_shape) =
+            if let Ok(transform) = transform_query.get(hurtbox_entity) {
+                (
+                    transform.translation().truncate(),
+                    hurtbox.shape.with_global_rotation(transform),
+                )
+            } else {
+                continue;
+            };
         let hurt = if let Some(proxy_entity) = hurtbox.for_entity {
             proxy_entity
         } else {
@@ -94,15 +98,16 @@ fn damage_check(
             if hurtbox.flags & hitbox.flags == 0 {
                 continue;
             }
-            let hitbox_translation = if let Ok(transform) = transform_query.get(hitbox_entity) {
-                transform.translation().truncate()
-            } else {
-                continue;
-            };
-            if hitbox
-                .shape
-                .overlaps(hitbox_translation, hurtbox.shape, hurtbox_translation)
-            {
+            let (hitbox_translation, hitbox_shape) =
+                if let Ok(transform) = transform_query.get(hitbox_entity) {
+                    (
+                        transform.translation().truncate(),
+                        hitbox.shape.with_global_rotation(transform),
+                    )
+                } else {
+                    continue;
+                };
+            if hitbox_shape.overlaps(hitbox_translation, hurtbox_shape, hurtbox_translation) {
                 match hurtbox.knockback_type {
                     HurtboxKnockbackType::Velocity(force) => {
                         ev_knockback.send(KnockbackEvent {
@@ -1,5 +1,6 @@
 use crate::common::prelude::*;
 use bevy::prelude::*;
+use rand::Rng;
 
 pub struct DamageRumPlugin;
 
@@ -24,14 +25,13 @@ struct DamageRum {
 }
 
 impl DamageRum {
-    fn new() -> Self {
+    fn new(game_rng: &mut GameRng) -> Self {
         Self {
-            velocity: Vec2::from_angle(rand::random::<f32>() * std::f32::consts::TAU) * 550.
-                + rand::random::<f32>() * 250.,
-            angular: (rand::random::<f32>() * 2. - 1.)
-                * (10. + (rand::random::<f32>().powf(2.)) * 40.),
-            scale: 0.7 + rand::random::<f32>() * 0.3,
-            opacity: 0.7 + rand::random::<f32>() * 0.3,
+            velocity: game_rng.direction(RngStream::Fx) * 550. + game_rng.f32(RngStream::Fx) * 250.,
+            angular: (game_rng.f32(RngStream::Fx) * 2. - 1.)
+                * (10. + (game_rng.f32(RngStream::Fx).powf(2.)) * 40.),
+            scale: 0.7 + game_rng.f32(RngStream::Fx) * 0.3,
+            opacity: 0.7 + game_rng.f32(RngStream::Fx) * 0.3,
         }
     }
 }
@@ -40,9 +40,10 @@ fn damage_rum_spawn(
     mut ev_spawn: EventReader<DamageRumSpawnEvent>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     for event in ev_spawn.iter() {
-        let bottles = 2 + rand::random::<u32>() % 5;
+        let bottles = 2 + game_rng.stream(RngStream::Fx).gen::<u32>() % 5;
         for _ in 0..bottles {
             commands.spawn((
                 SpriteBundle {
@@ -56,7 +57,7 @@ fn damage_rum_spawn(
                 Transform2::from_translation(event.position)
                     .with_depth((DepthLayer::Entity, 1.))
                     .with_scale(Vec2::ONE * 0.25),
-                DamageRum::new(),
+                DamageRum::new(&mut game_rng),
             ));
         }
     }
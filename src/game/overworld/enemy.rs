@@ -0,0 +1,172 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum EnemySystem {
+    Spawn,
+}
+
+pub struct EnemyPlugin;
+
+impl Plugin for EnemyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<EnemySpawnEvent>().add_systems(
+            Update,
+            (
+                enemy_spawn
+                    .in_set(EnemySystem::Spawn)
+                    .before(HealthbarSystem::Spawn),
+                enemy_animate,
+            ),
+        );
+    }
+}
+
+#[derive(Event, Default, Clone)]
+pub struct EnemySpawnEvent {
+    pub entity: Option<Entity>,
+    pub position: Vec2,
+    pub archetype: String,
+}
+
+#[derive(Component)]
+pub struct Enemy {
+    pub archetype: String,
+    frames: usize,
+    animation_period: f32,
+    sprite_rotation: Option<f32>,
+}
+
+#[derive(Component)]
+pub struct EnemySprite;
+
+fn enemy_spawn(
+    mut ev_spawn: EventReader<EnemySpawnEvent>,
+    mut commands: Commands,
+    mut ev_healthbar_spawn: EventWriter<HealthbarSpawnEvent>,
+    asset_library: Res<AssetLibrary>,
+    enemy_archetypes_assets: Res<Assets<EnemyArchetypesAsset>>,
+    collision_query: Res<CollisionQuery>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let handle = &asset_library.enemy_archetypes;
+    let Some(enemy_archetypes) = enemy_archetypes_assets.get(handle) else { return };
+    for event in ev_spawn.iter() {
+        let Some(archetype) = enemy_archetypes.get(&event.archetype) else {
+            warn!("Unknown enemy archetype: {}", event.archetype);
+            if let Some(entity) = event.entity {
+                commands.entity(entity).despawn_recursive();
+            }
+            continue;
+        };
+        if collision_query
+            .check(
+                event.position,
+                CollisionShape::Rect {
+                    size: archetype.collision_size * 1.5,
+                },
+                None,
+            )
+            .is_some()
+        {
+            continue;
+        }
+        let mut entity = if let Some(entity) = event.entity {
+            commands.entity(entity)
+        } else {
+            commands.spawn_empty()
+        };
+        entity
+            .insert((
+                TransformBundle::default(),
+                VisibilityBundle::default(),
+                Transform2::from_translation(event.position),
+                Enemy {
+                    archetype: event.archetype.clone(),
+                    frames: archetype.frames,
+                    animation_period: archetype.animation_period,
+                    sprite_rotation: archetype.sprite_rotation,
+                },
+                EnemyAi::new(archetype.behavior.clone(), &mut game_rng),
+                NavigationPath::default(),
+                YDepth::default(),
+                Health::new(archetype.health),
+                Hitbox {
+                    shape: CollisionShape::Rect {
+                        size: archetype.hitbox_size,
+                    },
+                    for_entity: None,
+                    flags: DAMAGE_FLAG_ENEMY,
+                },
+                Hurtbox {
+                    shape: CollisionShape::Rect {
+                        size: archetype.hurtbox_size,
+                    },
+                    for_entity: None,
+                    auto_despawn: false,
+                    flags: DAMAGE_FLAG_PLAYER,
+                    knockback_type: HurtboxKnockbackType::None,
+                    damage: archetype.damage,
+                },
+                Collision {
+                    shape: CollisionShape::Rect {
+                        size: archetype.collision_size,
+                    },
+                    flags: COLLISION_FLAG,
+                },
+                CharacterController {
+                    movement: Vec2::ZERO,
+                    speed: archetype.speed,
+                    knockback_resistance: archetype.knockback_resistance,
+                    ..Default::default()
+                },
+                AutoDamage {
+                    despawn: true,
+                    experience: archetype.experience,
+                    experience_count: archetype.experience_count,
+                    ..Default::default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    SpriteSheetBundle {
+                        texture_atlas: archetype.atlas.clone(),
+                        ..Default::default()
+                    },
+                    Transform2::new()
+                        .with_depth((DepthLayer::Entity, 0.))
+                        .with_scale(Vec2::ONE * archetype.scale),
+                    EnemySprite,
+                ));
+            });
+        ev_healthbar_spawn.send(HealthbarSpawnEvent {
+            entity: Some(entity.id()),
+            offset: Vec2::new(0., 75.),
+            size: Vec2::new(80., 6.),
+        });
+    }
+}
+
+fn enemy_animate(
+    query: Query<(&Enemy, &EnemyAi, &Children, &AutoDamage)>,
+    mut child_query: Query<(&mut TextureAtlasSprite, &mut Transform2), With<EnemySprite>>,
+    time: Res<Time>,
+) {
+    for (enemy, enemy_ai, children, auto_damage) in query.iter() {
+        for child in children.iter() {
+            if let Ok((mut sprite, mut transform)) = child_query.get_mut(*child) {
+                if let Some(sprite_rotation) = enemy.sprite_rotation {
+                    transform.rotation = enemy_ai.angle + sprite_rotation;
+                }
+                let time = (time.elapsed_seconds() / enemy.animation_period) % 1.;
+                sprite.index = ((time * enemy.frames as f32) as usize).min(enemy.frames - 1);
+                if auto_damage.invincibility > 0. {
+                    sprite.color.set_a(0.5);
+                } else {
+                    sprite.color.set_a(1.);
+                };
+            }
+        }
+    }
+}
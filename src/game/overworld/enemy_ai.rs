@@ -0,0 +1,274 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+pub struct EnemyAiPlugin;
+
+impl Plugin for EnemyAiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                enemy_ai_knockback,
+                enemy_ai_steer
+                    .after(enemy_ai_knockback)
+                    .after(NavigationSystem::Update)
+                    .before(CharacterControllerSystem::Update),
+                enemy_ai_attack.after(enemy_ai_steer),
+                enemy_projectile_move,
+            ),
+        );
+    }
+}
+
+#[derive(Component)]
+pub struct EnemyAi {
+    pub behavior: EnemyBehavior,
+    pub angle: f32,
+    wander_chance: TimedChance,
+    wander_time: f32,
+    wander_direction: Vec2,
+    relative_angle: f32,
+    stagger: f32,
+    attack_cooldown: f32,
+}
+
+impl EnemyAi {
+    pub fn new(behavior: EnemyBehavior, game_rng: &mut GameRng) -> Self {
+        Self {
+            attack_cooldown: behavior.attack.map_or(0., |attack| attack.cooldown),
+            behavior,
+            angle: 0.,
+            wander_chance: game_rng.timed_chance(RngStream::Ai),
+            wander_time: 0.,
+            wander_direction: Vec2::X,
+            relative_angle: game_rng.f32(RngStream::Ai) * std::f32::consts::TAU,
+            stagger: 0.,
+        }
+    }
+
+    pub fn staggered(&self) -> bool {
+        self.stagger > 0.
+    }
+
+    fn wander(&mut self, delta_seconds: f32, game_rng: &mut GameRng) {
+        for index in 0..self.behavior.steering.len() {
+            if let EnemySteering::Wander {
+                seconds,
+                variance,
+                duration,
+            } = self.behavior.steering[index]
+            {
+                if self.wander_time < 0.
+                    && self.wander_chance.check(seconds, variance, delta_seconds)
+                {
+                    self.wander_time = duration;
+                    self.wander_direction = game_rng.direction(RngStream::Ai);
+                }
+            }
+        }
+        self.wander_time -= delta_seconds;
+    }
+
+    fn seeks(&self) -> bool {
+        self.behavior
+            .steering
+            .iter()
+            .any(|steering| matches!(steering, EnemySteering::Seek))
+    }
+
+    fn steer(
+        &mut self,
+        position: Vec2,
+        target: Vec2,
+        waypoint: Option<Vec2>,
+        health_fraction: f32,
+        delta_seconds: f32,
+    ) -> Vec2 {
+        for index in 0..self.behavior.steering.len() {
+            let direction = match self.behavior.steering[index] {
+                EnemySteering::Seek => Some(waypoint.unwrap_or(target) - position),
+                EnemySteering::Orbit {
+                    radius,
+                    approach,
+                    angular_speed,
+                } => {
+                    self.relative_angle += angular_speed * delta_seconds;
+                    let distance = position.distance(target);
+                    let orbit_position = target
+                        + Vec2::from_angle(self.relative_angle) * (radius + distance * approach);
+                    Some(orbit_position - position)
+                }
+                EnemySteering::KeepDistance { min, max } => {
+                    let difference = target - position;
+                    let distance = difference.length();
+                    if distance < min {
+                        Some(-difference)
+                    } else if distance > max {
+                        Some(difference)
+                    } else {
+                        Some(Vec2::ZERO)
+                    }
+                }
+                EnemySteering::Wander { .. } => {
+                    if self.wander_time > 0. {
+                        Some(self.wander_direction)
+                    } else {
+                        None
+                    }
+                }
+                EnemySteering::Flee { health } => {
+                    if health_fraction < health {
+                        Some(position - target)
+                    } else {
+                        None
+                    }
+                }
+            };
+            if let Some(direction) = direction {
+                return direction;
+            }
+        }
+        Vec2::ZERO
+    }
+}
+
+#[derive(Component)]
+struct EnemyProjectile {
+    velocity: Vec2,
+}
+
+fn enemy_ai_knockback(
+    mut query: Query<&mut EnemyAi>,
+    mut ev_knockback: EventReader<KnockbackEvent>,
+) {
+    for event in ev_knockback.iter() {
+        if let Ok(mut enemy_ai) = query.get_mut(event.entity) {
+            enemy_ai.stagger = enemy_ai.behavior.knockback_stagger;
+        }
+    }
+}
+
+fn enemy_ai_steer(
+    mut query: Query<(
+        &mut CharacterController,
+        &GlobalTransform,
+        &mut EnemyAi,
+        Option<&Health>,
+        Option<&mut NavigationPath>,
+    )>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+    cutscenes: Res<Cutscenes>,
+    time: Res<Time>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let player_position = if let Ok(player_transform) = player_query.get_single() {
+        player_transform.translation().truncate()
+    } else {
+        Vec2::ZERO
+    };
+    for (mut character_controller, transform, mut enemy_ai, health, navigation_path) in
+        query.iter_mut()
+    {
+        enemy_ai.wander(time.delta_seconds(), &mut game_rng);
+        enemy_ai.stagger -= time.delta_seconds();
+        if cutscenes.running() || enemy_ai.staggered() {
+            character_controller.movement = Vec2::ZERO;
+            continue;
+        }
+        let health_fraction = health.map_or(1., |health| health.value / health.max);
+        let mut waypoint = None;
+        if let Some(mut navigation_path) = navigation_path {
+            let target = enemy_ai.seeks().then_some(player_position);
+            if navigation_path.target != target {
+                navigation_path.target = target;
+            }
+            waypoint = navigation_path.waypoint();
+        }
+        let direction = enemy_ai.steer(
+            transform.translation().truncate(),
+            player_position,
+            waypoint,
+            health_fraction,
+            time.delta_seconds(),
+        );
+        if direction.length_squared() == 0. {
+            character_controller.movement = Vec2::ZERO;
+        } else if let Some(turn_speed) = enemy_ai.behavior.turn_speed {
+            enemy_ai.angle = (time.delta_seconds() * turn_speed).lerp(
+                enemy_ai.angle,
+                enemy_ai.angle + Vec2::from_angle(enemy_ai.angle).angle_between(direction),
+            );
+            character_controller.movement = Vec2::from_angle(enemy_ai.angle);
+        } else {
+            enemy_ai.angle = Vec2::X.angle_between(direction);
+            character_controller.movement = direction.normalize();
+        }
+    }
+}
+
+fn enemy_ai_attack(
+    mut query: Query<(Entity, &mut EnemyAi, &GlobalTransform)>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    cutscenes: Res<Cutscenes>,
+    time: Res<Time>,
+) {
+    let Ok(player_transform) = player_query.get_single() else { return };
+    let player_position = player_transform.translation().truncate();
+    for (entity, mut enemy_ai, transform) in query.iter_mut() {
+        let Some(attack) = enemy_ai.behavior.attack else { continue };
+        enemy_ai.attack_cooldown -= time.delta_seconds();
+        if cutscenes.running() || enemy_ai.staggered() || enemy_ai.attack_cooldown > 0. {
+            continue;
+        }
+        let position = transform.translation().truncate();
+        let difference = player_position - position;
+        if difference.length() > attack.range || difference.length_squared() == 0. {
+            continue;
+        }
+        enemy_ai.attack_cooldown = attack.cooldown;
+        let angle = Vec2::X.angle_between(difference);
+        for i in 0..attack.count {
+            let spread = if attack.count > 1 {
+                attack.spread * (i as f32 / (attack.count - 1) as f32 - 0.5)
+            } else {
+                0.
+            };
+            let velocity = Vec2::from_angle(angle + spread) * attack.speed;
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::rgb(0.5, 0., 0.1),
+                        ..Default::default()
+                    },
+                    texture: asset_library.sprite_bullet_note.clone(),
+                    ..Default::default()
+                },
+                Transform2::from_translation(position)
+                    .with_depth((DepthLayer::Entity, 0.0))
+                    .with_scale(Vec2::ONE * attack.size / 14.),
+                Hurtbox {
+                    shape: CollisionShape::Rect {
+                        size: Vec2::ONE * attack.size,
+                    },
+                    for_entity: Some(entity),
+                    auto_despawn: true,
+                    flags: DAMAGE_FLAG_PLAYER,
+                    knockback_type: HurtboxKnockbackType::Velocity(velocity * attack.knockback),
+                    damage: attack.damage,
+                },
+                YDepth::default(),
+                EnemyProjectile { velocity },
+                TimeToLive::new(attack.lifetime),
+            ));
+        }
+    }
+}
+
+fn enemy_projectile_move(mut query: Query<(&mut Transform2, &EnemyProjectile)>, time: Res<Time>) {
+    for (mut transform, projectile) in query.iter_mut() {
+        transform.translation += projectile.velocity * time.delta_seconds();
+    }
+}
@@ -1,106 +1,35 @@
 use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
+use bevy_egui::{egui, EguiContext};
+use rand::Rng;
 
+const ENEMY_SPAWNS_ASSET_PATH: &str = "enemies/overworld.spawns.ron";
+
+/// Inserted when entering the overworld, so the spawn timing follows the run seed.
 #[derive(Resource)]
 struct EnemySpawnsState {
     chance: TimedChance,
-    none_level: EnemySpawnLevel,
-    easy_level: EnemySpawnLevel,
-    medium_level: EnemySpawnLevel,
-    hard_level: EnemySpawnLevel,
-    midnight_level: EnemySpawnLevel,
-    davy_level: EnemySpawnLevel,
-}
-
-impl Default for EnemySpawnsState {
-    fn default() -> Self {
-        Self {
-            chance: TimedChance::new(),
-            none_level: EnemySpawnLevel {
-                spawn_chances: vec![(1., EnemySpawn::Octopus(OctopusLevel::Easy))],
-                seconds_per_spawn: 1.,
-                spawn_max: 0,
-            },
-            easy_level: EnemySpawnLevel {
-                spawn_chances: vec![
-                    (0.01, EnemySpawn::Octopus(OctopusLevel::Medium)),
-                    (0.15, EnemySpawn::Turtle(TurtleLevel::Easy)),
-                    (1., EnemySpawn::Octopus(OctopusLevel::Easy)),
-                ],
-                seconds_per_spawn: 1.,
-                spawn_max: 15,
-            },
-            medium_level: EnemySpawnLevel {
-                spawn_chances: vec![
-                    (0.05, EnemySpawn::Octopus(OctopusLevel::Hard)),
-                    (0.01, EnemySpawn::Octopus(OctopusLevel::Medium)),
-                    (0.15, EnemySpawn::Turtle(TurtleLevel::Easy)),
-                    (1., EnemySpawn::Octopus(OctopusLevel::Easy)),
-                ],
-                seconds_per_spawn: 0.5,
-                spawn_max: 20,
-            },
-            hard_level: EnemySpawnLevel {
-                spawn_chances: vec![
-                    (0.1, EnemySpawn::Octopus(OctopusLevel::Hard)),
-                    (0.01, EnemySpawn::Octopus(OctopusLevel::Medium)),
-                    (0.005, EnemySpawn::Turtle(TurtleLevel::Hard)),
-                    (0.01, EnemySpawn::Turtle(TurtleLevel::Medium)),
-                    (0.15, EnemySpawn::Turtle(TurtleLevel::Easy)),
-                    (1., EnemySpawn::Octopus(OctopusLevel::Easy)),
-                ],
-                seconds_per_spawn: 0.25,
-                spawn_max: 30,
-            },
-            midnight_level: EnemySpawnLevel {
-                spawn_chances: vec![
-                    (0.1, EnemySpawn::Octopus(OctopusLevel::Hard)),
-                    (0.1, EnemySpawn::Octopus(OctopusLevel::Medium)),
-                    (0.005, EnemySpawn::Turtle(TurtleLevel::Hard)),
-                    (0.01, EnemySpawn::Turtle(TurtleLevel::Medium)),
-                    (0.15, EnemySpawn::Turtle(TurtleLevel::Easy)),
-                    (1., EnemySpawn::Octopus(OctopusLevel::Easy)),
-                ],
-                seconds_per_spawn: 0.1,
-                spawn_max: 40,
-            },
-            davy_level: EnemySpawnLevel {
-                spawn_chances: vec![
-                    (0.1, EnemySpawn::Turtle(TurtleLevel::Easy)),
-                    (1., EnemySpawn::Octopus(OctopusLevel::Easy)),
-                ],
-                seconds_per_spawn: 0.5,
-                spawn_max: 10,
-            },
-        }
-    }
 }
 
-enum EnemySpawn {
-    Octopus(OctopusLevel),
-    Turtle(TurtleLevel),
-}
-
-struct EnemySpawnLevel {
-    spawn_chances: Vec<(f32, EnemySpawn)>,
-    seconds_per_spawn: f32,
-    spawn_max: i32,
+#[derive(Default)]
+struct EnemySpawnsEditor {
+    threat_level: ThreatLevel,
+    status: String,
 }
 
 pub struct EnemySpawnsPlugin;
 
 impl Plugin for EnemySpawnsPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<EnemySpawnsState>()
-            .add_event::<DespawnSpawnedEntitiesEvent>()
+        app.add_event::<DespawnSpawnedEntitiesEvent>()
+            .add_systems(OnEnter(AppState::Overworld), enemy_spawns_init)
             .add_systems(
                 Update,
                 (
-                    enemy_spawns
-                        .before(OctopusSystem::Spawn)
-                        .before(TurtleSystem::Spawn),
+                    enemy_spawns.before(EnemySystem::Spawn),
                     enemy_spawns_despawn,
+                    enemy_spawns_debug,
                 ),
             );
     }
@@ -116,47 +45,57 @@ pub struct DespawnSpawnedEntitiesEvent;
 
 const DESPAWN_BUFFER_DISTANCE: f32 = 200.;
 const RANDOM_SPAWN_DISTANCE: Vec2 = Vec2::new(1280. * 0.5 + 100., 768. * 0.5 + 100.);
-fn random_spawn_offset() -> Vec2 {
-    let area = rand::random::<u8>() % 4;
+fn random_spawn_offset(game_rng: &mut GameRng) -> Vec2 {
+    let rng = game_rng.stream(RngStream::Spawns);
+    let area = rng.gen::<u8>() % 4;
     if area == 0 {
         Vec2::new(
             RANDOM_SPAWN_DISTANCE.x,
-            RANDOM_SPAWN_DISTANCE.y * rand::random::<f32>() * 2. - RANDOM_SPAWN_DISTANCE.y,
+            RANDOM_SPAWN_DISTANCE.y * rng.gen::<f32>() * 2. - RANDOM_SPAWN_DISTANCE.y,
         )
     } else if area == 1 {
         Vec2::new(
             -RANDOM_SPAWN_DISTANCE.x,
-            RANDOM_SPAWN_DISTANCE.y * rand::random::<f32>() * 2. - RANDOM_SPAWN_DISTANCE.y,
+            RANDOM_SPAWN_DISTANCE.y * rng.gen::<f32>() * 2. - RANDOM_SPAWN_DISTANCE.y,
         )
     } else if area == 2 {
         Vec2::new(
-            RANDOM_SPAWN_DISTANCE.x * rand::random::<f32>() * 2. - RANDOM_SPAWN_DISTANCE.x,
+            RANDOM_SPAWN_DISTANCE.x * rng.gen::<f32>() * 2. - RANDOM_SPAWN_DISTANCE.x,
             RANDOM_SPAWN_DISTANCE.y,
         )
     } else {
         Vec2::new(
-            RANDOM_SPAWN_DISTANCE.x * rand::random::<f32>() * 2. - RANDOM_SPAWN_DISTANCE.x,
+            RANDOM_SPAWN_DISTANCE.x * rng.gen::<f32>() * 2. - RANDOM_SPAWN_DISTANCE.x,
             -RANDOM_SPAWN_DISTANCE.y,
         )
     }
 }
 
+fn enemy_spawns_init(mut commands: Commands, mut game_rng: ResMut<GameRng>) {
+    commands.insert_resource(EnemySpawnsState {
+        chance: game_rng.timed_chance(RngStream::Spawns),
+    });
+}
+
 fn enemy_spawns(
     mut commands: Commands,
     mut queries: ParamSet<(
         Query<&GlobalTransform, With<Camera>>,
         Query<(Entity, &GlobalTransform, &mut SpawnedEntity)>,
     )>,
-    mut ev_octopus_spawn: EventWriter<OctopusSpawnEvent>,
-    mut ev_turtle_spawn: EventWriter<TurtleSpawnEvent>,
+    mut ev_enemy_spawn: EventWriter<EnemySpawnEvent>,
     state_time: Res<StateTime<AppState>>,
     game_state: Res<GameState>,
     screen_fade: Res<ScreenFade>,
     threat_level: Res<ThreatLevel>,
     cutscenes: Res<Cutscenes>,
     app_state: Res<State<AppState>>,
-    mut state: ResMut<EnemySpawnsState>,
+    state: Option<ResMut<EnemySpawnsState>>,
     time: Res<Time>,
+    asset_library: Res<AssetLibrary>,
+    enemy_spawns_assets: Res<Assets<EnemySpawnsAsset>>,
+    enemy_archetypes_assets: Res<Assets<EnemyArchetypesAsset>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     if cutscenes.running() && matches!(app_state.get(), AppState::Overworld) {
         return;
@@ -179,56 +118,33 @@ fn enemy_spawns(
             count += 1;
         }
     }
-    let EnemySpawnsState {
-        chance,
-        none_level,
-        easy_level,
-        medium_level,
-        hard_level,
-        midnight_level,
-        davy_level,
-    } = state.as_mut();
-    let level = match *threat_level {
-        ThreatLevel::None => none_level,
-        ThreatLevel::Easy => easy_level,
-        ThreatLevel::Medium => medium_level,
-        ThreatLevel::Hard => hard_level,
-        ThreatLevel::Midnight => midnight_level,
-        ThreatLevel::Davy => davy_level,
-    };
+    let Some(enemy_spawns) = enemy_spawns_assets.get(&asset_library.enemy_spawns) else { return };
+    let archetypes_handle = &asset_library.enemy_archetypes;
+    let Some(enemy_archetypes) = enemy_archetypes_assets.get(archetypes_handle) else { return };
+    let Some(mut state) = state else { return };
+    let level = enemy_spawns.level(*threat_level);
     if !state_time.just_entered()
-        && chance.check(level.seconds_per_spawn, 0., time.delta_seconds())
+        && state
+            .chance
+            .check(level.seconds_per_spawn, 0., time.delta_seconds())
         && count < level.spawn_max
         && !game_state.quests.block_enemy_spawns()
         && screen_fade.faded_in()
         && *threat_level != ThreatLevel::None
     {
         for spawn_chance in level.spawn_chances.iter() {
-            if rand::random::<f32>() < spawn_chance.0 {
-                let count = match spawn_chance.1 {
-                    EnemySpawn::Turtle(TurtleLevel::Medium) => 2,
-                    _ => 1,
-                };
+            if game_rng.f32(RngStream::Spawns) < spawn_chance.chance {
+                let count = enemy_archetypes
+                    .get(&spawn_chance.enemy)
+                    .map_or(1, |archetype| archetype.spawn_count);
                 for _ in 0..count {
-                    let position = camera_position + random_spawn_offset();
-                    match spawn_chance.1 {
-                        EnemySpawn::Octopus(level) => {
-                            let entity = commands.spawn(SpawnedEntity::default()).id();
-                            ev_octopus_spawn.send(OctopusSpawnEvent {
-                                entity: Some(entity),
-                                position,
-                                level,
-                            });
-                        }
-                        EnemySpawn::Turtle(level) => {
-                            let entity = commands.spawn(SpawnedEntity::default()).id();
-                            ev_turtle_spawn.send(TurtleSpawnEvent {
-                                entity: Some(entity),
-                                position,
-                                level,
-                            });
-                        }
-                    }
+                    let position = camera_position + random_spawn_offset(&mut game_rng);
+                    let entity = commands.spawn(SpawnedEntity::default()).id();
+                    ev_enemy_spawn.send(EnemySpawnEvent {
+                        entity: Some(entity),
+                        position,
+                        archetype: spawn_chance.enemy.clone(),
+                    });
                 }
                 break;
             }
@@ -249,3 +165,141 @@ fn enemy_spawns_despawn(
         }
     }
 }
+
+fn enemy_spawns_debug(
+    mut egui_query: Query<&mut EguiContext>,
+    mut menu_bar: ResMut<MenuBar>,
+    mut enemy_spawns_assets: ResMut<Assets<EnemySpawnsAsset>>,
+    enemy_archetypes_assets: Res<Assets<EnemyArchetypesAsset>>,
+    asset_library: Res<AssetLibrary>,
+    mut editor: Local<EnemySpawnsEditor>,
+) {
+    menu_bar.item("Enemy Spawns", |open| {
+        let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
+        let handle = &asset_library.enemy_spawns;
+        let Some(mut enemy_spawns) = enemy_spawns_assets.get(handle).cloned() else { return };
+        let archetype_names = enemy_archetypes_assets
+            .get(&asset_library.enemy_archetypes)
+            .map_or(vec![], |enemy_archetypes| enemy_archetypes.names());
+        let mut changed = false;
+        egui::Window::new("Enemy Spawns")
+            .open(open)
+            .show(egui_context.get_mut(), |ui| {
+                ui.horizontal(|ui| {
+                    for threat_level in ThreatLevel::all() {
+                        ui.selectable_value(
+                            &mut editor.threat_level,
+                            threat_level,
+                            format!("{:?}", threat_level),
+                        );
+                    }
+                });
+                ui.separator();
+                let level = enemy_spawns.level_mut(editor.threat_level);
+                ui.horizontal(|ui| {
+                    ui.label("Seconds Per Spawn");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut level.seconds_per_spawn)
+                                .speed(0.01)
+                                .clamp_range(0.01..=10.0),
+                        )
+                        .changed();
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Spawn Max");
+                    changed |= ui
+                        .add(egui::DragValue::new(&mut level.spawn_max).clamp_range(0..=200))
+                        .changed();
+                });
+                let mut remove = None;
+                for (index, spawn_chance) in level.spawn_chances.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_source(("enemy_spawn", index))
+                            .selected_text(spawn_chance.enemy.as_str())
+                            .show_ui(ui, |ui| {
+                                for name in archetype_names.iter() {
+                                    if ui
+                                        .selectable_label(spawn_chance.enemy == *name, *name)
+                                        .clicked()
+                                    {
+                                        spawn_chance.enemy = name.to_string();
+                                        changed = true;
+                                    }
+                                }
+                            });
+                        changed |= ui
+                            .add(egui::Slider::new(&mut spawn_chance.chance, 0.0..=1.0))
+                            .changed();
+                        if ui.button("Remove").clicked() {
+                            remove = Some(index);
+                        }
+                    });
+                }
+                if let Some(index) = remove {
+                    level.spawn_chances.remove(index);
+                    changed = true;
+                }
+                if ui.button("Add Spawn").clicked() {
+                    level.spawn_chances.push(EnemySpawnChance {
+                        chance: 1.,
+                        enemy: archetype_names
+                            .first()
+                            .unwrap_or(&"octopus_easy")
+                            .to_string(),
+                    });
+                    changed = true;
+                }
+                ui.separator();
+                if ui.button("Save").clicked() {
+                    let unknown = ThreatLevel::all()
+                        .into_iter()
+                        .flat_map(|threat_level| {
+                            enemy_spawns.level(threat_level).spawn_chances.iter()
+                        })
+                        .find(|spawn_chance| {
+                            !archetype_names.contains(&spawn_chance.enemy.as_str())
+                        });
+                    editor.status = if let Some(spawn_chance) = unknown {
+                        format!("Unknown enemy archetype: {}", spawn_chance.enemy)
+                    } else {
+                        match enemy_spawns.validate() {
+                            Ok(()) => enemy_spawns_save(&enemy_spawns),
+                            Err(error) => error,
+                        }
+                    };
+                }
+                if !editor.status.is_empty() {
+                    ui.label(editor.status.as_str());
+                }
+            });
+        if changed {
+            if let Some(asset) = enemy_spawns_assets.get_mut(handle) {
+                *asset = enemy_spawns;
+            }
+        }
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn enemy_spawns_save(enemy_spawns: &EnemySpawnsAsset) -> String {
+    let contents = match ron::ser::to_string_pretty(enemy_spawns, ron::ser::PrettyConfig::default())
+    {
+        Ok(contents) => contents,
+        Err(error) => return error.to_string(),
+    };
+    let path = std::path::Path::new(
+        &std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_owned()),
+    )
+    .join("assets")
+    .join(ENEMY_SPAWNS_ASSET_PATH);
+    match std::fs::write(&path, contents) {
+        Ok(()) => format!("Saved {}", path.display()),
+        Err(error) => error.to_string(),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn enemy_spawns_save(_enemy_spawns: &EnemySpawnsAsset) -> String {
+    format!("Cannot write {} on the web", ENEMY_SPAWNS_ASSET_PATH)
+}
@@ -6,7 +6,8 @@ pub struct DangerousSeasTriggerPlugin;
 
 impl Plugin for DangerousSeasTriggerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (dangerous_seas_world_spawn, dangerous_seas_check));
+        app.add_world_location("DangerousSeasTrigger", WorldLocationCount::Multiple)
+            .add_systems(Update, (dangerous_seas_world_spawn, dangerous_seas_check));
     }
 }
 
@@ -19,7 +20,7 @@ fn dangerous_seas_world_spawn(
     world_locations: Res<WorldLocations>,
 ) {
     for _ in ev_spawn.iter() {
-        let triggers = world_locations.get_multiple_rect("DangerousSeasTrigger");
+        let triggers = world_locations.multiple("DangerousSeasTrigger");
         for trigger in triggers {
             commands.spawn((
                 TransformBundle::default(),
@@ -1,23 +1,28 @@
 use crate::common::prelude::*;
 use crate::game::prelude::*;
-use bevy::prelude::*;
+use bevy::{ecs::system::EntityCommands, prelude::*};
 
 pub struct RubblePlugin;
 
 impl Plugin for RubblePlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<RubbleSpawnEvent>().add_systems(
-            Update,
-            (
-                rubble_spawn.before(HealthbarSystem::Spawn),
-                rubble_world_spawn,
-            ),
-        );
+        app.add_event::<RubbleSpawnEvent>()
+            .add_world_location("Rubble", WorldLocationCount::Multiple)
+            .add_ldtk_entity("Rubble", rubble_ldtk_spawn)
+            .add_systems(
+                Update,
+                (
+                    rubble_spawn.before(HealthbarSystem::Spawn),
+                    rubble_world_spawn,
+                ),
+            );
     }
 }
 
 #[derive(Event, Default, Clone, Copy)]
 pub struct RubbleSpawnEvent {
+    /// Turns an existing entity into rubble, keeping its transform.
+    pub entity: Option<Entity>,
     pub position: Vec2,
 }
 
@@ -31,11 +36,17 @@ fn rubble_spawn(
     mut ev_healthbar_spawn: EventWriter<HealthbarSpawnEvent>,
 ) {
     for event in ev_spawn.iter() {
-        let entity = commands
-            .spawn((
+        let mut rubble = if let Some(entity) = event.entity {
+            commands.entity(entity)
+        } else {
+            commands.spawn((
                 TransformBundle::default(),
                 VisibilityBundle::default(),
                 Transform2::from_translation(event.position),
+            ))
+        };
+        let entity = rubble
+            .insert((
                 Rubble,
                 Health::new(1.),
                 Hitbox {
@@ -77,15 +88,34 @@ fn rubble_spawn(
     }
 }
 
+fn rubble_ldtk_spawn(entity: &mut EntityCommands, _: &LdtkEntity, _: &LdtkFields) {
+    let rubble = entity.id();
+    entity
+        .commands()
+        .add(move |world: &mut bevy::prelude::World| {
+            world.send_event(RubbleSpawnEvent {
+                entity: Some(rubble),
+                ..Default::default()
+            });
+        });
+}
+
+/// Hand-made levels spawn rubble as LDtk entities, so only generated worlds need it placed here.
 fn rubble_world_spawn(
     mut ev_spawn: EventReader<WorldLocationsSpawnEvent>,
     world_locations: Res<WorldLocations>,
+    game_state: Res<GameState>,
     mut ev_rubble_spawn: EventWriter<RubbleSpawnEvent>,
 ) {
     for _ in ev_spawn.iter() {
-        let positions = world_locations.get_multiple_positions("Rubble");
-        for position in positions {
-            ev_rubble_spawn.send(RubbleSpawnEvent { position });
+        if game_state.world_seed.is_none() {
+            continue;
+        }
+        for rect in world_locations.multiple("Rubble") {
+            ev_rubble_spawn.send(RubbleSpawnEvent {
+                entity: None,
+                position: rect.position,
+            });
         }
     }
 }
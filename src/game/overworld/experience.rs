@@ -31,11 +31,12 @@ pub fn experience_spawn(
     mut ev_spawn: EventReader<ExperienceSpawnEvent>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     for event in ev_spawn.iter() {
         for _ in 0..event.count {
-            let angle = Vec2::from_angle(rand::random::<f32>() * std::f32::consts::TAU);
-            let velocity = angle * (50. + rand::random::<f32>() * 200.);
+            let angle = game_rng.direction(RngStream::Loot);
+            let velocity = angle * (50. + game_rng.f32(RngStream::Loot) * 200.);
             commands.spawn((
                 SpriteBundle {
                     sprite: Sprite {
@@ -20,19 +20,21 @@ impl Plugin for OverworldPlugin {
             attacks::AttacksPlugin,
             damage::DamagePlugin,
             cutscenes::CutscenesPlugin,
-            octopus::OctopusPlugin,
+            enemy::EnemyPlugin,
             ui::OverworldUiPlugin,
             camera::OverworldCameraPlugin,
             entities::EntitiesPlugin,
         ))
         .add_plugins((
             trigger::TriggerPlugin,
+            enemy_ai::EnemyAiPlugin,
+            navigation::NavigationPlugin,
             enemy_spawns::EnemySpawnsPlugin,
             threat_level::ThreatLevelPlugin,
-            turtle::TurtlePlugin,
             experience::ExperiencePlugin,
             damage_flash::DamageFlashPlugin,
             damage_rum::DamageRumPlugin,
+            archipelago::ArchipelagoPlugin,
         ))
         .add_event::<OverworldEnterEvent>()
         .add_event::<WorldAmbienceSoundStopEvent>()
@@ -80,10 +82,6 @@ fn overworld_init(
             .as_looping(),
         WorldAmbienceSound,
     ));
-    commands.spawn((
-        AudioPlusSource::new(asset_library.sound_effects.sfx_overworld_music.clone()).as_looping(),
-        WorldAmbienceSound,
-    ));
 }
 
 fn overworld_init_after_ldtk(
@@ -115,6 +113,7 @@ fn overworld_sound_stop(
     }
 }
 
+pub mod archipelago;
 pub mod attacks;
 pub mod boat;
 pub mod camera;
@@ -123,18 +122,19 @@ pub mod cutscenes;
 pub mod damage;
 pub mod damage_flash;
 pub mod damage_rum;
+pub mod enemy;
+pub mod enemy_ai;
 pub mod enemy_spawns;
 pub mod entities;
 pub mod experience;
 pub mod health;
 pub mod healthbar;
+pub mod navigation;
 pub mod ocean;
-pub mod octopus;
 pub mod player;
 pub mod threat_level;
 pub mod town;
 pub mod trigger;
-pub mod turtle;
 pub mod ui;
 pub mod water_ring;
 pub mod world;
@@ -0,0 +1,118 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+const NAVIGATION_REPATH_SECONDS: f32 = 0.5;
+const NAVIGATION_WAYPOINT_DISTANCE: f32 = 50.;
+/// Paths searched per frame. Anything else that needs a new path waits for a later frame.
+const NAVIGATION_REPATHS_PER_FRAME: usize = 4;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum NavigationSystem {
+    Update,
+}
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (navigation_destination, navigation_update)
+                .chain()
+                .in_set(NavigationSystem::Update)
+                .before(CharacterControllerSystem::Update),
+        );
+    }
+}
+
+/// Follows a path around islands toward `target`. Added automatically alongside
+/// `CharacterControllerDestination`.
+#[derive(Component, Default)]
+pub struct NavigationPath {
+    pub target: Option<Vec2>,
+    waypoints: Vec<Vec2>,
+    path_target: Option<Vec2>,
+    repath: f32,
+}
+
+impl NavigationPath {
+    /// The next waypoint before the target, or `None` when the target can be approached directly.
+    pub fn waypoint(&self) -> Option<Vec2> {
+        if self.waypoints.len() > 1 {
+            Some(self.waypoints[0])
+        } else {
+            None
+        }
+    }
+}
+
+fn navigation_destination(
+    mut query: Query<(
+        Entity,
+        &CharacterControllerDestination,
+        Option<&mut NavigationPath>,
+    )>,
+    mut path_query: Query<&mut NavigationPath, Without<CharacterControllerDestination>>,
+    mut removed: RemovedComponents<CharacterControllerDestination>,
+    mut commands: Commands,
+) {
+    for (entity, destination, navigation_path) in query.iter_mut() {
+        if let Some(mut navigation_path) = navigation_path {
+            if navigation_path.target != Some(destination.target) {
+                navigation_path.target = Some(destination.target);
+            }
+        } else {
+            commands.entity(entity).insert(NavigationPath {
+                target: Some(destination.target),
+                ..Default::default()
+            });
+        }
+    }
+    for entity in removed.iter() {
+        if let Ok(mut navigation_path) = path_query.get_mut(entity) {
+            navigation_path.target = None;
+        }
+    }
+}
+
+fn navigation_update(
+    mut query: Query<(&mut NavigationPath, &GlobalTransform, &Collision)>,
+    nav_grid: Res<NavGrid>,
+    time: Res<Time>,
+) {
+    let mut repaths = 0;
+    for (mut navigation_path, transform, collision) in query.iter_mut() {
+        let position = transform.translation().truncate();
+        let Some(target) = navigation_path.target else {
+            navigation_path.waypoints.clear();
+            navigation_path.path_target = None;
+            continue;
+        };
+        navigation_path.repath -= time.delta_seconds();
+        if nav_grid.is_changed() {
+            navigation_path.repath = 0.;
+        }
+        let target_moved = navigation_path.path_target.map_or(true, |path_target| {
+            path_target.distance(target) > NAV_GRID_CELL_SIZE
+        });
+        if (target_moved || navigation_path.repath <= 0.) && repaths < NAVIGATION_REPATHS_PER_FRAME
+        {
+            repaths += 1;
+            let radius = collision
+                .shape
+                .bounds(Vec2::ZERO)
+                .map_or(0., |(_, max)| max.max_element());
+            navigation_path.repath = NAVIGATION_REPATH_SECONDS;
+            navigation_path.path_target = Some(target);
+            navigation_path.waypoints = nav_grid
+                .find_path(position, target, radius)
+                .unwrap_or_default();
+        }
+        while navigation_path.waypoints.len() > 1
+            && navigation_path.waypoints[0].distance(position) < NAVIGATION_WAYPOINT_DISTANCE
+        {
+            navigation_path.waypoints.remove(0);
+        }
+    }
+}
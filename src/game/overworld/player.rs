@@ -10,17 +10,19 @@ pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<PlayerSpawnEvent>().add_systems(
-            Update,
-            (
-                player_spawn.before(BoatSystem::Spawn),
-                player_controls.before(BoatSystem::Update),
-                player_enter_town,
-                player_upgrade_attack,
-                player_invincibility,
-                player_damage,
-            ),
-        );
+        app.add_event::<PlayerSpawnEvent>()
+            .add_world_location("GameStart", WorldLocationCount::Single)
+            .add_systems(
+                Update,
+                (
+                    player_spawn.before(BoatSystem::Spawn),
+                    player_controls.before(BoatSystem::Update),
+                    player_enter_town,
+                    player_upgrade_attack,
+                    player_invincibility,
+                    player_damage,
+                ),
+            );
     }
 }
 
@@ -82,8 +84,7 @@ fn player_spawn(
 fn player_controls(
     mut query: Query<(&mut Boat, &GlobalTransform, &Player)>,
     mouse: Res<Mouse>,
-    input: Res<Input<MouseButton>>,
-    keys: Res<Input<KeyCode>>,
+    actions: Res<Actions>,
     cutscenes: Res<Cutscenes>,
     game_state: Res<GameState>,
 ) {
@@ -95,17 +96,22 @@ fn player_controls(
             boat.movement = Vec2::ZERO;
             continue;
         }
-        let mut mouse_aim = (mouse.position - global_transform.translation().truncate()) / 200.;
-        if mouse_aim.length_squared() == 0. {
-            mouse_aim = Vec2::new(0.1, 0.);
-        }
-        boat.direction = Vec2::X.angle_between(mouse_aim);
-        boat.movement = mouse_aim;
-        if !input.pressed(MouseButton::Left) {
-            boat.movement *= 0.0001;
+        if let Some(stick) = actions.movement() {
+            boat.direction = Vec2::X.angle_between(stick);
+            boat.movement = stick;
+        } else {
+            let mut mouse_aim = (mouse.position - global_transform.translation().truncate()) / 200.;
+            if mouse_aim.length_squared() == 0. {
+                mouse_aim = Vec2::new(0.1, 0.);
+            }
+            boat.direction = Vec2::X.angle_between(mouse_aim);
+            boat.movement = mouse_aim;
+            if !actions.pressed(Action::Move) {
+                boat.movement *= 0.0001;
+            }
         }
-        boat.dash = keys.pressed(KeyCode::Space);
-        if keys.just_pressed(KeyCode::F) {
+        boat.dash = actions.pressed(Action::Dash);
+        if actions.just_pressed(Action::Jam) {
             boat.shoot = !boat.shoot;
         }
         boat.attacks = game_state.attacks;
@@ -8,6 +8,10 @@ pub struct ThreatLevelPlugin;
 impl Plugin for ThreatLevelPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ThreatLevel>()
+            .add_world_location("ThreatLevelEasy", WorldLocationCount::Multiple)
+            .add_world_location("ThreatLevelMedium", WorldLocationCount::Multiple)
+            .add_world_location("ThreatLevelHard", WorldLocationCount::Multiple)
+            .add_world_location("ThreatLevelMidnight", WorldLocationCount::Multiple)
             .add_systems(Update, (threat_level_update, threat_level_debug));
     }
 }
@@ -23,6 +27,19 @@ pub enum ThreatLevel {
     Davy,
 }
 
+impl ThreatLevel {
+    pub fn all() -> [ThreatLevel; 6] {
+        [
+            Self::None,
+            Self::Easy,
+            Self::Medium,
+            Self::Hard,
+            Self::Midnight,
+            Self::Davy,
+        ]
+    }
+}
+
 fn threat_level_update(
     player_query: Query<&GlobalTransform, With<Player>>,
     mut threat_level: ResMut<ThreatLevel>,
@@ -40,7 +57,9 @@ fn threat_level_update(
     macro_rules! disable_threat_level_near_position {
         ($str:literal, $condition:expr) => {
             if $condition
-                && player_position.distance(world_locations.get_single_position($str)) < 500.
+                && world_locations
+                    .nearest($str, player_position)
+                    .map_or(false, |rect| player_position.distance(rect.position) < 500.)
             {
                 return;
             }
@@ -59,7 +78,7 @@ fn threat_level_update(
 
     macro_rules! threat_level {
         ($str:literal, $value:expr) => {
-            for rect in world_locations.get_multiple_rect($str).iter() {
+            for rect in world_locations.multiple($str).iter() {
                 if (CollisionShape::Rect { size: rect.size }).overlaps(
                     rect.position,
                     CollisionShape::Point,
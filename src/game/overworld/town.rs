@@ -10,6 +10,9 @@ impl Plugin for TownPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<TownSpawnEvent>()
             .add_systems(Update, (town_spawn, town_world_spawn, town_update));
+        for name in TOWN_NAMES.iter() {
+            app.add_world_location(&town_safe_name(name), WorldLocationCount::Optional);
+        }
     }
 }
 
@@ -67,6 +70,7 @@ fn town_spawn(
                         ..Default::default()
                     },
                     Transform2::from_xy(0., 135.).with_depth(DEPTH_LAYER_TOWN_NAME),
+                    LocalizedText::new(event.town.name_key()),
                 ));
             });
     }
@@ -79,14 +83,17 @@ fn town_world_spawn(
 ) {
     for _ in ev_spawn.iter() {
         for name in TOWN_NAMES.iter() {
-            let town_name = town_safe_name(name);
-            let positions = world_locations.get_multiple_positions(&town_name);
-            for position in positions {
-                ev_rubble_spawn.send(TownSpawnEvent {
-                    position,
-                    entity: None,
-                    town: TownData::build(name, world_locations.as_ref()),
-                });
+            match TownData::build(name, world_locations.as_ref()) {
+                Ok(town) => {
+                    ev_rubble_spawn.send(TownSpawnEvent {
+                        position: town.position,
+                        entity: None,
+                        town,
+                    });
+                }
+                // not every world has every town
+                Err(WorldLocationError::Missing(_)) => {}
+                Err(error) => error!("{}", error),
             }
         }
     }
@@ -35,22 +35,27 @@ fn trigger_check(
     transform_query: Query<&Transform2>,
 ) {
     for (trigger_entity, mut trigger) in trigger_query.iter_mut() {
-        let trigger_translation = if let Ok(transform) = transform_query.get(trigger_entity) {
-            transform.translation
-        } else {
-            continue;
-        };
-        for (player_entity, player_collision) in player_query.iter() {
-            let player_translation = if let Ok(transform) = transform_query.get(player_entity) {
-                transform.translation
+        let (trigger_translation, trigger_shape) =
+            if let Ok(transform) = transform_query.get(trigger_entity) {
+                (
+                    transform.translation,
+                    trigger.shape.rotated(transform.rotation),
+                )
             } else {
                 continue;
             };
-            trigger.inside = player_collision.shape.overlaps(
-                player_translation,
-                trigger.shape,
-                trigger_translation,
-            );
+        for (player_entity, player_collision) in player_query.iter() {
+            let (player_translation, player_shape) =
+                if let Ok(transform) = transform_query.get(player_entity) {
+                    (
+                        transform.translation,
+                        player_collision.shape.rotated(transform.rotation),
+                    )
+                } else {
+                    continue;
+                };
+            trigger.inside =
+                player_shape.overlaps(player_translation, trigger_shape, trigger_translation);
         }
     }
 }
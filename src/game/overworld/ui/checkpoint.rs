@@ -59,6 +59,7 @@ fn checkpoint_spawn(
                     },
                     Transform2::from_translation(CHECKPOINT_POSITION)
                         .with_depth(DEPTH_LAYER_CHECKPOINT_TEXT),
+                    LocalizedText::new("checkpoint.title"),
                 ));
             });
     }
@@ -1,5 +1,5 @@
 use crate::{common::prelude::*, game::prelude::*};
-use bevy::prelude::*;
+use bevy::{prelude::*, sprite::Anchor};
 
 const CONTROLS_UI_POSITION: Vec2 = Vec2::new(-260., -315.);
 const CONTROLS_UI_SCALE: f32 = 0.55;
@@ -15,6 +15,7 @@ impl Plugin for ControlsUiPlugin {
                 controls_ui_update_dash,
                 controls_ui_update_jam,
                 controls_ui_update_map,
+                controls_ui_update_binding,
             ),
         );
     }
@@ -32,6 +33,55 @@ pub struct ControlsUiJam;
 #[derive(Component)]
 pub struct ControlsUiMap;
 
+#[derive(Component)]
+pub struct ControlsUiBinding {
+    action: Action,
+    min_width: f32,
+}
+
+fn controls_ui_binding(
+    parent: &mut ChildBuilder,
+    asset_library: &AssetLibrary,
+    action: Action,
+    position: Vec2,
+    size: Vec2,
+) {
+    parent
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(size),
+                    ..Default::default()
+                },
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            },
+            Transform2::from_translation(position).with_depth(DEPTH_LAYER_UI_CONTROLS_BINDING),
+            ControlsUiBinding {
+                action,
+                min_width: size.x,
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_library.font_bold.clone(),
+                            font_size: 64.0,
+                            color: Color::rgb_u8(64, 64, 64),
+                        },
+                    )
+                    .with_alignment(TextAlignment::Center),
+                    text_anchor: Anchor::Center,
+                    ..Default::default()
+                },
+                Transform2::new().with_depth(DEPTH_LAYER_UI_CONTROLS_BINDING_TEXT),
+            ));
+        });
+}
+
 fn controls_ui_spawn(
     mut ev_spawn: EventReader<ControlsUiSpawnEvent>,
     mut commands: Commands,
@@ -56,16 +106,26 @@ fn controls_ui_spawn(
                         VisibilityBundle::default(),
                     ))
                     .with_children(|parent| {
-                        parent.spawn((
-                            SpriteBundle {
-                                texture: asset_library.sprite_controls_dash.clone(),
-                                ..Default::default()
-                            },
-                            Transform2::from_xy(0., 0.)
-                                .with_scale(Vec2::ONE * 0.5)
-                                .with_depth(DEPTH_LAYER_UI_CONTROLS),
-                            ControlsUiDash,
-                        ));
+                        parent
+                            .spawn((
+                                SpriteBundle {
+                                    texture: asset_library.sprite_controls_dash.clone(),
+                                    ..Default::default()
+                                },
+                                Transform2::from_xy(0., 0.)
+                                    .with_scale(Vec2::ONE * 0.5)
+                                    .with_depth(DEPTH_LAYER_UI_CONTROLS),
+                                ControlsUiDash,
+                            ))
+                            .with_children(|parent| {
+                                controls_ui_binding(
+                                    parent,
+                                    asset_library.as_ref(),
+                                    Action::Dash,
+                                    Vec2::new(-55., 143.),
+                                    Vec2::new(220., 110.),
+                                );
+                            });
                         parent
                             .spawn((
                                 SpriteSheetBundle {
@@ -86,18 +146,35 @@ fn controls_ui_spawn(
                                     Transform2::from_xy(-100., 145.)
                                         .with_depth(DEPTH_LAYER_UI_CONTROLS_KEY),
                                 ));
+                                controls_ui_binding(
+                                    parent,
+                                    asset_library.as_ref(),
+                                    Action::Jam,
+                                    Vec2::new(-100., 145.),
+                                    Vec2::new(150., 150.),
+                                );
+                            });
+                        parent
+                            .spawn((
+                                SpriteBundle {
+                                    texture: asset_library.sprite_controls_map.clone(),
+                                    visibility: Visibility::Hidden,
+                                    ..Default::default()
+                                },
+                                Transform2::from_xy(480., 0.)
+                                    .with_scale(Vec2::ONE * 0.5)
+                                    .with_depth(DEPTH_LAYER_UI_CONTROLS),
+                                ControlsUiMap,
+                            ))
+                            .with_children(|parent| {
+                                controls_ui_binding(
+                                    parent,
+                                    asset_library.as_ref(),
+                                    Action::OpenMap,
+                                    Vec2::new(-105., 140.),
+                                    Vec2::new(120., 120.),
+                                );
                             });
-                        parent.spawn((
-                            SpriteBundle {
-                                texture: asset_library.sprite_controls_map.clone(),
-                                visibility: Visibility::Hidden,
-                                ..Default::default()
-                            },
-                            Transform2::from_xy(480., 0.)
-                                .with_scale(Vec2::ONE * 0.5)
-                                .with_depth(DEPTH_LAYER_UI_CONTROLS),
-                            ControlsUiMap,
-                        ));
                     });
             });
     }
@@ -157,3 +234,36 @@ pub fn controls_ui_update_map(
         };
     }
 }
+
+/// Covers the key printed on the control sprites when the player rebinds it or uses a gamepad.
+pub fn controls_ui_update_binding(
+    mut query: Query<(&ControlsUiBinding, &mut Visibility, &mut Sprite, &Children)>,
+    mut text_query: Query<&mut Text>,
+    input_map: Res<InputMap>,
+    actions: Res<Actions>,
+) {
+    let gamepad = actions.gamepad();
+    for (binding, mut visibility, mut sprite, children) in query.iter_mut() {
+        let visible = gamepad || !input_map.is_default(binding.action, false);
+        *visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if !visible {
+            continue;
+        }
+        let label = input_map.label(binding.action, gamepad);
+        let label_width = label.chars().count() as f32 * 36. + 40.;
+        if let Some(size) = sprite.custom_size.as_mut() {
+            size.x = binding.min_width.max(label_width);
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                if text.sections[0].value != label {
+                    text.sections[0].value = label.clone();
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,6 @@
 use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
-use rand::{prelude::*, rngs::StdRng, SeedableRng};
 
 const HEALTH_UI_POSITION: Vec2 = Vec2::new(-580., -300.);
 const HEALTH_UI_SCALE: f32 = 0.55;
@@ -26,11 +25,11 @@ pub struct HealthBottle {
     settings: BottleSettings,
 }
 
-fn random_bottle_settings() -> BottleSettings {
+fn random_bottle_settings(game_rng: &mut GameRng) -> BottleSettings {
     BottleSettings {
-        y: rand::random::<f32>() * 0.05 - 0.025,
-        scale: Vec2::ONE * (0.95 + rand::random::<f32>() * 0.1),
-        rotation: (rand::random::<f32>() * 20. - 10.).to_radians(),
+        y: game_rng.f32(RngStream::Fx) * 0.05 - 0.025,
+        scale: Vec2::ONE * (0.95 + game_rng.f32(RngStream::Fx) * 0.1),
+        rotation: (game_rng.f32(RngStream::Fx) * 20. - 10.).to_radians(),
     }
 }
 
@@ -45,6 +44,7 @@ fn health_ui_spawn(
     mut ev_spawn: EventReader<HealthUiSpawnEvent>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     for _ in ev_spawn.iter() {
         commands
@@ -66,10 +66,9 @@ fn health_ui_spawn(
                     ))
                     .with_children(|parent| {
                         let amt = 10;
-                        let mut rng = StdRng::from_seed(Default::default());
                         for i in 0..amt {
-                            let settings = random_bottle_settings();
-                            let brightness = 0.7 + rng.gen::<f32>() * 0.3;
+                            let settings = random_bottle_settings(&mut game_rng);
+                            let brightness = 0.7 + game_rng.f32(RngStream::Fx) * 0.3;
                             parent.spawn((
                                 SpriteSheetBundle {
                                     sprite: TextureAtlasSprite {
@@ -108,6 +107,7 @@ fn health_ui_bottle_update(
     mut query: Query<(&mut TextureAtlasSprite, &mut Transform2, &mut HealthBottle)>,
     player_query: Query<&Health, With<Player>>,
     time: Res<Time>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let player_health = if let Ok(health) = player_query.get_single() {
         health.value as f32 / health.max as f32
@@ -116,8 +116,8 @@ fn health_ui_bottle_update(
     };
     for (mut sprite, mut transform, mut bottle) in query.iter_mut() {
         if player_health < bottle.last_health {
-            bottle.settings = random_bottle_settings();
-            transform.translation.y += rand::random::<f32>() * 30.;
+            bottle.settings = random_bottle_settings(&mut game_rng);
+            transform.translation.y += game_rng.f32(RngStream::Fx) * 30.;
         }
         let lerp_amt = time.delta_seconds() * 2.2_f32;
         transform.translation.y = (lerp_amt * 2.).lerp(transform.translation.y, bottle.settings.y);
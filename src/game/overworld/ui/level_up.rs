@@ -1,4 +1,5 @@
 use crate::common::prelude::*;
+use crate::game::prelude::*;
 use audio_plus::prelude::*;
 use bevy::{prelude::*, sprite::Anchor};
 
@@ -28,6 +29,8 @@ fn level_up_spawn(
     asset_library: Res<AssetLibrary>,
     time: Res<Time>,
     mut state: ResMut<LevelUpState>,
+    game_state: Res<GameState>,
+    localization: Res<Localization>,
 ) {
     for _ in ev_spawn.iter() {
         if time.elapsed_seconds() > state.last_spawn_time + 4.5 {
@@ -61,7 +64,7 @@ fn level_up_spawn(
                     parent.spawn((
                         Text2dBundle {
                             text: Text::from_section(
-                                "Level Up",
+                                localization.text("level_up.title"),
                                 TextStyle {
                                     font: asset_library.font_bold.clone(),
                                     font_size: 62.0,
@@ -78,7 +81,7 @@ fn level_up_spawn(
                     parent.spawn((
                         Text2dBundle {
                             text: Text::from_section(
-                                "Spend skill points at town",
+                                localization.plural("level_up.spend", game_state.skill_points),
                                 TextStyle {
                                     font: asset_library.font_bold.clone(),
                                     font_size: 22.0,
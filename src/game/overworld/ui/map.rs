@@ -34,6 +34,8 @@ fn map_open(
     mut commands: Commands,
     map_builder: Res<MapBuilder>,
     asset_library: Res<AssetLibrary>,
+    game_state: Res<GameState>,
+    localization: Res<Localization>,
 ) {
     commands
         .spawn((
@@ -59,6 +61,27 @@ fn map_open(
                     .with_scale(Vec2::ONE * 0.25)
                     .with_depth(DEPTH_LAYER_MAP_COMPASS),
             ));
+            // the run seed, which is what the main menu takes to replay this world
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        localization
+                            .text("map.seed")
+                            .replace("{seed}", &game_state.seed.to_string()),
+                        TextStyle {
+                            font: asset_library.font_bold.clone(),
+                            font_size: 48.,
+                            color: Color::BLACK,
+                        },
+                    )
+                    .with_alignment(TextAlignment::Center),
+                    text_anchor: Anchor::Center,
+                    ..Default::default()
+                },
+                Transform2::from_xy(0., -265.)
+                    .with_scale(Vec2::ONE * 0.5)
+                    .with_depth(DEPTH_LAYER_MAP_COMPASS),
+            ));
             parent
                 .spawn((
                     SpriteBundle {
@@ -181,16 +204,12 @@ fn map_open(
 }
 
 fn map_wait_for_close(
-    input: Res<Input<KeyCode>>,
-    mouse: Res<Input<MouseButton>>,
+    actions: Res<Actions>,
     mut ev_continue: EventWriter<CutsceneContinueEvent<MapCutscene>>,
     asset_library: Res<AssetLibrary>,
     mut commands: Commands,
 ) {
-    if input.just_pressed(KeyCode::Space)
-        || mouse.just_pressed(MouseButton::Left)
-        || input.just_pressed(KeyCode::M)
-    {
+    if actions.just_pressed(Action::Advance) || actions.just_pressed(Action::OpenMap) {
         ev_continue.send_default();
 
         commands.spawn((
@@ -220,14 +239,14 @@ pub struct MapObjective {
 }
 
 fn map_input(
-    input: Res<Input<KeyCode>>,
+    actions: Res<Actions>,
     cutscenes: Res<Cutscenes>,
     state_time: Res<StateTime<AppState>>,
     mut ev_cutscene: EventWriter<CutsceneStartEvent<MapCutscene>>,
     game_state: Res<GameState>,
 ) {
     if state_time.time > 1.
-        && input.just_pressed(KeyCode::M)
+        && actions.just_pressed(Action::OpenMap)
         && !cutscenes.running()
         && game_state.dangerous_seas
         && !game_state.quests.fighting()
@@ -258,11 +277,13 @@ fn map_update_objective(
     world_locations: Res<WorldLocations>,
     time: Res<Time>,
 ) {
-    let objective_position = if let Some(objective_marker) = game_state.quests.marker() {
-        world_locations.get_single_position(objective_marker) + Vec2::new(0., 300.)
-    } else {
-        Vec2::new(99999., 99999.)
-    };
+    let objective_position = game_state
+        .quests
+        .marker()
+        .and_then(|objective_marker| world_locations.single_position(objective_marker).ok())
+        .map_or(Vec2::new(99999., 99999.), |position| {
+            position + Vec2::new(0., 300.)
+        });
     for (mut map_player_transform, map_objective) in query.iter_mut() {
         map_player_transform.translation = map_builder.world_to_map(objective_position);
         map_player_transform.scale = Vec2::ONE * map_objective.scale
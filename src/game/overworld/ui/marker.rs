@@ -85,7 +85,9 @@ fn marker_update(
         Vec2::ZERO
     };
     let objective_position = if let Some(objective_marker) = game_state.quests.marker() {
-        Some(world_locations.get_single_position(objective_marker))
+        world_locations
+            .nearest(objective_marker, camera_position)
+            .map(|rect| rect.position)
     } else {
         None
     };
@@ -3,6 +3,11 @@ use crate::game::prelude::*;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
 
+/// Levels of the hand-made world further than this from the camera aren't spawned. The project
+/// is currently a single level covering the whole map, so it always stays spawned and this only
+/// takes effect once the world is split into several levels.
+const WORLD_STREAM_DISTANCE: f32 = 3000.;
+
 pub struct WorldPlugin;
 
 impl Plugin for WorldPlugin {
@@ -22,21 +27,35 @@ fn world_spawn(
     mut ev_spawn: EventReader<WorldLoadEvent>,
     mut commands: Commands,
     mut ev_ldtk_spawn: EventWriter<LdtkSpawnEvent>,
+    mut ev_archipelago_spawn: EventWriter<ArchipelagoSpawnEvent>,
     mut ev_ocean_spawn: EventWriter<OceanSpawnEvent>,
     asset_library: Res<AssetLibrary>,
+    localization: Res<Localization>,
+    input_map: Res<InputMap>,
+    game_state: Res<GameState>,
 ) {
     for _ in ev_spawn.iter() {
         ev_ocean_spawn.send_default();
-        ev_ldtk_spawn.send(LdtkSpawnEvent {
-            entity: None,
-            asset: asset_library.level.clone(),
-            position: Vec2::new(0., 0.),
-        });
+        if let Some(seed) = game_state.world_seed {
+            ev_archipelago_spawn.send(ArchipelagoSpawnEvent { seed });
+        } else {
+            ev_ldtk_spawn.send(LdtkSpawnEvent {
+                entity: None,
+                asset: asset_library.level.clone(),
+                position: Vec2::new(0., 0.),
+                stream_distance: Some(WORLD_STREAM_DISTANCE),
+                ..Default::default()
+            });
+        }
 
         commands.spawn((
             Text2dBundle {
                 text: Text::from_section(
-                    "Hold left mouse button to move!\nPress space to dash!\nPress F to start jamming!",
+                    localization
+                        .text("world.tutorial")
+                        .replace("{move}", &input_map.label(Action::Move, false))
+                        .replace("{dash}", &input_map.label(Action::Dash, false))
+                        .replace("{jam}", &input_map.label(Action::Jam, false)),
                     TextStyle {
                         font: asset_library.font_bold.clone(),
                         font_size: 48.0,
@@ -1,7 +1,7 @@
 pub use super::{
-    all_dialogue::*,
     data::{band_members::BandMember, town_data::TownData},
     overworld::{
+        archipelago::{Archipelago, ArchipelagoLocation, ArchipelagoSpawnEvent},
         attacks::{
             bombs::{Bombs, BombsLevel},
             dash_attack::{Dash, DashAttack},
@@ -28,18 +28,19 @@ pub use super::{
         },
         damage_flash::DamageFlashSpawnEvent,
         damage_rum::DamageRumSpawnEvent,
+        enemy::{Enemy, EnemySpawnEvent, EnemySystem},
+        enemy_ai::EnemyAi,
         enemy_spawns::DespawnSpawnedEntitiesEvent,
         entities::rubble::{Rubble, RubbleSpawnEvent},
         experience::{Experience, ExperienceSpawnEvent},
         health::Health,
         healthbar::{Healthbar, HealthbarSpawnEvent, HealthbarSystem},
+        navigation::{NavigationPath, NavigationSystem},
         ocean::{Ocean, OceanSpawnEvent},
-        octopus::{Octopus, OctopusLevel, OctopusSpawnEvent, OctopusSystem},
         player::{Player, PlayerSpawnEvent},
         threat_level::ThreatLevel,
         town::{Town, TownSpawnEvent},
         trigger::Trigger,
-        turtle::{Turtle, TurtleLevel, TurtleSpawnEvent, TurtleSystem},
         ui::{
             boss_healthbar::BossHealthbarSpawnEvent, checkpoint::CheckpointSpawnEvent,
             level_up::LevelUpSpawnEvent, OverworldUiSpawnEvent,
@@ -49,5 +50,6 @@ pub use super::{
         OverworldEnterEvent, OverworldPlugin, WorldAmbienceSoundStopEvent,
     },
     quests::{Quest, QuestBarkeepEvent, QuestMayorEvent, Quests},
+    save::{load_game, save_game, SaveError, SAVE_SLOT_DEFAULT},
     state::GameState,
 };
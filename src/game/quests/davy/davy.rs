@@ -63,17 +63,27 @@ fn davy_spawn(
     mut overworld_camera: ResMut<OverworldCamera>,
     mut ev_boss_healthbar_spawn: EventWriter<BossHealthbarSpawnEvent>,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let spawn_position = world_locations.get_single_position("DavySpawn");
     for _ in ev_spawn.iter() {
+        let (spawn_position, move_to) = match (
+            world_locations.single_position("DavySpawn"),
+            world_locations.single_position("DavyMoveTo"),
+        ) {
+            (Ok(spawn_position), Ok(move_to)) => (spawn_position, move_to),
+            (Err(error), _) | (_, Err(error)) => {
+                error!("{}", error);
+                continue;
+            }
+        };
         let stats = davy_stats_by_health(1.);
         ev_enemies_despawn.send_default();
         let entity = commands
             .spawn((
                 Davy {
-                    target: world_locations.get_single_position("DavyMoveTo"),
+                    target: move_to,
                     angle: 0.,
-                    adjust_angle_chance: TimedChance::new(),
+                    adjust_angle_chance: game_rng.timed_chance(RngStream::Ai),
                 },
                 AutoDamage {
                     despawn: true,
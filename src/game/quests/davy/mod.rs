@@ -1,6 +1,7 @@
 use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use self::davy::DavySpawnEvent;
 
@@ -10,16 +11,20 @@ impl Plugin for DavyQuestPlugin {
     fn build(&self, app: &mut App) {
         app.add_cutscene::<Davy1Cutscene>()
             .add_cutscene::<Davy2Cutscene>()
+            .add_world_location("DavyTrigger", WorldLocationCount::Multiple)
+            .add_world_location("DavySpawn", WorldLocationCount::Single)
+            .add_world_location("DavyMoveTo", WorldLocationCount::Single)
+            .add_world_location("DavyArena", WorldLocationCount::Single)
             .add_plugins((davy::DavyPlugin, trigger::DavyTriggerPlugin));
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct DavyQuest {
     pub stage: DavyQuestStage,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub enum DavyQuestStage {
     #[default]
     TalkToMayor,
@@ -29,6 +34,29 @@ pub enum DavyQuestStage {
     Dialogue2,
 }
 
+impl DavyQuestStage {
+    pub fn all() -> &'static [DavyQuestStage] {
+        &[
+            Self::TalkToMayor,
+            Self::TalkedToMayor,
+            Self::Dialogue1,
+            Self::Fight,
+            Self::Dialogue2,
+        ]
+    }
+
+    /// Name dialogue conditions match on, which stays the same when a variant is renamed.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TalkToMayor => "talk_to_mayor",
+            Self::TalkedToMayor => "talked_to_mayor",
+            Self::Dialogue1 => "dialogue1",
+            Self::Fight => "fight",
+            Self::Dialogue2 => "dialogue2",
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, Resource)]
 pub struct Davy1Cutscene {
     pub boat: Option<Entity>,
@@ -51,12 +79,12 @@ fn davy1_init1(
 ) {
     ev_davy_spawn.send_default();
 
-    for (p, t) in DAVY1.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("davy1");
 
-    let rect = world_locations.get_single_rect("DavyArena");
-    overworld_camera.arena_enable(rect.position, rect.size);
+    match world_locations.single("DavyArena") {
+        Ok(rect) => overworld_camera.arena_enable(rect.position, rect.size),
+        Err(error) => error!("{}", error),
+    }
 }
 
 fn davy1_cleanup(mut game_state: ResMut<GameState>) {
@@ -82,9 +110,7 @@ impl Cutscene for Davy2Cutscene {
 
 fn davy2_init1(mut dialogue: ResMut<Dialogue>, mut game_state: ResMut<GameState>) {
     game_state.attacks.kraken = 1;
-    for (p, t) in DAVY2.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("davy2");
 }
 
 fn davy2_fade_out(mut screen_fade: ResMut<ScreenFade>) {
@@ -21,7 +21,7 @@ fn davy_trigger_world_spawn(
     world_locations: Res<WorldLocations>,
 ) {
     for _ in ev_spawn.iter() {
-        let triggers = world_locations.get_multiple_rect("DavyTrigger");
+        let triggers = world_locations.multiple("DavyTrigger");
         for trigger in triggers {
             commands.spawn((
                 TransformBundle::default(),
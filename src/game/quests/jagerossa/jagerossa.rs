@@ -60,19 +60,29 @@ fn jagerossa_spawn(
     mut overworld_camera: ResMut<OverworldCamera>,
     mut ev_boss_healthbar_spawn: EventWriter<BossHealthbarSpawnEvent>,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let spawn_position = world_locations.get_single_position("JagerossaSpawn");
     for _ in ev_spawn.iter() {
+        let (spawn_position, move_to) = match (
+            world_locations.single_position("JagerossaSpawn"),
+            world_locations.single_position("JagerossaMoveTo"),
+        ) {
+            (Ok(spawn_position), Ok(move_to)) => (spawn_position, move_to),
+            (Err(error), _) | (_, Err(error)) => {
+                error!("{}", error);
+                continue;
+            }
+        };
         let stats = jagerossa_stats_by_health(1.);
         let entity = commands
             .spawn((
                 Jagerossa {
-                    target: world_locations.get_single_position("JagerossaMoveTo"),
+                    target: move_to,
                     angle: 0.,
-                    adjust_angle_chance: TimedChance::new(),
+                    adjust_angle_chance: game_rng.timed_chance(RngStream::Ai),
                     backoff_time: 1.5,
                     backoff_dir: Vec2::new(1., -1.),
-                    backoff_chance: TimedChance::new(),
+                    backoff_chance: game_rng.timed_chance(RngStream::Ai),
                 },
                 AutoDamage {
                     despawn: true,
@@ -114,6 +124,7 @@ fn jagerossa_move(
     )>,
     cutscenes: Res<Cutscenes>,
     time: Res<Time>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let player_position = if let Ok(player_transform) = queries.p1().get_single() {
         player_transform.translation().truncate()
@@ -144,7 +155,7 @@ fn jagerossa_move(
             {
                 jagerossa.backoff_dir =
                     (global_transform.translation().truncate() - player_position).normalize();
-                if rand::random() {
+                if game_rng.bool(RngStream::Ai) {
                     jagerossa.backoff_dir = jagerossa.backoff_dir.perp();
                 } else {
                     jagerossa.backoff_dir = -jagerossa.backoff_dir.perp();
@@ -2,6 +2,7 @@ use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
 use jagerossa::JagerossaSpawnEvent;
+use serde::{Deserialize, Serialize};
 
 pub struct JagerossaQuestPlugin;
 
@@ -9,16 +10,20 @@ impl Plugin for JagerossaQuestPlugin {
     fn build(&self, app: &mut App) {
         app.add_cutscene::<Jagerossa1Cutscene>()
             .add_cutscene::<Jagerossa2Cutscene>()
+            .add_world_location("JagerossaTrigger", WorldLocationCount::Multiple)
+            .add_world_location("JagerossaSpawn", WorldLocationCount::Single)
+            .add_world_location("JagerossaMoveTo", WorldLocationCount::Single)
+            .add_world_location("JagerossaArena", WorldLocationCount::Single)
             .add_plugins((jagerossa::JagerossaPlugin, trigger::JagerossaTriggerPlugin));
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct JagerossaQuest {
     pub stage: JagerossaQuestStage,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub enum JagerossaQuestStage {
     #[default]
     ControlsTutorial,
@@ -27,6 +32,27 @@ pub enum JagerossaQuestStage {
     Dialogue2,
 }
 
+impl JagerossaQuestStage {
+    pub fn all() -> &'static [JagerossaQuestStage] {
+        &[
+            Self::ControlsTutorial,
+            Self::Dialogue1,
+            Self::Fight,
+            Self::Dialogue2,
+        ]
+    }
+
+    /// Name dialogue conditions match on, which stays the same when a variant is renamed.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ControlsTutorial => "controls_tutorial",
+            Self::Dialogue1 => "dialogue1",
+            Self::Fight => "fight",
+            Self::Dialogue2 => "dialogue2",
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, Resource)]
 pub struct Jagerossa1Cutscene {
     pub boat: Option<Entity>,
@@ -49,12 +75,12 @@ fn jagerossa1_init1(
 ) {
     ev_jagerossa_spawn.send_default();
 
-    for (p, t) in JAGEROSSA1.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("jagerossa1");
 
-    let rect = world_locations.get_single_rect("JagerossaArena");
-    overworld_camera.arena_enable(rect.position, rect.size);
+    match world_locations.single("JagerossaArena") {
+        Ok(rect) => overworld_camera.arena_enable(rect.position, rect.size),
+        Err(error) => error!("{}", error),
+    }
 }
 
 fn jagerossa1_cleanup(mut game_state: ResMut<GameState>) {
@@ -80,9 +106,7 @@ impl Cutscene for Jagerossa2Cutscene {
 
 fn jagerossa2_init1(mut dialogue: ResMut<Dialogue>, mut game_state: ResMut<GameState>) {
     game_state.attacks.shotgun_cannons = 1;
-    for (p, t) in JAGEROSSA2.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("jagerossa2");
 }
 
 fn jagerossa2_cleanup(
@@ -94,11 +118,16 @@ fn jagerossa2_cleanup(
 ) {
     if let Ok((player_entity, mut player_boat)) = player_query.get_single_mut() {
         player_boat.shoot = false;
-        commands
-            .entity(player_entity)
-            .insert(CharacterControllerDestination {
-                target: world_locations.get_single_position("Portallica") + Vec2::new(0., -100.),
-            });
+        match world_locations.single_position("Portallica") {
+            Ok(position) => {
+                commands
+                    .entity(player_entity)
+                    .insert(CharacterControllerDestination {
+                        target: position + Vec2::new(0., -100.),
+                    });
+            }
+            Err(error) => error!("{}", error),
+        }
     }
     game_state.quests.next();
     overworld_camera.reset();
@@ -24,7 +24,7 @@ fn jagerossa_trigger_world_spawn(
     world_locations: Res<WorldLocations>,
 ) {
     for _ in ev_spawn.iter() {
-        let triggers = world_locations.get_multiple_rect("JagerossaTrigger");
+        let triggers = world_locations.multiple("JagerossaTrigger");
         for trigger in triggers {
             commands.spawn((
                 TransformBundle::default(),
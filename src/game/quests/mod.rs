@@ -8,6 +8,7 @@ use crate::game::prelude::*;
 use crate::{common::prelude::*, DEV_BUILD};
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContext};
+use serde::{Deserialize, Serialize};
 
 use super::town::outside::rum_refill::RumRefillCutscene;
 
@@ -30,12 +31,10 @@ impl Plugin for QuestsPlugin {
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Quests {
     pub active_quest: Quest,
-    pub mayor_dialogue: u32,
-    pub mayor_after_dialogue: u32,
-    pub barkeep_dialogue: u32,
     pub talked_to_barkeep: bool,
     pub endgame_town_dialogue: bool,
     pub upgrades_dialogue: bool,
@@ -216,7 +215,7 @@ impl Quests {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Quest {
     Jagerossa(JagerossaQuest),
     Ringo(RingoQuest),
@@ -235,6 +234,59 @@ impl Quest {
             Self::End => Self::End,
         }
     }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Jagerossa(..) => "jagerossa",
+            Self::Ringo(..) => "ringo",
+            Self::Plank(..) => "plank",
+            Self::Davy(..) => "davy",
+            Self::End => "end",
+        }
+    }
+
+    pub fn stage_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Jagerossa(quest) => Some(quest.stage.name()),
+            Self::Ringo(quest) => Some(quest.stage.name()),
+            Self::Plank(quest) => Some(quest.stage.name()),
+            Self::Davy(quest) => Some(quest.stage.name()),
+            Self::End => None,
+        }
+    }
+
+    /// Every quest, each at its first stage.
+    pub fn all() -> Vec<Quest> {
+        vec![
+            Self::Jagerossa(JagerossaQuest::default()),
+            Self::Ringo(RingoQuest::default()),
+            Self::Plank(PlankQuest::default()),
+            Self::Davy(DavyQuest::default()),
+            Self::End,
+        ]
+    }
+
+    pub fn stage_names(&self) -> Vec<&'static str> {
+        match self {
+            Self::Jagerossa(..) => JagerossaQuestStage::all()
+                .iter()
+                .map(|stage| stage.name())
+                .collect(),
+            Self::Ringo(..) => RingoQuestStage::all()
+                .iter()
+                .map(|stage| stage.name())
+                .collect(),
+            Self::Plank(..) => PlankQuestStage::all()
+                .iter()
+                .map(|stage| stage.name())
+                .collect(),
+            Self::Davy(..) => DavyQuestStage::all()
+                .iter()
+                .map(|stage| stage.name())
+                .collect(),
+            Self::End => vec![],
+        }
+    }
 }
 
 impl Default for Quest {
@@ -254,27 +306,21 @@ fn quests_mayor(
         match &mut game_state.quests.active_quest {
             Quest::Ringo(quest) => {
                 if matches!(quest.stage, RingoQuestStage::TalkToMayor) {
-                    for (p, t) in RINGO_MAYOR.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
+                    dialogue.add_conversation("ringo_mayor");
                     quest.stage = RingoQuestStage::TalkedToMayor;
                     fallback_dialogue = false;
                 }
             }
             Quest::Plank(quest) => {
                 if matches!(quest.stage, PlankQuestStage::TalkToMayor) {
-                    for (p, t) in PLANK_MAYOR.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
+                    dialogue.add_conversation("plank_mayor");
                     quest.stage = PlankQuestStage::TalkedToMayor;
                     fallback_dialogue = false;
                 }
             }
             Quest::Davy(quest) => {
                 if matches!(quest.stage, DavyQuestStage::TalkToMayor) {
-                    for (p, t) in DAVY_MAYOR.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
+                    dialogue.add_conversation("davy_mayor");
                     quest.stage = DavyQuestStage::TalkedToMayor;
                     fallback_dialogue = false;
                 }
@@ -282,71 +328,7 @@ fn quests_mayor(
             _ => {}
         }
         if fallback_dialogue {
-            if game_state.quests.end() {
-                match game_state.quests.mayor_after_dialogue % 6 {
-                    0 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY1.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    1 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY2.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    2 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY3.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    3 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY4.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    4 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY5.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    _ => {
-                        for (p, t) in MAYOR_AFTER_VICTORY6.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                }
-                game_state.quests.mayor_after_dialogue =
-                    (game_state.quests.mayor_after_dialogue + 1) % 6;
-            } else {
-                match game_state.quests.mayor_dialogue % 5 {
-                    0 => {
-                        for (p, t) in MAYOR_RANDOM1.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    1 => {
-                        for (p, t) in MAYOR_RANDOM2.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    2 => {
-                        for (p, t) in MAYOR_RANDOM3.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    3 => {
-                        for (p, t) in MAYOR_RANDOM4.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    _ => {
-                        for (p, t) in MAYOR_RANDOM5.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                }
-                game_state.quests.mayor_dialogue = (game_state.quests.mayor_dialogue + 1) % 5;
-            }
+            dialogue.add_conversation("mayor");
         }
     }
 }
@@ -362,45 +344,11 @@ fn quests_barkeep(
         let need_rum = game_state.health != game_state.health_max;
         if !game_state.quests.talked_to_barkeep {
             game_state.quests.talked_to_barkeep = true;
-            for (p, t) in BARKEEP1.iter() {
-                dialogue.add_text(*p, String::from(*t));
-            }
+            dialogue.add_conversation("barkeep1");
             fallback_dialogue = false;
         }
         if fallback_dialogue && !need_rum {
-            match game_state.quests.barkeep_dialogue % 6 {
-                0 => {
-                    for (p, t) in BARKEEP_RANDOM1.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                1 => {
-                    for (p, t) in BARKEEP_RANDOM2.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                2 => {
-                    for (p, t) in BARKEEP_RANDOM3.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                3 => {
-                    for (p, t) in BARKEEP_RANDOM4.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                4 => {
-                    for (p, t) in BARKEEP_RANDOM5.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                _ => {
-                    for (p, t) in BARKEEP_RANDOM6.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-            }
-            game_state.quests.barkeep_dialogue = (game_state.quests.barkeep_dialogue + 1) % 6;
+            dialogue.add_conversation("barkeep_idle");
         }
         if need_rum {
             ev_rum_refill_cutscene.send_default();
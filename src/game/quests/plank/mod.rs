@@ -1,6 +1,7 @@
 use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use self::plank::PlankSpawnEvent;
 
@@ -10,16 +11,20 @@ impl Plugin for PlankQuestPlugin {
     fn build(&self, app: &mut App) {
         app.add_cutscene::<Plank1Cutscene>()
             .add_cutscene::<Plank2Cutscene>()
+            .add_world_location("PlankTrigger", WorldLocationCount::Multiple)
+            .add_world_location("PlankSpawn", WorldLocationCount::Single)
+            .add_world_location("PlankMoveTo", WorldLocationCount::Single)
+            .add_world_location("PlankArena", WorldLocationCount::Single)
             .add_plugins((plank::PlankPlugin, trigger::PlankTriggerPlugin));
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct PlankQuest {
     pub stage: PlankQuestStage,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub enum PlankQuestStage {
     #[default]
     TalkToMayor,
@@ -29,6 +34,29 @@ pub enum PlankQuestStage {
     Dialogue2,
 }
 
+impl PlankQuestStage {
+    pub fn all() -> &'static [PlankQuestStage] {
+        &[
+            Self::TalkToMayor,
+            Self::TalkedToMayor,
+            Self::Dialogue1,
+            Self::Fight,
+            Self::Dialogue2,
+        ]
+    }
+
+    /// Name dialogue conditions match on, which stays the same when a variant is renamed.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TalkToMayor => "talk_to_mayor",
+            Self::TalkedToMayor => "talked_to_mayor",
+            Self::Dialogue1 => "dialogue1",
+            Self::Fight => "fight",
+            Self::Dialogue2 => "dialogue2",
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, Resource)]
 pub struct Plank1Cutscene {
     pub boat: Option<Entity>,
@@ -51,12 +79,12 @@ fn plank1_init1(
 ) {
     ev_plank_spawn.send_default();
 
-    for (p, t) in PLANK1.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("plank1");
 
-    let rect = world_locations.get_single_rect("PlankArena");
-    overworld_camera.arena_enable(rect.position, rect.size);
+    match world_locations.single("PlankArena") {
+        Ok(rect) => overworld_camera.arena_enable(rect.position, rect.size),
+        Err(error) => error!("{}", error),
+    }
 }
 
 fn plank1_cleanup(mut game_state: ResMut<GameState>) {
@@ -83,9 +111,7 @@ impl Cutscene for Plank2Cutscene {
 
 fn plank2_init1(mut dialogue: ResMut<Dialogue>, mut game_state: ResMut<GameState>) {
     game_state.attacks.bombs = 1;
-    for (p, t) in PLANK2.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("plank2");
 }
 
 fn plank2_fade_out(mut screen_fade: ResMut<ScreenFade>) {
@@ -100,7 +126,10 @@ fn plank2_cleanup(
 ) {
     overworld_camera.reset();
     game_state.quests.next();
-    game_state.town = TownData::build("Iron Maiden's Cove", world_locations.as_ref());
+    match TownData::build("Iron Maiden's Cove", world_locations.as_ref()) {
+        Ok(town) => game_state.town = town,
+        Err(error) => error!("{}", error),
+    }
     app_state.set(AppState::TownOutside);
 }
 
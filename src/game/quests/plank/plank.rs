@@ -67,20 +67,30 @@ fn plank_spawn(
     mut overworld_camera: ResMut<OverworldCamera>,
     mut ev_boss_healthbar_spawn: EventWriter<BossHealthbarSpawnEvent>,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let spawn_position = world_locations.get_single_position("PlankSpawn");
     for _ in ev_spawn.iter() {
+        let (spawn_position, move_to) = match (
+            world_locations.single_position("PlankSpawn"),
+            world_locations.single_position("PlankMoveTo"),
+        ) {
+            (Ok(spawn_position), Ok(move_to)) => (spawn_position, move_to),
+            (Err(error), _) | (_, Err(error)) => {
+                error!("{}", error);
+                continue;
+            }
+        };
         let stats = plank_stats_by_health(1.);
         ev_enemies_despawn.send_default();
         let entity = commands
             .spawn((
                 Plank {
-                    target: world_locations.get_single_position("PlankMoveTo"),
+                    target: move_to,
                     angle: 0.,
-                    adjust_angle_chance: TimedChance::new(),
+                    adjust_angle_chance: game_rng.timed_chance(RngStream::Ai),
                     backoff_time: 2.0,
                     backoff_dir: Vec2::new(-1., 0.),
-                    backoff_chance: TimedChance::new(),
+                    backoff_chance: game_rng.timed_chance(RngStream::Ai),
                     backoff_stop: false,
                 },
                 AutoDamage {
@@ -123,6 +133,7 @@ fn plank_move(
     )>,
     cutscenes: Res<Cutscenes>,
     time: Res<Time>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let player_position = if let Ok(player_transform) = queries.p1().get_single() {
         player_transform.translation().truncate()
@@ -154,12 +165,12 @@ fn plank_move(
             } else if plank.backoff_chance.check(2.5, 0.25, time.delta_seconds()) {
                 plank.backoff_dir =
                     (global_transform.translation().truncate() - player_position).normalize();
-                if rand::random() {
+                if game_rng.bool(RngStream::Ai) {
                     plank.backoff_dir = plank.backoff_dir.perp();
                 } else {
                     plank.backoff_dir = -plank.backoff_dir.perp();
                 }
-                plank.backoff_stop = rand::random();
+                plank.backoff_stop = game_rng.bool(RngStream::Ai);
                 plank.backoff_time = 0.5;
             }
             let mut difference = destination - global_transform.translation().truncate();
@@ -1,6 +1,7 @@
 use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use self::ringo::RingoSpawnEvent;
 
@@ -10,16 +11,20 @@ impl Plugin for RingoQuestPlugin {
     fn build(&self, app: &mut App) {
         app.add_cutscene::<Ringo1Cutscene>()
             .add_cutscene::<Ringo2Cutscene>()
+            .add_world_location("RingoTrigger", WorldLocationCount::Multiple)
+            .add_world_location("RingoSpawn", WorldLocationCount::Single)
+            .add_world_location("RingoMoveTo", WorldLocationCount::Single)
+            .add_world_location("RingoArena", WorldLocationCount::Single)
             .add_plugins((ringo::RingoPlugin, trigger::RingoTriggerPlugin));
     }
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct RingoQuest {
     pub stage: RingoQuestStage,
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub enum RingoQuestStage {
     #[default]
     TalkToMayor,
@@ -29,6 +34,29 @@ pub enum RingoQuestStage {
     Dialogue2,
 }
 
+impl RingoQuestStage {
+    pub fn all() -> &'static [RingoQuestStage] {
+        &[
+            Self::TalkToMayor,
+            Self::TalkedToMayor,
+            Self::Dialogue1,
+            Self::Fight,
+            Self::Dialogue2,
+        ]
+    }
+
+    /// Name dialogue conditions match on, which stays the same when a variant is renamed.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TalkToMayor => "talk_to_mayor",
+            Self::TalkedToMayor => "talked_to_mayor",
+            Self::Dialogue1 => "dialogue1",
+            Self::Fight => "fight",
+            Self::Dialogue2 => "dialogue2",
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, Resource)]
 pub struct Ringo1Cutscene {
     pub boat: Option<Entity>,
@@ -51,12 +79,12 @@ fn ringo1_init1(
 ) {
     ev_ring_spawn.send_default();
 
-    for (p, t) in RINGO1.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("ringo1");
 
-    let rect = world_locations.get_single_rect("RingoArena");
-    overworld_camera.arena_enable(rect.position, rect.size);
+    match world_locations.single("RingoArena") {
+        Ok(rect) => overworld_camera.arena_enable(rect.position, rect.size),
+        Err(error) => error!("{}", error),
+    }
 }
 
 fn ringo1_cleanup(mut game_state: ResMut<GameState>) {
@@ -83,9 +111,7 @@ impl Cutscene for Ringo2Cutscene {
 
 fn ringo2_init1(mut dialogue: ResMut<Dialogue>, mut game_state: ResMut<GameState>) {
     game_state.attacks.shockwave = 1;
-    for (p, t) in RINGO2.iter() {
-        dialogue.add_text(*p, String::from(*t));
-    }
+    dialogue.add_conversation("ringo2");
 }
 
 fn ringo2_fade_out(mut screen_fade: ResMut<ScreenFade>) {
@@ -100,7 +126,10 @@ fn ringo2_cleanup(
 ) {
     overworld_camera.reset();
     game_state.quests.next();
-    game_state.town = TownData::build("Isla de Dio", world_locations.as_ref());
+    match TownData::build("Isla de Dio", world_locations.as_ref()) {
+        Ok(town) => game_state.town = town,
+        Err(error) => error!("{}", error),
+    }
     app_state.set(AppState::TownOutside);
 }
 
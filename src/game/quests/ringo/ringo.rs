@@ -76,17 +76,27 @@ fn ringo_spawn(
     mut overworld_camera: ResMut<OverworldCamera>,
     mut ev_boss_healthbar_spawn: EventWriter<BossHealthbarSpawnEvent>,
     asset_library: Res<AssetLibrary>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    let spawn_position = world_locations.get_single_position("RingoSpawn");
     for _ in ev_spawn.iter() {
+        let (spawn_position, move_to) = match (
+            world_locations.single_position("RingoSpawn"),
+            world_locations.single_position("RingoMoveTo"),
+        ) {
+            (Ok(spawn_position), Ok(move_to)) => (spawn_position, move_to),
+            (Err(error), _) | (_, Err(error)) => {
+                error!("{}", error);
+                continue;
+            }
+        };
         let stats = ringo_stats_by_health(1.);
         ev_enemies_despawn.send_default();
         let entity = commands
             .spawn((
                 Ringo {
-                    target: world_locations.get_single_position("RingoMoveTo"),
+                    target: move_to,
                     angle: 0.,
-                    dash_chance: TimedChance::new(),
+                    dash_chance: game_rng.timed_chance(RngStream::Ai),
                     charge_time: 0.,
                 },
                 AutoDamage {
@@ -0,0 +1,186 @@
+use crate::common::storage::{storage_read, storage_remove, storage_write};
+use crate::game::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const SAVE_VERSION: u32 = 2;
+pub const SAVE_SLOT_DEFAULT: usize = 0;
+
+/// Steps that upgrade a save from the paired version to the next, run in order on the untyped
+/// game state so they can still see renamed and removed fields.
+const SAVE_MIGRATIONS: &[(u32, fn(&mut Value))] = &[];
+
+#[derive(Debug)]
+pub enum SaveError {
+    Missing,
+    Corrupt(String),
+    UnsupportedVersion(u32),
+}
+
+#[derive(Serialize)]
+struct SaveFileRef<'a> {
+    version: u32,
+    game_state: &'a GameState,
+}
+
+#[derive(Deserialize)]
+struct SaveFile {
+    version: u32,
+    game_state: Value,
+}
+
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+/// Version 1 saves were ron. Ron can't be read untyped without losing enum variant names, so
+/// these are read straight into the current `GameState`, which only added fields since.
+#[derive(Deserialize)]
+struct LegacySaveFile {
+    game_state: GameState,
+}
+
+fn save_key(slot: usize) -> String {
+    format!("save{}.json", slot)
+}
+
+fn legacy_save_key(slot: usize) -> String {
+    format!("save{}", slot)
+}
+
+pub fn save_game(slot: usize, game_state: &GameState) -> bool {
+    let Some(contents) = encode_save(game_state) else {
+        return false;
+    };
+    storage_write(&save_key(slot), &contents)
+}
+
+pub fn load_game(slot: usize) -> Result<GameState, SaveError> {
+    let mut game_state = if let Some(contents) = storage_read(&save_key(slot)) {
+        decode_save(&contents)?
+    } else {
+        let contents = storage_read(&legacy_save_key(slot)).ok_or(SaveError::Missing)?;
+        decode_legacy_save(&contents)?
+    };
+    game_state.save_slot = slot;
+    game_state.checkpoint = Some(Box::new(game_state.clone()));
+    Ok(game_state)
+}
+
+pub fn delete_game(slot: usize) -> bool {
+    let removed = storage_remove(&save_key(slot));
+    storage_remove(&legacy_save_key(slot)) || removed
+}
+
+fn encode_save(game_state: &GameState) -> Option<String> {
+    let save = SaveFileRef {
+        version: SAVE_VERSION,
+        game_state,
+    };
+    serde_json::to_string_pretty(&save).ok()
+}
+
+fn decode_save(contents: &str) -> Result<GameState, SaveError> {
+    let save: SaveFile =
+        serde_json::from_str(contents).map_err(|err| SaveError::Corrupt(err.to_string()))?;
+    if save.version == 0 || save.version > SAVE_VERSION {
+        return Err(SaveError::UnsupportedVersion(save.version));
+    }
+    let mut game_state = save.game_state;
+    migrate(&mut game_state, save.version, SAVE_MIGRATIONS);
+    serde_json::from_value(game_state).map_err(|err| SaveError::Corrupt(err.to_string()))
+}
+
+fn decode_legacy_save(contents: &str) -> Result<GameState, SaveError> {
+    let header: SaveHeader =
+        ron::from_str(contents).map_err(|err| SaveError::Corrupt(err.to_string()))?;
+    if header.version != 1 {
+        return Err(SaveError::UnsupportedVersion(header.version));
+    }
+    let save: LegacySaveFile =
+        ron::from_str(contents).map_err(|err| SaveError::Corrupt(err.to_string()))?;
+    Ok(save.game_state)
+}
+
+fn migrate(game_state: &mut Value, version: u32, migrations: &[(u32, fn(&mut Value))]) {
+    for (from_version, migration) in migrations.iter() {
+        if *from_version >= version {
+            migration(game_state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game_state() -> GameState {
+        let mut game_state = GameState::default();
+        game_state.level = 4;
+        game_state.seed = u64::MAX - 1;
+        game_state.world_seed = Some(7);
+        game_state
+            .dialogue_flags
+            .insert(String::from("barkeep_round"));
+        game_state
+    }
+
+    #[test]
+    fn round_trip() {
+        let contents = encode_save(&game_state()).unwrap();
+        let loaded = decode_save(&contents).unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(game_state()).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_versions() {
+        for version in [0, SAVE_VERSION + 1] {
+            let contents = serde_json::json!({ "version": version, "game_state": {} }).to_string();
+            assert!(matches!(
+                decode_save(&contents),
+                Err(SaveError::UnsupportedVersion(found)) if found == version
+            ));
+        }
+        assert!(matches!(
+            decode_legacy_save("(version: 3, game_state: ())"),
+            Err(SaveError::UnsupportedVersion(3))
+        ));
+    }
+
+    #[test]
+    fn migrates_older_versions() {
+        fn rename_level(game_state: &mut Value) {
+            if let Some(level) = game_state.as_object_mut().and_then(|map| map.remove("lvl")) {
+                game_state["level"] = level;
+            }
+        }
+        let migrations: &[(u32, fn(&mut Value))] = &[(1, rename_level)];
+
+        let mut old = serde_json::json!({ "lvl": 3 });
+        migrate(&mut old, 1, migrations);
+        assert_eq!(old, serde_json::json!({ "level": 3 }));
+
+        let mut current = serde_json::json!({ "lvl": 3 });
+        migrate(&mut current, 2, migrations);
+        assert_eq!(current, serde_json::json!({ "lvl": 3 }));
+    }
+
+    #[test]
+    fn reads_legacy_saves() {
+        let mut legacy = ron::to_string(&SaveFileRef {
+            version: 1,
+            game_state: &game_state(),
+        })
+        .unwrap();
+        // version 1 quests still had dialogue counters
+        legacy = legacy.replacen("quests:(", "quests:(mayor_dialogue:2,", 1);
+        assert!(legacy.contains("mayor_dialogue"));
+        let loaded = decode_legacy_save(&legacy).unwrap();
+        assert_eq!(loaded.level, 4);
+        assert_eq!(loaded.dialogue_flags, game_state().dialogue_flags);
+    }
+}
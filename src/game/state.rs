@@ -1,8 +1,11 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::game::prelude::*;
 
-#[derive(Clone, Debug, Resource)]
+#[derive(Clone, Debug, Resource, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GameState {
     pub town: TownData,
     pub band_members: [BandMember; 2],
@@ -11,6 +14,7 @@ pub struct GameState {
     pub quests: Quests,
     pub dangerous_seas: bool,
     pub attacks: Attacks,
+    #[serde(skip)]
     pub checkpoint_notification: bool,
     pub health: f32,
     pub health_max: f32,
@@ -18,7 +22,16 @@ pub struct GameState {
     pub experience: f32,
     pub level: u32,
     pub skill_points: u32,
+    pub dialogue_flags: BTreeSet<String>,
+    pub dialogue_counters: BTreeMap<String, u32>,
+    /// Seed of the run, which `GameRng` streams are derived from.
+    pub seed: u64,
+    /// Seed of the generated archipelago, or `None` for the hand-made world.
+    pub world_seed: Option<u64>,
 
+    #[serde(skip)]
+    pub save_slot: usize,
+    #[serde(skip)]
     pub checkpoint: Option<Box<GameState>>,
 }
 
@@ -44,7 +57,12 @@ impl Default for GameState {
             experience: 0.,
             level: 1,
             skill_points: 0,
+            dialogue_flags: BTreeSet::new(),
+            dialogue_counters: BTreeMap::new(),
+            seed: 0,
+            world_seed: None,
             checkpoint_notification: false,
+            save_slot: SAVE_SLOT_DEFAULT,
             checkpoint: None,
         }
     }
@@ -54,6 +72,7 @@ impl GameState {
     pub fn checkpoint(&mut self) {
         self.checkpoint_notification = true;
         self.checkpoint = Some(Box::new(self.clone()));
+        save_game(self.save_slot, self);
     }
 
     pub fn restore_checkpoint(&mut self) -> bool {
@@ -92,9 +92,7 @@ fn concert_hall_init(
         HoverSound,
     ));
     if !game_state.quests.upgrades_dialogue {
-        for (p, t) in UPGRADE_MENU.iter() {
-            dialogue.add_text(*p, String::from(*t));
-        }
+        dialogue.add_conversation("upgrade_menu");
         game_state.quests.upgrades_dialogue = true;
     }
 }
@@ -66,7 +66,7 @@ struct UpgradesDescriptionText {
 
 #[derive(Debug, Clone)]
 struct UpgradesDisplayInfo {
-    name: &'static str,
+    name_key: &'static str,
     texture: Handle<Image>,
     offset: Vec2,
 }
@@ -96,32 +96,32 @@ impl UpgradesType {
     fn display_info(&self, asset_library: &AssetLibrary) -> UpgradesDisplayInfo {
         match *self {
             Self::Guitar => UpgradesDisplayInfo {
-                name: "Drums",
+                name_key: "upgrades.drums",
                 texture: asset_library.sprite_upgrades_ability_drums.clone(),
                 offset: Vec2::new(0., 0.),
             },
             Self::Drums => UpgradesDisplayInfo {
-                name: "Guitar",
+                name_key: "upgrades.guitar",
                 texture: asset_library.sprite_upgrades_ability_guitar.clone(),
                 offset: Vec2::new(0., 0.),
             },
             Self::Flute => UpgradesDisplayInfo {
-                name: "Flute",
+                name_key: "upgrades.flute",
                 texture: asset_library.sprite_upgrades_ability_flute.clone(),
                 offset: Vec2::new(0., -20.),
             },
             Self::Harmonica => UpgradesDisplayInfo {
-                name: "Harmonica",
+                name_key: "upgrades.harmonica",
                 texture: asset_library.sprite_upgrades_ability_harmonica.clone(),
                 offset: Vec2::new(0., -25.),
             },
             Self::Accordion => UpgradesDisplayInfo {
-                name: "Accordion",
+                name_key: "upgrades.accordion",
                 texture: asset_library.sprite_upgrades_ability_accordion.clone(),
                 offset: Vec2::new(0., -5.),
             },
             Self::Defense => UpgradesDisplayInfo {
-                name: "Defense",
+                name_key: "upgrades.defense",
                 texture: asset_library.sprite_upgrades_ability_defense.clone(),
                 offset: Vec2::new(0., 0.),
             },
@@ -177,16 +177,16 @@ impl UpgradesType {
     }
     fn upgrade_text(&self) -> Vec<&str> {
         match *self {
-            Self::Guitar => vec!["Increase damage"],
-            Self::Drums => vec!["Increase spread", "Minor damage increase"],
+            Self::Guitar => vec!["upgrades.damage"],
+            Self::Drums => vec!["upgrades.spread", "upgrades.minor_damage"],
             Self::Flute => vec![
-                "Increase size",
-                "Increase knockback",
-                "Minor damage increase",
+                "upgrades.size",
+                "upgrades.knockback",
+                "upgrades.minor_damage",
             ],
-            Self::Harmonica => vec!["Throw additional bomb"],
-            Self::Accordion => vec!["More tentacles"],
-            Self::Defense => vec!["Increase damage resistance"],
+            Self::Harmonica => vec!["upgrades.additional_bomb"],
+            Self::Accordion => vec!["upgrades.tentacles"],
+            Self::Defense => vec!["upgrades.damage_resistance"],
         }
     }
 }
@@ -197,6 +197,7 @@ fn upgrades_spawn(
     asset_library: Res<AssetLibrary>,
     mut state: ResMut<UpgradesState>,
     game_state: Res<GameState>,
+    localization: Res<Localization>,
 ) {
     for _ in ev_upgrades_spawn.iter() {
         *state = UpgradesState::default();
@@ -231,7 +232,7 @@ fn upgrades_spawn(
                 parent.spawn((
                     Text2dBundle {
                         text: Text::from_section(
-                            "Level up to unlock additional skill points.",
+                            localization.text("upgrades.level_up_hint"),
                             TextStyle {
                                 font: asset_library.font_bold.clone(),
                                 font_size: 90.0,
@@ -289,13 +290,17 @@ fn upgrades_spawn(
                                                 Vec2::new(-335., 95.) + display_info.offset,
                                             )
                                             .with_depth(DEPTH_LAYER_UPGRADES_ABILITY_ICON),
-                                            Label(String::from(display_info.name)),
+                                            Label(localization.text(display_info.name_key)),
                                         ));
                                     }
                                     parent.spawn((
                                         Text2dBundle {
                                             text: Text::from_section(
-                                                if locked { "Locked" } else { display_info.name },
+                                                localization.text(if locked {
+                                                    "upgrades.locked"
+                                                } else {
+                                                    display_info.name_key
+                                                }),
                                                 TextStyle {
                                                     font: asset_library.font_bold.clone(),
                                                     font_size: 100.0,
@@ -514,6 +519,7 @@ fn upgrades_description(
     mut text_query: Query<(&mut Text, &UpgradesDescriptionText)>,
     upgrades_state: Res<UpgradesState>,
     screen_fade: Res<ScreenFade>,
+    localization: Res<Localization>,
 ) {
     if !screen_fade.faded_in() {
         return;
@@ -529,8 +535,9 @@ fn upgrades_description(
         }
         for (mut text, txt) in text_query.iter_mut() {
             if txt.index < upgrade_text.len() as u32 {
-                if text.sections[0].value != upgrade_text[txt.index as usize] {
-                    text.sections[0].value = upgrade_text[txt.index as usize].to_owned();
+                let value = localization.text(upgrade_text[txt.index as usize]);
+                if text.sections[0].value != value {
+                    text.sections[0].value = value;
                 }
             } else {
                 if text.sections[0].value != "" {
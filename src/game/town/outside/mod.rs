@@ -223,6 +223,7 @@ fn outside_init(
             ..Default::default()
         },
         Transform2::from_xy(0., 330.).with_depth(DEPTH_LAYER_TOWN_OUTSIDE_NAME),
+        LocalizedText::new(game_state.town.name_key()),
     ));
 
     commands.spawn((
@@ -273,9 +274,7 @@ fn outside_init(
         },
     ));
     if game_state.quests.end() && !game_state.quests.endgame_town_dialogue {
-        for (p, t) in JAGEROSSA_AFTER_VICTORY.iter() {
-            dialogue.add_text(*p, String::from(*t));
-        }
+        dialogue.add_conversation("jagerossa_after_victory");
         game_state.quests.endgame_town_dialogue = true;
     }
 }
@@ -357,15 +356,11 @@ fn outside_click(
                     }
                     ClickableAction::Leave => {
                         if game_state.quests.must_talk_to_mayor() {
-                            for (p, t) in MUST_TALK_TO_MAYOR.iter() {
-                                dialogue.add_text(*p, String::from(*t));
-                            }
+                            dialogue.add_conversation("must_talk_to_mayor");
                         } else if !game_state.quests.talked_to_barkeep
                             && game_state.health != game_state.health_max
                         {
-                            for (p, t) in MUST_TALK_TO_BARKEEP.iter() {
-                                dialogue.add_text(*p, String::from(*t));
-                            }
+                            dialogue.add_conversation("must_talk_to_barkeep");
                         } else {
                             screen_fade.fade_out(0.5);
                             state.leave = OutsideLeave::LeaveToOverworld;
@@ -52,6 +52,7 @@ fn init1(
     asset_library: Res<AssetLibrary>,
     mut state: ResMut<RumRefillState>,
     game_state: Res<GameState>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     *state = RumRefillState::default();
     let health = game_state.health / game_state.health_max;
@@ -89,7 +90,7 @@ fn init1(
             ));
             for i in 0..10 {
                 let x = (i as f32 - 4.5) * 40.;
-                let brightness = 0.6 + rand::random::<f32>() * 0.4;
+                let brightness = 0.6 + game_rng.f32(RngStream::Fx) * 0.4;
                 let to = Vec2::new(x, 0.);
                 let from = Vec2::new(x + 100., 0.);
                 parent.spawn((
@@ -102,8 +103,8 @@ fn init1(
                         ..Default::default()
                     },
                     Transform2::from_translation(to)
-                        .with_rotation(rand::random::<f32>() * 0.2 - 0.1)
-                        .with_scale(Vec2::ONE * (1. + rand::random::<f32>() * 0.2 - 0.1))
+                        .with_rotation(game_rng.f32(RngStream::Fx) * 0.2 - 0.1)
+                        .with_scale(Vec2::ONE * (1. + game_rng.f32(RngStream::Fx) * 0.2 - 0.1))
                         .with_depth((
                             DEPTH_LAYER_TOWN_OUTSIDE_RUM_REFILL_BOTTLE.0,
                             DEPTH_LAYER_TOWN_OUTSIDE_RUM_REFILL_BOTTLE.1 + brightness * 0.001,
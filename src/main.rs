@@ -68,60 +68,17 @@ fn debug_dialogue(
         egui::Window::new("Dialogues")
             .open(open)
             .show(egui_context.get_mut(), |ui| {
-                macro_rules! dialogue_button_for {
-                    ($ui:ident, $e:expr) => {
-                        if $ui.button(stringify!($e)).clicked() {
-                            for (p, t) in $e.iter() {
-                                dialogue.add_text(*p, String::from(*t));
-                            }
+                let keys: Vec<String> = dialogue
+                    .conversation_keys()
+                    .into_iter()
+                    .map(String::from)
+                    .collect();
+                ui.horizontal_wrapped(|ui| {
+                    for key in keys.iter() {
+                        if ui.button(key).clicked() {
+                            dialogue.add_conversation(key);
                         }
-                    };
-                }
-                ui.horizontal(|ui| {
-                    dialogue_button_for!(ui, MUST_TALK_TO_MAYOR);
-                    dialogue_button_for!(ui, MUST_TALK_TO_BARKEEP);
-                    dialogue_button_for!(ui, JAGEROSSA1);
-                    dialogue_button_for!(ui, JAGEROSSA2);
-                    dialogue_button_for!(ui, DANGEROUS_SEAS);
-                    dialogue_button_for!(ui, RINGO_MAYOR);
-                });
-                ui.horizontal(|ui| {
-                    dialogue_button_for!(ui, RINGO1);
-                    dialogue_button_for!(ui, RINGO2);
-                    dialogue_button_for!(ui, PLANK_MAYOR);
-                    dialogue_button_for!(ui, PLANK1);
-                    dialogue_button_for!(ui, PLANK2);
-                    dialogue_button_for!(ui, DAVY_MAYOR);
-                });
-                ui.horizontal(|ui| {
-                    dialogue_button_for!(ui, DAVY1);
-                    dialogue_button_for!(ui, DAVY2);
-                    dialogue_button_for!(ui, MAYOR_RANDOM1);
-                    dialogue_button_for!(ui, MAYOR_RANDOM2);
-                    dialogue_button_for!(ui, MAYOR_RANDOM3);
-                    dialogue_button_for!(ui, MAYOR_RANDOM4);
-                });
-                ui.horizontal(|ui| {
-                    dialogue_button_for!(ui, MAYOR_RANDOM5);
-                    dialogue_button_for!(ui, BARKEEP1);
-                    dialogue_button_for!(ui, BARKEEP_RANDOM1);
-                    dialogue_button_for!(ui, BARKEEP_RANDOM2);
-                    dialogue_button_for!(ui, BARKEEP_RANDOM3);
-                    dialogue_button_for!(ui, BARKEEP_RANDOM4);
-                });
-                ui.horizontal(|ui| {
-                    dialogue_button_for!(ui, BARKEEP_RANDOM5);
-                    dialogue_button_for!(ui, BARKEEP_RANDOM6);
-                    dialogue_button_for!(ui, UPGRADE_MENU);
-                    dialogue_button_for!(ui, JAGEROSSA_AFTER_VICTORY);
-                    dialogue_button_for!(ui, MAYOR_AFTER_VICTORY1);
-                });
-                ui.horizontal(|ui| {
-                    dialogue_button_for!(ui, MAYOR_AFTER_VICTORY2);
-                    dialogue_button_for!(ui, MAYOR_AFTER_VICTORY3);
-                    dialogue_button_for!(ui, MAYOR_AFTER_VICTORY4);
-                    dialogue_button_for!(ui, MAYOR_AFTER_VICTORY5);
-                    dialogue_button_for!(ui, MAYOR_AFTER_VICTORY6);
+                    }
                 });
             });
     });
@@ -0,0 +1,165 @@
+use super::{MenuState, CONTINUE_TEXT_HOVER, CONTINUE_TEXT_PRESS};
+use crate::common::prelude::*;
+use audio_plus::prelude::*;
+use bevy::{prelude::*, sprite::Anchor};
+
+const CONTROLS_MENU_POSITION: Vec2 = Vec2::new(632., 340.);
+const CONTROLS_MENU_SPACING: f32 = 22.;
+
+pub struct ControlsMenuPlugin;
+
+impl Plugin for ControlsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ControlsMenuSpawnEvent>()
+            .init_resource::<ControlsMenuState>()
+            .add_systems(
+                Update,
+                (
+                    controls_menu_spawn,
+                    controls_menu_update.before(super::menu_button),
+                ),
+            );
+    }
+}
+
+#[derive(Event, Default, Clone, Copy)]
+pub struct ControlsMenuSpawnEvent;
+
+#[derive(Default, Resource)]
+struct ControlsMenuState {
+    listening: Option<Action>,
+    conflict: Option<Action>,
+}
+
+#[derive(Component)]
+struct ControlsMenuItem {
+    action: Option<Action>,
+}
+
+fn controls_menu_spawn(
+    mut ev_spawn: EventReader<ControlsMenuSpawnEvent>,
+    mut commands: Commands,
+    mut state: ResMut<ControlsMenuState>,
+    asset_library: Res<AssetLibrary>,
+) {
+    for _ in ev_spawn.iter() {
+        state.listening = None;
+        state.conflict = None;
+        let items = Action::all()
+            .iter()
+            .map(|action| Some(*action))
+            .chain(std::iter::once(None));
+        for (index, action) in items.enumerate() {
+            commands.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_library.font_bold.clone(),
+                            font_size: 48.0,
+                            color: Color::BLACK,
+                        },
+                    )
+                    .with_alignment(TextAlignment::Right),
+                    text_anchor: Anchor::TopRight,
+                    ..Default::default()
+                },
+                Transform2::from_translation(
+                    CONTROLS_MENU_POSITION - Vec2::Y * CONTROLS_MENU_SPACING * index as f32,
+                )
+                .with_depth((DepthLayer::Front, 0.2))
+                .with_scale(Vec2::ONE * 0.5),
+                Clickable {
+                    shape: CollisionShape::Rect {
+                        size: Vec2::new(400., CONTROLS_MENU_SPACING),
+                    },
+                    use_global: true,
+                    offset: Vec2::new(-200., -12.),
+                    ..Default::default()
+                },
+                ControlsMenuItem { action },
+            ));
+        }
+    }
+}
+
+fn controls_menu_update(
+    mut query: Query<(&mut Text, &Clickable, &ControlsMenuItem)>,
+    mut state: ResMut<ControlsMenuState>,
+    mut input_map: ResMut<InputMap>,
+    mut keys: ResMut<Input<KeyCode>>,
+    mut mouse: ResMut<Input<MouseButton>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    localization: Res<Localization>,
+    menu_state: Res<MenuState>,
+    asset_library: Res<AssetLibrary>,
+    mut commands: Commands,
+) {
+    if let Some(action) = state.listening {
+        if keys.just_pressed(KeyCode::Escape) || menu_state.leaving() {
+            state.listening = None;
+            state.conflict = None;
+        } else if let Some(binding) = Binding::just_pressed(&keys, &mouse, &gamepad_buttons) {
+            match input_map.rebind(action, binding) {
+                Ok(()) => {
+                    state.listening = None;
+                    state.conflict = None;
+                }
+                Err(conflict) => {
+                    state.conflict = Some(conflict);
+                }
+            }
+            if let Binding::Key(key) = binding {
+                keys.reset(key);
+            }
+            mouse.reset_all();
+        }
+    } else if !menu_state.leaving() {
+        for (_, clickable, item) in query.iter() {
+            if !clickable.confirmed {
+                continue;
+            }
+            if let Some(action) = item.action {
+                state.listening = Some(action);
+            } else {
+                input_map.reset();
+            }
+            commands.spawn((
+                AudioPlusSource::new(asset_library.sound_effects.sfx_menu_button_click.clone())
+                    .as_playing(),
+                TimeToLive { seconds: 3. },
+            ));
+        }
+    }
+    for (mut text, clickable, item) in query.iter_mut() {
+        let value = if let Some(action) = item.action {
+            let binding = if state.listening == Some(action) {
+                if let Some(conflict) = state.conflict {
+                    localization
+                        .text("controls.conflict")
+                        .replace("{action}", &localization.text(conflict.name_key()))
+                } else {
+                    localization.text("controls.listening")
+                }
+            } else if let Some(gamepad_binding) = input_map.binding(action, true) {
+                let keyboard = input_map.label(action, false);
+                format!("{} / {}", keyboard, gamepad_binding.name())
+            } else {
+                input_map.label(action, false)
+            };
+            format!("{}: {}", localization.text(action.name_key()), binding)
+        } else {
+            localization.text("controls.reset")
+        };
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+        text.sections[0].style.color = if item.action.is_some() && state.listening == item.action {
+            CONTINUE_TEXT_PRESS
+        } else if clickable.hovered {
+            CONTINUE_TEXT_HOVER
+        } else {
+            Color::BLACK
+        };
+    }
+}
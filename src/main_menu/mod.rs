@@ -1,12 +1,16 @@
 use crate::{
     common::{label::Label, prelude::*},
-    game::state::GameState,
+    game::{
+        save::{load_game, SAVE_SLOT_DEFAULT},
+        state::GameState,
+    },
     DEV_BUILD,
 };
 use audio_plus::prelude::*;
-use bevy::{prelude::*, sprite::Anchor, window::WindowMode};
+use bevy::{prelude::*, sprite::Anchor, window::ReceivedCharacter};
+use rand::Rng;
 
-use self::slider::VolumeSliderSpawnEvent;
+use self::{controls::ControlsMenuSpawnEvent, slider::VolumeSliderSpawnEvent};
 
 const LOGO_POSITION: Vec2 = Vec2::new(0., 115.);
 const LOGO_SCALE: Vec2 = Vec2::new(0.84, 0.84);
@@ -14,17 +18,36 @@ const LOGO_MOVEMENT_GROW: Vec2 = Vec2::new(1., 1.4);
 const BUTTON_SCALE: Vec2 = Vec2::new(0.72, 0.72);
 const BUTTON_POSITION: Vec2 = Vec2::new(80., -200.);
 const BUTTON_TEXT_SCALE: Vec2 = Vec2::new(0.8, 0.8);
+const CONTINUE_BUTTON_SCALE: Vec2 = Vec2::new(0.5, 0.5);
+const CONTINUE_BUTTON_POSITION: Vec2 = Vec2::new(-260., -215.);
+const CONTINUE_TEXT_NORMAL: Color = Color::rgb(0.45, 0.24, 0.02);
+const CONTINUE_TEXT_HOVER: Color = Color::rgb(0.62, 0.36, 0.04);
+const CONTINUE_TEXT_PRESS: Color = Color::rgb(0.32, 0.16, 0.01);
+const LOCALE_POSITION: Vec2 = Vec2::new(632., 378.);
+const CAMERA_RATIO_POSITION: Vec2 = Vec2::new(560., -378.);
+const WORLD_SOURCE_POSITION: Vec2 = Vec2::new(560., -348.);
+const SEED_POSITION: Vec2 = Vec2::new(560., -318.);
+/// Any 19 digit number fits in a `u64`.
+const SEED_MAX_DIGITS: usize = 19;
 
 #[derive(Default, Resource)]
 struct MenuState {
     play: bool,
+    continue_game: bool,
+    save: Option<GameState>,
+}
+
+impl MenuState {
+    fn leaving(&self) -> bool {
+        self.play || self.continue_game
+    }
 }
 
 pub struct MainMenuPlugin;
 
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(slider::VolumeSliderPlugin)
+        app.add_plugins((slider::VolumeSliderPlugin, controls::ControlsMenuPlugin))
             .init_resource::<MenuState>()
             .add_systems(OnEnter(AppState::MainMenu), menu_setup)
             .add_systems(
@@ -37,13 +60,25 @@ impl Plugin for MainMenuPlugin {
                     menu_background_move,
                     menu_outro_debug.run_if(in_state(AppState::MainMenu)),
                     menu_fullscreen,
+                    menu_locale,
+                    menu_camera_ratio,
+                    menu_world_source,
+                    menu_seed,
                 ),
             );
     }
 }
 
+#[derive(Clone, Copy)]
+enum ButtonAction {
+    Play,
+    Continue,
+}
+
 #[derive(Component)]
 struct Button {
+    action: ButtonAction,
+    position: Vec2,
     shape: CollisionShape,
     last_hover: bool,
     clicked: bool,
@@ -59,6 +94,9 @@ struct ButtonText {
     press: Handle<Image>,
 }
 
+#[derive(Component)]
+struct ButtonLabel;
+
 #[derive(Component)]
 struct Sound;
 
@@ -78,6 +116,21 @@ struct Background;
 #[derive(Component)]
 struct Fullscreen;
 
+#[derive(Component)]
+struct LocaleSelect;
+
+#[derive(Component)]
+struct CameraRatioSelect;
+
+#[derive(Component)]
+struct WorldSourceSelect;
+
+#[derive(Component, Default)]
+struct SeedEntry {
+    editing: bool,
+    digits: String,
+}
+
 fn menu_setup(
     mut menu_state: ResMut<MenuState>,
     mut screen_fade: ResMut<ScreenFade>,
@@ -86,12 +139,19 @@ fn menu_setup(
     mut cutscenes: ResMut<Cutscenes>,
     mut dialogue: ResMut<Dialogue>,
     mut ev_volume_slider_spawn: EventWriter<VolumeSliderSpawnEvent>,
+    mut ev_controls_menu_spawn: EventWriter<ControlsMenuSpawnEvent>,
+    localization: Res<Localization>,
+    settings: Res<Settings>,
 ) {
-    *menu_state = MenuState::default();
+    *menu_state = MenuState {
+        save: load_game(SAVE_SLOT_DEFAULT).ok(),
+        ..Default::default()
+    };
     cutscenes.clear();
     dialogue.clear();
     screen_fade.fade_in(1.);
     ev_volume_slider_spawn.send_default();
+    ev_controls_menu_spawn.send_default();
     commands.spawn(Camera2dBundle::default());
     commands.spawn((
         AudioPlusSource::new(asset_library.sound_effects.sfx_menu_ambient.clone()).as_looping(),
@@ -165,6 +225,8 @@ fn menu_setup(
                 ..Default::default()
             },
             Button {
+                action: ButtonAction::Play,
+                position: BUTTON_POSITION,
                 shape: CollisionShape::Rect {
                     size: Vec2::new(406., 159.) * BUTTON_SCALE,
                 },
@@ -205,6 +267,52 @@ fn menu_setup(
             ));
         });
 
+    if menu_state.save.is_some() {
+        commands
+            .spawn((
+                SpriteBundle {
+                    texture: asset_library.menu_sprite_button_back.clone(),
+                    ..Default::default()
+                },
+                Button {
+                    action: ButtonAction::Continue,
+                    position: CONTINUE_BUTTON_POSITION,
+                    shape: CollisionShape::Rect {
+                        size: Vec2::new(406., 159.) * CONTINUE_BUTTON_SCALE,
+                    },
+                    last_hover: false,
+                    clicked: false,
+                    audio_hover: hover_audio,
+                    audio_click: click_audio,
+                    audio_click_confirm: click_confirm_audio,
+                },
+                Transform2::from_translation(CONTINUE_BUTTON_POSITION)
+                    .with_scale(CONTINUE_BUTTON_SCALE)
+                    .with_depth((DepthLayer::Front, 0.3)),
+                Label("Continue Button".to_owned()),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            "CONTINUE",
+                            TextStyle {
+                                font: asset_library.font_bold.clone(),
+                                font_size: 72.0,
+                                color: CONTINUE_TEXT_NORMAL,
+                            },
+                        )
+                        .with_alignment(TextAlignment::Center),
+                        text_anchor: Anchor::Center,
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(0., 4.).with_depth((DepthLayer::Front, 0.4)),
+                    ButtonLabel,
+                    LocalizedText::new("menu.continue"),
+                ));
+            });
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     commands.spawn((
         SpriteBundle {
@@ -254,6 +362,121 @@ fn menu_setup(
         Transform2::from_xy(-632., -378.)
             .with_depth((DepthLayer::Front, 0.2))
             .with_scale(Vec2::ONE * 0.5),
+        LocalizedText::new("menu.jam"),
+    ));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                localization.locale_name(),
+                TextStyle {
+                    font: asset_library.font_bold.clone(),
+                    font_size: 48.0,
+                    color: Color::BLACK,
+                },
+            )
+            .with_alignment(TextAlignment::Right),
+            text_anchor: Anchor::TopRight,
+            ..Default::default()
+        },
+        Transform2::from_translation(LOCALE_POSITION)
+            .with_depth((DepthLayer::Front, 0.2))
+            .with_scale(Vec2::ONE * 0.5),
+        Clickable {
+            shape: CollisionShape::Rect {
+                size: Vec2::new(300., 50.),
+            },
+            use_global: true,
+            offset: Vec2::new(-75., -12.),
+            ..Default::default()
+        },
+        LocaleSelect,
+    ));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: asset_library.font_bold.clone(),
+                    font_size: 48.0,
+                    color: Color::BLACK,
+                },
+            )
+            .with_alignment(TextAlignment::Right),
+            text_anchor: Anchor::BottomRight,
+            ..Default::default()
+        },
+        Transform2::from_translation(CAMERA_RATIO_POSITION)
+            .with_depth((DepthLayer::Front, 0.2))
+            .with_scale(Vec2::ONE * 0.5),
+        Clickable {
+            shape: CollisionShape::Rect {
+                size: Vec2::new(200., 30.),
+            },
+            use_global: true,
+            offset: Vec2::new(-50., 8.),
+            ..Default::default()
+        },
+        LocalizedText::new(settings.camera_ratio.name_key()),
+        CameraRatioSelect,
+    ));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: asset_library.font_bold.clone(),
+                    font_size: 48.0,
+                    color: Color::BLACK,
+                },
+            )
+            .with_alignment(TextAlignment::Right),
+            text_anchor: Anchor::BottomRight,
+            ..Default::default()
+        },
+        Transform2::from_translation(WORLD_SOURCE_POSITION)
+            .with_depth((DepthLayer::Front, 0.2))
+            .with_scale(Vec2::ONE * 0.5),
+        Clickable {
+            shape: CollisionShape::Rect {
+                size: Vec2::new(200., 30.),
+            },
+            use_global: true,
+            offset: Vec2::new(-50., 8.),
+            ..Default::default()
+        },
+        LocalizedText::new(settings.world_source.name_key()),
+        WorldSourceSelect,
+    ));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: asset_library.font_bold.clone(),
+                    font_size: 48.0,
+                    color: Color::BLACK,
+                },
+            )
+            .with_alignment(TextAlignment::Right),
+            text_anchor: Anchor::BottomRight,
+            ..Default::default()
+        },
+        Transform2::from_translation(SEED_POSITION)
+            .with_depth((DepthLayer::Front, 0.2))
+            .with_scale(Vec2::ONE * 0.5),
+        Clickable {
+            shape: CollisionShape::Rect {
+                size: Vec2::new(200., 30.),
+            },
+            use_global: true,
+            offset: Vec2::new(-50., 8.),
+            ..Default::default()
+        },
+        SeedEntry::default(),
     ));
 
     commands.spawn((
@@ -309,6 +532,7 @@ fn menu_button(
     mut screen_fade: ResMut<ScreenFade>,
     mut button_query: Query<(&mut Button, &GlobalTransform, &Children, &mut Transform2)>,
     mut text_query: Query<(&ButtonText, &mut Handle<Image>)>,
+    mut label_query: Query<&mut Text, With<ButtonLabel>>,
     mut sfx_query: Query<&mut AudioPlusSource>,
     sound_query: Query<Entity, With<Sound>>,
     mouse: Res<Mouse>,
@@ -316,7 +540,7 @@ fn menu_button(
     mut menu_state: ResMut<MenuState>,
 ) {
     for (mut button, transform, children, mut transform2) in button_query.iter_mut() {
-        let hover = !menu_state.play
+        let hover = !menu_state.leaving()
             && button.shape.overlaps(
                 transform.translation().truncate(),
                 CollisionShape::Point,
@@ -339,13 +563,16 @@ fn menu_button(
                         source.stop();
                     }
                 }
-                menu_state.play = true;
+                match button.action {
+                    ButtonAction::Play => menu_state.play = true,
+                    ButtonAction::Continue => menu_state.continue_game = true,
+                }
                 play_sound(button.audio_click_confirm, &mut sfx_query);
                 screen_fade.fade_out(1.8);
             }
             button.clicked = false;
         }
-        transform2.translation = BUTTON_POSITION;
+        transform2.translation = button.position;
         if button.clicked && hover {
             transform2.translation += Vec2::new(-2., -2.);
         }
@@ -359,19 +586,47 @@ fn menu_button(
                     *image = text.normal.clone();
                 }
             }
+            if let Ok(mut label) = label_query.get_mut(*child) {
+                label.sections[0].style.color = if button.clicked && hover {
+                    CONTINUE_TEXT_PRESS
+                } else if hover {
+                    CONTINUE_TEXT_HOVER
+                } else {
+                    CONTINUE_TEXT_NORMAL
+                };
+            }
         }
     }
 }
 
 fn menu_fade(
     menu_state: Res<MenuState>,
+    settings: Res<Settings>,
     mut game_state: ResMut<GameState>,
+    mut game_rng: ResMut<GameRng>,
     mut app_state: ResMut<NextState<AppState>>,
     screen_fade: Res<ScreenFade>,
 ) {
-    if menu_state.play && screen_fade.faded_out() {
+    if !screen_fade.faded_out() {
+        return;
+    }
+    if menu_state.play {
         *game_state = GameState::default();
+        game_state.seed = match settings.seed {
+            Some(seed) => seed,
+            None => game_rng.new_run_seed(),
+        };
+        game_rng.reseed(game_state.seed);
+        if settings.world_source == WorldSource::Archipelago {
+            game_state.world_seed = Some(game_rng.stream(RngStream::World).gen());
+        }
         app_state.set(AppState::IntroCutscene);
+    } else if menu_state.continue_game {
+        if let Some(save) = menu_state.save.clone() {
+            game_rng.reseed(save.seed);
+            *game_state = save;
+            app_state.set(AppState::Overworld);
+        }
     }
 }
 
@@ -399,7 +654,7 @@ fn menu_outro_debug(mut input: ResMut<Input<KeyCode>>, mut app_state: ResMut<Nex
 
 fn menu_fullscreen(
     mut fullscreen_query: Query<(&mut Sprite, &Clickable), With<Fullscreen>>,
-    mut window_query: Query<&mut Window>,
+    mut settings: ResMut<Settings>,
 ) {
     for (mut fullscreen_sprite, fullscreen_clickable) in fullscreen_query.iter_mut() {
         fullscreen_sprite
@@ -410,15 +665,141 @@ fn menu_fullscreen(
                 0.6
             });
         if fullscreen_clickable.confirmed {
-            if let Some(mut window) = window_query.get_single_mut().ok() {
-                if window.mode == WindowMode::BorderlessFullscreen {
-                    window.mode = WindowMode::Windowed;
-                } else {
-                    window.mode = WindowMode::BorderlessFullscreen;
+            settings.fullscreen = !settings.fullscreen;
+        }
+    }
+}
+
+fn menu_locale(
+    mut locale_query: Query<(&mut Text, &Clickable), With<LocaleSelect>>,
+    localization: Res<Localization>,
+    mut settings: ResMut<Settings>,
+    menu_state: Res<MenuState>,
+) {
+    for (mut locale_text, locale_clickable) in locale_query.iter_mut() {
+        if locale_clickable.confirmed && !menu_state.leaving() {
+            settings.locale = localization.next_locale();
+        }
+        if locale_text.sections[0].value != localization.locale_name() {
+            locale_text.sections[0].value = localization.locale_name().to_owned();
+        }
+        locale_text.sections[0].style.color = if locale_clickable.hovered {
+            CONTINUE_TEXT_HOVER
+        } else {
+            Color::BLACK
+        };
+    }
+}
+
+fn menu_camera_ratio(
+    mut camera_ratio_query: Query<
+        (&mut Text, &mut LocalizedText, &Clickable),
+        With<CameraRatioSelect>,
+    >,
+    mut settings: ResMut<Settings>,
+    menu_state: Res<MenuState>,
+) {
+    for (mut camera_ratio_text, mut localized_text, camera_ratio_clickable) in
+        camera_ratio_query.iter_mut()
+    {
+        if camera_ratio_clickable.confirmed && !menu_state.leaving() {
+            settings.camera_ratio = settings.camera_ratio.next();
+        }
+        if localized_text.key != settings.camera_ratio.name_key() {
+            localized_text.key = settings.camera_ratio.name_key().to_owned();
+        }
+        camera_ratio_text.sections[0].style.color = if camera_ratio_clickable.hovered {
+            CONTINUE_TEXT_HOVER
+        } else {
+            Color::BLACK
+        };
+    }
+}
+
+fn menu_world_source(
+    mut world_source_query: Query<
+        (&mut Text, &mut LocalizedText, &Clickable),
+        With<WorldSourceSelect>,
+    >,
+    mut settings: ResMut<Settings>,
+    menu_state: Res<MenuState>,
+) {
+    for (mut world_source_text, mut localized_text, world_source_clickable) in
+        world_source_query.iter_mut()
+    {
+        if world_source_clickable.confirmed && !menu_state.leaving() {
+            settings.world_source = settings.world_source.next();
+        }
+        if localized_text.key != settings.world_source.name_key() {
+            localized_text.key = settings.world_source.name_key().to_owned();
+        }
+        world_source_text.sections[0].style.color = if world_source_clickable.hovered {
+            CONTINUE_TEXT_HOVER
+        } else {
+            Color::BLACK
+        };
+    }
+}
+
+/// Click to type a seed, then click again or press enter. An empty seed picks a new one for
+/// every run.
+fn menu_seed(
+    mut seed_query: Query<(&mut Text, &mut SeedEntry, &Clickable)>,
+    mut settings: ResMut<Settings>,
+    mut ev_received_character: EventReader<ReceivedCharacter>,
+    mut keys: ResMut<Input<KeyCode>>,
+    localization: Res<Localization>,
+    menu_state: Res<MenuState>,
+) {
+    let typed: Vec<char> = ev_received_character
+        .iter()
+        .map(|event| event.char)
+        .collect();
+    for (mut seed_text, mut seed_entry, seed_clickable) in seed_query.iter_mut() {
+        if seed_entry.editing {
+            for character in typed.iter() {
+                if character.is_ascii_digit() && seed_entry.digits.len() < SEED_MAX_DIGITS {
+                    seed_entry.digits.push(*character);
                 }
             }
+            if keys.just_pressed(KeyCode::Back) {
+                seed_entry.digits.pop();
+                keys.reset(KeyCode::Back);
+            }
+            let confirmed = seed_clickable.confirmed || keys.just_pressed(KeyCode::Return);
+            if confirmed || menu_state.leaving() {
+                keys.reset(KeyCode::Return);
+                seed_entry.editing = false;
+                settings.seed = seed_entry.digits.parse().ok();
+            }
+        } else if seed_clickable.confirmed && !menu_state.leaving() {
+            seed_entry.editing = true;
+            seed_entry.digits = settings
+                .seed
+                .map(|seed| seed.to_string())
+                .unwrap_or_default();
+        }
+        let value = if seed_entry.editing {
+            localization
+                .text("settings.seed")
+                .replace("{seed}", &format!("{}_", seed_entry.digits))
+        } else if let Some(seed) = settings.seed {
+            localization
+                .text("settings.seed")
+                .replace("{seed}", &seed.to_string())
+        } else {
+            localization.text("settings.seed.random")
+        };
+        if seed_text.sections[0].value != value {
+            seed_text.sections[0].value = value;
         }
+        seed_text.sections[0].style.color = if seed_clickable.hovered || seed_entry.editing {
+            CONTINUE_TEXT_HOVER
+        } else {
+            Color::BLACK
+        };
     }
 }
 
+pub mod controls;
 pub mod slider;
@@ -2,6 +2,13 @@ use crate::common::{label::Label, prelude::*};
 use audio_plus::prelude::*;
 use bevy::{prelude::*, sprite::Anchor};
 
+const SLIDER_WIDTH: f32 = 116.;
+const MASTER_SLIDER_POSITION: Vec2 = Vec2::new(41., -312.);
+const BUS_SLIDERS_POSITION: Vec2 = Vec2::new(-620., 340.);
+const BUS_SLIDERS_SPACING: f32 = 36.;
+const BUS_SLIDERS_INDENT: f32 = 16.;
+const BUS_SLIDERS_OFFSET: f32 = 200.;
+
 pub struct VolumeSliderPlugin;
 
 impl Plugin for VolumeSliderPlugin {
@@ -15,58 +22,36 @@ impl Plugin for VolumeSliderPlugin {
 pub struct VolumeSliderSpawnEvent;
 
 #[derive(Component)]
-pub struct VolumeSliderKnob;
+pub struct VolumeSliderKnob {
+    /// `None` for the master volume.
+    bus: Option<AudioPlusMixerChannel>,
+    x: f32,
+}
+
+impl VolumeSliderKnob {
+    fn min_volume(&self) -> f32 {
+        if self.bus.is_some() {
+            0.
+        } else {
+            0.1
+        }
+    }
+}
 
 fn volume_slider_spawn(
     mut ev_spawn: EventReader<VolumeSliderSpawnEvent>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mixer: Res<AudioPlusMixer>,
 ) {
     for _ in ev_spawn.iter() {
-        commands
-            .spawn((
-                VisibilityBundle::default(),
-                TransformBundle::default(),
-                FollowCamera { offset: Vec2::ZERO },
-                Transform2::from_xy(41., -312.).without_pixel_perfect(),
-            ))
-            .with_children(|parent| {
-                parent.spawn((
-                    SpriteBundle {
-                        texture: asset_library.menu_slider_back.clone(),
-                        ..Default::default()
-                    },
-                    Transform2::from_xy(0., 0.)
-                        .with_scale(Vec2::ONE * 0.5)
-                        .with_depth((DepthLayer::Front, 0.5)),
-                ));
-                parent.spawn((
-                    SpriteBundle {
-                        texture: asset_library.menu_slider_knob.clone(),
-                        ..Default::default()
-                    },
-                    Transform2::from_xy(0., 0.)
-                        .with_scale(Vec2::ONE * 0.5)
-                        .with_depth((DepthLayer::Front, 0.51)),
-                    VolumeSliderKnob,
-                    Clickable {
-                        shape: CollisionShape::Rect {
-                            size: Vec2::new(100., 100.),
-                        },
-                        use_global: true,
-                        ..Default::default()
-                    },
-                ));
-                parent.spawn((
-                    SpriteBundle {
-                        texture: asset_library.menu_slider_icon.clone(),
-                        ..Default::default()
-                    },
-                    Transform2::from_xy(-100., 0.)
-                        .with_scale(Vec2::ONE * 0.5)
-                        .with_depth((DepthLayer::Front, 0.51)),
-                ));
-            });
+        spawn_slider(
+            &mut commands,
+            &asset_library,
+            MASTER_SLIDER_POSITION,
+            None,
+            Vec2::new(100., 100.),
+        );
         commands.spawn((
             Text2dBundle {
                 text: Text::from_section(
@@ -85,17 +70,100 @@ fn volume_slider_spawn(
                 .with_depth((DepthLayer::Front, 0.51)),
             Label("A".into()),
         ));
+        for (index, bus) in mixer.buses().enumerate() {
+            let position = BUS_SLIDERS_POSITION - Vec2::Y * BUS_SLIDERS_SPACING * index as f32;
+            commands.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        bus.name(),
+                        TextStyle {
+                            font: asset_library.font_bold.clone(),
+                            font_size: 48.0,
+                            color: Color::BLACK,
+                        },
+                    )
+                    .with_alignment(TextAlignment::Left),
+                    text_anchor: Anchor::CenterLeft,
+                    ..Default::default()
+                },
+                Transform2::from_translation(
+                    position + Vec2::X * BUS_SLIDERS_INDENT * mixer.get_depth(bus) as f32,
+                )
+                .with_depth((DepthLayer::Front, 0.51))
+                .with_scale(Vec2::ONE * 0.5),
+            ));
+            spawn_slider(
+                &mut commands,
+                &asset_library,
+                position + Vec2::X * BUS_SLIDERS_OFFSET,
+                Some(bus),
+                Vec2::new(100., BUS_SLIDERS_SPACING * 1.8),
+            );
+        }
     }
 }
 
+fn spawn_slider(
+    commands: &mut Commands,
+    asset_library: &AssetLibrary,
+    position: Vec2,
+    bus: Option<AudioPlusMixerChannel>,
+    knob_size: Vec2,
+) {
+    commands
+        .spawn((
+            VisibilityBundle::default(),
+            TransformBundle::default(),
+            FollowCamera { offset: Vec2::ZERO },
+            Transform2::from_translation(position).without_pixel_perfect(),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpriteBundle {
+                    texture: asset_library.menu_slider_back.clone(),
+                    ..Default::default()
+                },
+                Transform2::from_xy(0., 0.)
+                    .with_scale(Vec2::ONE * 0.5)
+                    .with_depth((DepthLayer::Front, 0.5)),
+            ));
+            parent.spawn((
+                SpriteBundle {
+                    texture: asset_library.menu_slider_knob.clone(),
+                    ..Default::default()
+                },
+                Transform2::from_xy(0., 0.)
+                    .with_scale(Vec2::ONE * 0.5)
+                    .with_depth((DepthLayer::Front, 0.51)),
+                VolumeSliderKnob { bus, x: position.x },
+                Clickable {
+                    shape: CollisionShape::Rect { size: knob_size },
+                    use_global: true,
+                    ..Default::default()
+                },
+            ));
+            if bus.is_none() {
+                parent.spawn((
+                    SpriteBundle {
+                        texture: asset_library.menu_slider_icon.clone(),
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(-100., 0.)
+                        .with_scale(Vec2::ONE * 0.5)
+                        .with_depth((DepthLayer::Front, 0.51)),
+                ));
+            }
+        });
+}
+
 fn volume_slider_update(
-    mut query: Query<(&mut Transform2, &Clickable), With<VolumeSliderKnob>>,
+    mut query: Query<(&mut Transform2, &Clickable, &VolumeSliderKnob)>,
     mouse: Res<Mouse>,
-    mut mixer: ResMut<AudioPlusMixer>,
+    mut settings: ResMut<Settings>,
     asset_library: Res<AssetLibrary>,
     mut commands: Commands,
 ) {
-    for (mut transform, clickable) in query.iter_mut() {
+    for (mut transform, clickable, knob) in query.iter_mut() {
         if clickable.just_clicked() {
             commands.spawn((
                 AudioPlusSource::new(asset_library.sound_effects.sfx_menu_button_click.clone())
@@ -110,10 +178,26 @@ fn volume_slider_update(
                 TimeToLive { seconds: 3. },
             ));
         }
+        let min_volume = knob.min_volume();
+        let volume = match knob.bus {
+            Some(bus) => settings.bus_volume(bus),
+            None => settings.master_volume,
+        };
         if clickable.clicked {
-            let offset = ((mouse.position.x - 41.) / 116. + 0.5).clamp(0., 1.) * 0.9 + 0.1;
-            mixer.set_master_volume(offset);
+            let offset = ((mouse.position.x - knob.x) / SLIDER_WIDTH + 0.5).clamp(0., 1.)
+                * (1. - min_volume)
+                + min_volume;
+            if volume != offset {
+                match knob.bus {
+                    Some(bus) => settings.set_bus_volume(bus, offset),
+                    None => settings.master_volume = offset,
+                }
+            }
         }
-        transform.translation.x = ((mixer.get_master_volume() * 1.1111 - 0.1) - 0.5) * 116.;
+        let volume = match knob.bus {
+            Some(bus) => settings.bus_volume(bus),
+            None => settings.master_volume,
+        };
+        transform.translation.x = ((volume - min_volume) / (1. - min_volume) - 0.5) * SLIDER_WIDTH;
     }
 }